@@ -0,0 +1,333 @@
+use anchor_client::solana_sdk::bpf_loader_upgradeable;
+use anchor_client::solana_sdk::pubkey::Pubkey;
+use anchor_client::solana_sdk::signer::Signer;
+use anchor_client::solana_sdk::system_program;
+use anyhow::{anyhow, Result};
+use arbiter::state::ConfigLimits;
+use clap::Subcommand;
+
+use crate::config::Config;
+use crate::macros::{assert_exists, assert_not_exists};
+use crate::program::{account_exists, create_program_client, send_with_approval};
+use crate::terminal::{display_lamports, print_serialized, DisplayOptions};
+
+/// The variants for each program administration command.
+#[derive(Subcommand)]
+pub enum AdminCommand {
+    /// Interact with the global program configuration.
+    Config {
+        /// The subcommand to invoke on the configuration.
+        #[clap(subcommand)]
+        subcmd: ConfigCommand,
+    },
+    /// Set the lamport fee charged for creating a document.
+    Fee {
+        /// The fee in lamports, where zero disables it.
+        lamports: u64,
+        /// The pubkey of the wallet receiving the fees, defaulting
+        /// to the currently configured treasury.
+        #[clap(long)]
+        treasury: Option<Pubkey>,
+    },
+    /// Pause every mutating instruction of the program.
+    Pause,
+    /// Resume the program after it was paused.
+    Unpause,
+    /// Manage the document fee waivers of clerks.
+    Waiver {
+        /// The subcommand to invoke on the fee waivers.
+        #[clap(subcommand)]
+        subcmd: WaiverCommand,
+    },
+}
+
+/// The variants for each fee waiver command.
+#[derive(Subcommand)]
+pub enum WaiverCommand {
+    /// Exempt the documents of a clerk from the creation fee.
+    Grant {
+        /// The pubkey of the clerk being granted the waiver.
+        clerk: Pubkey,
+    },
+    /// Remove the fee waiver of a clerk.
+    Revoke {
+        /// The pubkey of the clerk whose waiver is being revoked.
+        clerk: Pubkey,
+    },
+}
+
+/// The variants for each global configuration command.
+#[derive(Subcommand)]
+pub enum ConfigCommand {
+    /// Get the serialized account data for the global configuration.
+    Get {
+        /// Display the account data as JSON.
+        #[clap(long)]
+        json: bool,
+        /// Pretty print the serialized account data.
+        #[clap(long)]
+        pretty: bool,
+    },
+    /// Initialize or update the global configuration limits.
+    Set {
+        /// The maximum byte length of a document title.
+        #[clap(long)]
+        max_title_length: Option<u16>,
+        /// The maximum byte length of a document URI.
+        #[clap(long)]
+        max_uri_length: Option<u16>,
+        /// The maximum number of participants stored on a document.
+        #[clap(long)]
+        max_participants: Option<u16>,
+        /// The maximum document limit of a clerk.
+        #[clap(long)]
+        max_clerk_limit: Option<u8>,
+        /// The pubkey of a new admin to transfer the configuration to.
+        #[clap(long)]
+        new_admin: Option<Pubkey>,
+    },
+}
+
+pub fn entry(cfg: &Config, subcmd: &AdminCommand) -> Result<()> {
+    match subcmd {
+        AdminCommand::Config { subcmd } => match subcmd {
+            ConfigCommand::Get { json, pretty } => {
+                process_config_get(cfg, DisplayOptions::from_args(*json, *pretty))
+            }
+            ConfigCommand::Set {
+                max_title_length,
+                max_uri_length,
+                max_participants,
+                max_clerk_limit,
+                new_admin,
+            } => process_config_set(
+                cfg,
+                *max_title_length,
+                *max_uri_length,
+                *max_participants,
+                *max_clerk_limit,
+                new_admin,
+            ),
+        },
+        AdminCommand::Fee { lamports, treasury } => process_set_fee(cfg, *lamports, treasury),
+        AdminCommand::Pause => process_set_paused(cfg, true),
+        AdminCommand::Unpause => process_set_paused(cfg, false),
+        AdminCommand::Waiver { subcmd } => match subcmd {
+            WaiverCommand::Grant { clerk } => process_grant_waiver(cfg, clerk),
+            WaiverCommand::Revoke { clerk } => process_revoke_waiver(cfg, clerk),
+        },
+    }
+}
+
+/// Derive the global `Config` program address.
+pub fn find_config_address(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[arbiter::seeds::CONFIG], program_id).0
+}
+
+/// Derive the `FeeWaiver` program address for the argued clerk.
+pub fn find_waiver_address(program_id: &Pubkey, clerk: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[arbiter::seeds::WAIVER, clerk.as_ref()], program_id).0
+}
+
+fn process_config_get(cfg: &Config, display: DisplayOptions) -> Result<()> {
+    let (program, _) = create_program_client(cfg);
+    print_serialized(
+        program.account::<arbiter::state::Config>(find_config_address(&program.id()))?,
+        &display,
+    )
+}
+
+fn process_config_set(
+    cfg: &Config,
+    max_title_length: Option<u16>,
+    max_uri_length: Option<u16>,
+    max_participants: Option<u16>,
+    max_clerk_limit: Option<u8>,
+    new_admin: &Option<Pubkey>,
+) -> Result<()> {
+    let (program, signer) = create_program_client(cfg);
+    let config = find_config_address(&program.id());
+
+    if account_exists(&program, &config)? {
+        let current = program.account::<arbiter::state::Config>(config)?.limits;
+
+        return send_with_approval(
+            cfg,
+            program
+                .request()
+                .accounts(arbiter::accounts::UpdateConfig {
+                    admin: signer.pubkey(),
+                    config,
+                })
+                .args(arbiter::instruction::UpdateConfig {
+                    limits: ConfigLimits {
+                        max_title_length: max_title_length.unwrap_or(current.max_title_length),
+                        max_uri_length: max_uri_length.unwrap_or(current.max_uri_length),
+                        max_participants: max_participants.unwrap_or(current.max_participants),
+                        max_clerk_limit: max_clerk_limit.unwrap_or(current.max_clerk_limit),
+                    },
+                    new_admin: *new_admin,
+                })
+                .signer(signer.as_ref()),
+            vec!["arbiter::UpdateConfig"],
+        );
+    }
+
+    if new_admin.is_some() {
+        return Err(anyhow!(
+            "the admin of a new configuration is always the program upgrade authority"
+        ));
+    }
+
+    let limits = match (
+        max_title_length,
+        max_uri_length,
+        max_participants,
+        max_clerk_limit,
+    ) {
+        (
+            Some(max_title_length),
+            Some(max_uri_length),
+            Some(max_participants),
+            Some(max_clerk_limit),
+        ) => ConfigLimits {
+            max_title_length,
+            max_uri_length,
+            max_participants,
+            max_clerk_limit,
+        },
+        _ => {
+            return Err(anyhow!(
+                "every limit must be provided to initialize the configuration"
+            ))
+        }
+    };
+
+    let program_data =
+        Pubkey::find_program_address(&[program.id().as_ref()], &bpf_loader_upgradeable::ID).0;
+
+    send_with_approval(
+        cfg,
+        program
+            .request()
+            .accounts(arbiter::accounts::InitConfig {
+                admin: signer.pubkey(),
+                payer: signer.pubkey(),
+                config,
+                program: program.id(),
+                program_data,
+                system_program: system_program::ID,
+            })
+            .args(arbiter::instruction::InitConfig { limits })
+            .signer(signer.as_ref()),
+        vec!["arbiter::InitConfig"],
+    )
+}
+
+fn process_set_paused(cfg: &Config, paused: bool) -> Result<()> {
+    let (program, signer) = create_program_client(cfg);
+    let config = find_config_address(&program.id());
+
+    assert_exists!(&program, arbiter::state::Config, &config);
+
+    if program.account::<arbiter::state::Config>(config)?.paused == paused {
+        println!(
+            "The program is already {}",
+            if paused { "paused" } else { "unpaused" }
+        );
+        return Ok(());
+    }
+
+    send_with_approval(
+        cfg,
+        program
+            .request()
+            .accounts(arbiter::accounts::SetPaused {
+                admin: signer.pubkey(),
+                config,
+            })
+            .args(arbiter::instruction::SetPaused { paused })
+            .signer(signer.as_ref()),
+        vec!["arbiter::SetPaused"],
+    )
+}
+
+fn process_set_fee(cfg: &Config, lamports: u64, treasury: &Option<Pubkey>) -> Result<()> {
+    let (program, signer) = create_program_client(cfg);
+    let config = find_config_address(&program.id());
+
+    assert_exists!(&program, arbiter::state::Config, &config);
+
+    let treasury = match treasury {
+        Some(t) => *t,
+        None => program.account::<arbiter::state::Config>(config)?.treasury,
+    };
+
+    println!(
+        "Document fee: {}\nTreasury: {}\n",
+        display_lamports(lamports),
+        treasury
+    );
+
+    send_with_approval(
+        cfg,
+        program
+            .request()
+            .accounts(arbiter::accounts::SetFee {
+                admin: signer.pubkey(),
+                config,
+            })
+            .args(arbiter::instruction::SetFee {
+                document_fee: lamports,
+                treasury,
+            })
+            .signer(signer.as_ref()),
+        vec!["arbiter::SetFee"],
+    )
+}
+
+fn process_grant_waiver(cfg: &Config, clerk: &Pubkey) -> Result<()> {
+    let (program, signer) = create_program_client(cfg);
+    let waiver = find_waiver_address(&program.id(), clerk);
+
+    assert_exists!(&program, arbiter::state::Clerk, clerk);
+    assert_not_exists!(&program, arbiter::state::FeeWaiver, &waiver);
+
+    send_with_approval(
+        cfg,
+        program
+            .request()
+            .accounts(arbiter::accounts::GrantFeeWaiver {
+                admin: signer.pubkey(),
+                payer: signer.pubkey(),
+                config: find_config_address(&program.id()),
+                clerk: *clerk,
+                waiver,
+                system_program: system_program::ID,
+            })
+            .args(arbiter::instruction::GrantFeeWaiver {})
+            .signer(signer.as_ref()),
+        vec!["arbiter::GrantFeeWaiver"],
+    )
+}
+
+fn process_revoke_waiver(cfg: &Config, clerk: &Pubkey) -> Result<()> {
+    let (program, signer) = create_program_client(cfg);
+    let waiver = find_waiver_address(&program.id(), clerk);
+
+    assert_exists!(&program, arbiter::state::FeeWaiver, &waiver);
+
+    send_with_approval(
+        cfg,
+        program
+            .request()
+            .accounts(arbiter::accounts::RevokeFeeWaiver {
+                admin: signer.pubkey(),
+                config: find_config_address(&program.id()),
+                waiver,
+            })
+            .args(arbiter::instruction::RevokeFeeWaiver {})
+            .signer(signer.as_ref()),
+        vec!["arbiter::RevokeFeeWaiver"],
+    )
+}
@@ -0,0 +1,183 @@
+use anchor_client::anchor_lang::ToAccountMetas;
+use anchor_client::solana_sdk::instruction::{AccountMeta, Instruction};
+use anchor_client::solana_sdk::pubkey::Pubkey;
+use anchor_client::solana_sdk::signer::Signer;
+use anchor_client::solana_sdk::system_program;
+use anyhow::Result;
+use clap::Subcommand;
+
+use super::admin::find_config_address;
+use super::document::find_prerequisite_address;
+use crate::config::Config;
+use crate::macros::{assert_exists, assert_not_exists};
+use crate::program::{create_program_client, fetch_document, send_with_approval};
+use crate::terminal::{print_serialized, DisplayOptions};
+
+/// The variants for each bundle program account command.
+#[derive(Subcommand)]
+pub enum BundleCommand {
+    /// Create a new bundle of documents held by your clerk.
+    Create {
+        /// The document pubkeys to include in the bundle.
+        #[clap(short, long, multiple_occurrences = true)]
+        document: Vec<Pubkey>,
+        /// Name of the new bundle.
+        #[clap(long)]
+        name: String,
+    },
+    /// Finalize every document in a fully signed bundle.
+    Finalize {
+        /// The pubkey of the bundle.
+        address: Pubkey,
+    },
+    /// Get the serialized account data and signing status for a bundle.
+    Get {
+        /// The pubkey of the bundle program account.
+        address: Pubkey,
+        /// Display the serialized data as JSON.
+        #[clap(long)]
+        json: bool,
+        /// Pretty print the serialized data.
+        #[clap(long)]
+        pretty: bool,
+    },
+}
+
+pub fn entry(cfg: &Config, subcmd: &BundleCommand) -> Result<()> {
+    match subcmd {
+        BundleCommand::Create { document, name } => process_create(cfg, document, name),
+        BundleCommand::Finalize { address } => process_finalize(cfg, address),
+        BundleCommand::Get {
+            address,
+            json,
+            pretty,
+        } => process_get(cfg, address, DisplayOptions::from_args(*json, *pretty)),
+    }
+}
+
+fn process_create(cfg: &Config, documents: &[Pubkey], name: &str) -> Result<()> {
+    let (program, signer) = create_program_client(cfg);
+
+    let clerk = Pubkey::find_program_address(
+        &[arbiter::seeds::CLERK, signer.pubkey().as_ref()],
+        &program.id(),
+    )
+    .0;
+
+    let bundle = Pubkey::find_program_address(
+        &[
+            arbiter::seeds::BUNDLE,
+            clerk.as_ref(),
+            arbiter::state::Bundle::name_seed(name),
+        ],
+        &program.id(),
+    )
+    .0;
+
+    assert_exists!(&program, arbiter::state::Clerk, &clerk);
+    assert_not_exists!(&program, arbiter::state::Bundle, &bundle);
+
+    let mut accounts = arbiter::accounts::InitBundle {
+        authority: signer.pubkey(),
+        payer: signer.pubkey(),
+        config: find_config_address(&program.id()),
+        clerk,
+        bundle,
+        system_program: system_program::ID,
+    }
+    .to_account_metas(None);
+
+    accounts.extend(documents.iter().map(|d| AccountMeta::new(*d, false)));
+
+    send_with_approval(
+        cfg,
+        program
+            .request()
+            .instruction(Instruction::new_with_borsh(
+                program.id(),
+                &arbiter::instruction::InitBundle {
+                    name: name.into(),
+                    documents: documents.to_vec(),
+                },
+                accounts,
+            ))
+            .signer(signer.as_ref()),
+        vec!["arbiter::InitBundle"],
+    )
+}
+
+fn process_finalize(cfg: &Config, address: &Pubkey) -> Result<()> {
+    let (program, signer) = create_program_client(cfg);
+    let bundle = program.account::<arbiter::state::Bundle>(*address)?;
+
+    let mut accounts = arbiter::accounts::FinalizeBundle {
+        authority: signer.pubkey(),
+        config: find_config_address(&program.id()),
+        clerk: bundle.clerk,
+        bundle: *address,
+    }
+    .to_account_metas(None);
+
+    accounts.extend(bundle.documents.iter().map(|d| AccountMeta::new(*d, false)));
+
+    let mut prerequisites = Vec::<Pubkey>::new();
+    for d in &bundle.documents {
+        for p in fetch_document(&program, d)?.prerequisites {
+            if !bundle.documents.contains(&p) && !prerequisites.contains(&p) {
+                prerequisites.push(p);
+            }
+        }
+    }
+
+    for p in &prerequisites {
+        accounts.push(AccountMeta::new_readonly(
+            find_prerequisite_address(&program, p)?,
+            false,
+        ));
+    }
+
+    send_with_approval(
+        cfg,
+        program
+            .request()
+            .instruction(Instruction::new_with_borsh(
+                program.id(),
+                &arbiter::instruction::FinalizeBundle {},
+                accounts,
+            ))
+            .signer(signer.as_ref()),
+        vec!["arbiter::FinalizeBundle"],
+    )
+}
+
+fn process_get(cfg: &Config, address: &Pubkey, display: DisplayOptions) -> Result<()> {
+    let (program, _) = create_program_client(cfg);
+    let bundle = program.account::<arbiter::state::Bundle>(*address)?;
+
+    print_serialized(&bundle, &display)?;
+
+    if !display.is_json() {
+        println!();
+        for d in &bundle.documents {
+            let doc = fetch_document(&program, d)?;
+            let signed = doc.signature_timestamps.iter().filter(|&&t| t > 0).count();
+
+            println!(
+                "{} ({}): {}/{} signatures{}",
+                d,
+                doc.title,
+                signed,
+                doc.signature_timestamps.len(),
+                if doc.is_finalized() {
+                    ", finalized"
+                } else if doc.has_all_signatures() {
+                    ", ready"
+                } else {
+                    ""
+                },
+            );
+        }
+    }
+
+    Ok(())
+}
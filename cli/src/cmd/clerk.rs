@@ -4,9 +4,10 @@ use anchor_client::solana_sdk::system_program;
 use anyhow::Result;
 use clap::Subcommand;
 
+use super::admin::find_config_address;
 use crate::config::Config;
 use crate::macros::{assert_exists, assert_not_exists};
-use crate::program::{create_program_client, send_with_approval};
+use crate::program::{create_program_client, fetch_clerk, send_with_approval};
 use crate::terminal::{print_serialized, DisplayOptions};
 
 /// The variants for each clerk program account command.
@@ -32,6 +33,8 @@ pub enum ClerkCommand {
         #[clap(long)]
         pretty: bool,
     },
+    /// Migrate your clerk from the legacy account layout to the current version.
+    Migrate,
     /// Upgrade the document storage limit for your clerk.
     Upgrade {
         /// The amount to increase the document storage by.
@@ -54,6 +57,7 @@ pub fn entry(cfg: &Config, subcmd: &ClerkCommand) -> Result<()> {
             owner,
             DisplayOptions::from_args(*json, *pretty),
         ),
+        ClerkCommand::Migrate => process_migrate(cfg),
         ClerkCommand::Upgrade { amount } => process_upgrade(cfg, *amount),
     }
 }
@@ -76,6 +80,7 @@ fn process_create(cfg: &Config, limit: u8) -> Result<()> {
             .accounts(arbiter::accounts::InitClerk {
                 authority: signer.pubkey(),
                 payer: signer.pubkey(),
+                config: find_config_address(&program.id()),
                 clerk,
                 system_program: system_program::ID,
             })
@@ -97,9 +102,43 @@ fn process_get(
         Pubkey::find_program_address(&[arbiter::seeds::CLERK, owner_pk.as_ref()], &program.id()).0
     });
 
-    print_serialized(
-        program.account::<arbiter::state::Clerk>(clerk_addr)?,
-        &display,
+    let clerk = fetch_clerk(&program, &clerk_addr)?;
+
+    print_serialized(&clerk, &display)?;
+
+    if !display.is_json() && clerk.version == arbiter::migration::LEGACY_VERSION {
+        println!("\nThe clerk uses the legacy account layout and must be migrated");
+    }
+
+    Ok(())
+}
+
+fn process_migrate(cfg: &Config) -> Result<()> {
+    let (program, signer) = create_program_client(cfg);
+    let clerk = Pubkey::find_program_address(
+        &[arbiter::seeds::CLERK, signer.pubkey().as_ref()],
+        &program.id(),
+    )
+    .0;
+
+    if fetch_clerk(&program, &clerk)?.version != arbiter::migration::LEGACY_VERSION {
+        println!("Clerk {} is already migrated", clerk);
+        return Ok(());
+    }
+
+    send_with_approval(
+        cfg,
+        program
+            .request()
+            .accounts(arbiter::accounts::MigrateClerk {
+                payer: signer.pubkey(),
+                config: find_config_address(&program.id()),
+                clerk,
+                system_program: system_program::ID,
+            })
+            .args(arbiter::instruction::MigrateClerk {})
+            .signer(signer.as_ref()),
+        vec!["arbiter::MigrateClerk"],
     )
 }
 
@@ -120,6 +159,7 @@ fn process_upgrade(cfg: &Config, amount: u8) -> Result<()> {
             .accounts(arbiter::accounts::Upgrade {
                 authority: signer.pubkey(),
                 payer: signer.pubkey(),
+                config: find_config_address(&program.id()),
                 clerk,
                 system_program: system_program::ID,
             })
@@ -0,0 +1,151 @@
+use anchor_client::solana_client::rpc_filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType};
+use anchor_client::solana_sdk::pubkey::Pubkey;
+use anchor_client::solana_sdk::signer::Signer;
+use anchor_client::solana_sdk::system_program;
+use anyhow::Result;
+use clap::Subcommand;
+
+use super::admin::find_config_address;
+use crate::config::Config;
+use crate::macros::{assert_exists, assert_not_exists};
+use crate::program::{create_program_client, send_with_approval};
+use crate::terminal::{print_serialized, DisplayOptions};
+
+/// The variants for each delegation program account command.
+#[derive(Subcommand)]
+pub enum DelegateCommand {
+    /// Authorize a delegate to sign documents on your behalf.
+    Grant {
+        /// The pubkey of the delegate being authorized.
+        delegate: Pubkey,
+        /// The pubkey of a single document to restrict the delegation to.
+        #[clap(long)]
+        document: Option<Pubkey>,
+        /// The unix timestamp after which the delegation is no longer valid.
+        #[clap(long)]
+        expires_at: Option<u64>,
+    },
+    /// List the delegations granted by a participant.
+    List {
+        /// Display the account data as JSON.
+        #[clap(long)]
+        json: bool,
+        /// The pubkey of the participant to list delegations for.
+        #[clap(long)]
+        participant: Option<Pubkey>,
+        /// Pretty print the serialized account data.
+        #[clap(long)]
+        pretty: bool,
+    },
+    /// Revoke a delegate's authority to sign on your behalf.
+    Revoke {
+        /// The pubkey of the delegate being revoked.
+        delegate: Pubkey,
+    },
+}
+
+pub fn entry(cfg: &Config, subcmd: &DelegateCommand) -> Result<()> {
+    match subcmd {
+        DelegateCommand::Grant {
+            delegate,
+            document,
+            expires_at,
+        } => process_grant(cfg, delegate, document, expires_at),
+        DelegateCommand::List {
+            json,
+            participant,
+            pretty,
+        } => process_list(cfg, participant, DisplayOptions::from_args(*json, *pretty)),
+        DelegateCommand::Revoke { delegate } => process_revoke(cfg, delegate),
+    }
+}
+
+/// Derive the `Delegation` program address for the participant and delegate.
+pub fn find_delegation_address(
+    program_id: &Pubkey,
+    participant: &Pubkey,
+    delegate: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            arbiter::seeds::DELEGATION,
+            participant.as_ref(),
+            delegate.as_ref(),
+        ],
+        program_id,
+    )
+    .0
+}
+
+fn process_grant(
+    cfg: &Config,
+    delegate: &Pubkey,
+    document: &Option<Pubkey>,
+    expires_at: &Option<u64>,
+) -> Result<()> {
+    let (program, signer) = create_program_client(cfg);
+    let delegation = find_delegation_address(&program.id(), &signer.pubkey(), delegate);
+
+    assert_not_exists!(&program, arbiter::state::Delegation, &delegation);
+
+    if let Some(doc) = document {
+        assert_exists!(&program, arbiter::state::Document, doc);
+    }
+
+    send_with_approval(
+        cfg,
+        program
+            .request()
+            .accounts(arbiter::accounts::GrantDelegation {
+                participant: signer.pubkey(),
+                payer: signer.pubkey(),
+                config: find_config_address(&program.id()),
+                delegate: *delegate,
+                delegation,
+                system_program: system_program::ID,
+            })
+            .args(arbiter::instruction::GrantDelegation {
+                document: *document,
+                expires_at: *expires_at,
+            })
+            .signer(signer.as_ref()),
+        vec!["arbiter::GrantDelegation"],
+    )
+}
+
+fn process_list(cfg: &Config, participant: &Option<Pubkey>, display: DisplayOptions) -> Result<()> {
+    let (program, signer) = create_program_client(cfg);
+    let participant_pk = participant.unwrap_or_else(|| signer.pubkey());
+
+    let delegations = program
+        .accounts::<arbiter::state::Delegation>(vec![RpcFilterType::Memcmp(Memcmp {
+            offset: 8,
+            bytes: MemcmpEncodedBytes::Base58(participant_pk.to_string()),
+            encoding: None,
+        })])?
+        .into_iter()
+        .map(|(_, d)| d)
+        .collect::<Vec<_>>();
+
+    print_serialized(delegations, &display)
+}
+
+fn process_revoke(cfg: &Config, delegate: &Pubkey) -> Result<()> {
+    let (program, signer) = create_program_client(cfg);
+    let delegation = find_delegation_address(&program.id(), &signer.pubkey(), delegate);
+
+    assert_exists!(&program, arbiter::state::Delegation, &delegation);
+
+    send_with_approval(
+        cfg,
+        program
+            .request()
+            .accounts(arbiter::accounts::RevokeDelegation {
+                participant: signer.pubkey(),
+                delegation,
+            })
+            .args(arbiter::instruction::RevokeDelegation {})
+            .signer(signer.as_ref()),
+        vec!["arbiter::RevokeDelegation"],
+    )
+}
@@ -1,19 +1,41 @@
 use anchor_client::anchor_lang::ToAccountMetas;
-use anchor_client::solana_sdk::instruction::Instruction;
+use anchor_client::solana_sdk::hash::hash;
+use anchor_client::solana_sdk::instruction::{AccountMeta, Instruction};
 use anchor_client::solana_sdk::pubkey::Pubkey;
+use anchor_client::solana_sdk::secp256k1_program;
+use anchor_client::solana_sdk::signature::read_keypair_file;
 use anchor_client::solana_sdk::signer::Signer;
 use anchor_client::solana_sdk::system_program;
-use anyhow::Result;
-use clap::Subcommand;
+use anchor_client::solana_sdk::sysvar;
+use anchor_client::Program;
+use anyhow::{anyhow, Result};
+use arbiter::state::{
+    display_eth_address, DocumentSettings, ParticipantProof, ParticipantSet, Receipt, SignerGroup,
+};
+use arbiter::{token_2022, token_metadata};
+use clap::{Subcommand, ValueHint};
+use std::fs;
+use std::time::{SystemTime, UNIX_EPOCH};
 
+use super::admin::{find_config_address, find_waiver_address};
+use super::delegate::find_delegation_address;
 use crate::config::Config;
 use crate::macros::{assert_exists, assert_not_exists};
-use crate::program::{create_program_client, send_with_approval};
+use crate::program::{
+    account_exists, create_program_client, fetch_document, send_with_approval,
+    send_with_fee_approval,
+};
+use crate::proofs::{read_participants_csv, read_proof, write_proofs};
 use crate::terminal::{print_serialized, DisplayOptions};
 
 /// The variants for each document account command.
 #[derive(Subcommand)]
 pub enum DocumentCommand {
+    /// Accept an invitation to participate in a document.
+    Accept {
+        /// The pubkey of the document to accept.
+        address: Pubkey,
+    },
     /// Add new participant(s) to a document.
     Add {
         /// The pubkey of the document to update.
@@ -22,17 +44,119 @@ pub enum DocumentCommand {
         #[clap(short, long, multiple_occurrences = true)]
         participant: Vec<Pubkey>,
     },
+    /// Attach a supplementary file to a document before it is signed.
+    Attach {
+        /// The pubkey of the document to update.
+        address: Pubkey,
+        /// The unique label of the attachment.
+        #[clap(long)]
+        label: String,
+        /// URI of the attachment content in storage using
+        /// the `ar://`, `ipfs://` or `https://` scheme.
+        #[clap(long)]
+        uri: String,
+        /// Path to a local copy of the attachment to hash.
+        #[clap(long, value_hint = ValueHint::FilePath)]
+        file: String,
+    },
+    /// Claim the non-transferable receipt of signing a finalized document.
+    Claim {
+        /// The pubkey of the finalized document.
+        address: Pubkey,
+        /// The pubkey of the participant receiving the receipt, defaulting to you.
+        #[clap(long)]
+        participant: Option<Pubkey>,
+    },
+    /// Replace a finalized document with a receipt of its content hash and
+    /// participants, clearing the document and refunding its excess rent.
+    Compress {
+        /// The pubkey of the finalized document.
+        address: Pubkey,
+    },
     /// Create a new document under the clerk.
     Create {
+        /// The unix timestamp each participant must sign by, given once
+        /// for every participant or in the same order as `--participant`.
+        #[clap(long, multiple_occurrences = true, requires = "participant")]
+        deadline: Vec<u64>,
+        /// Create the document as a draft that must be opened before it can be signed.
+        #[clap(long)]
+        draft: bool,
         /// The participant pubkeys to add.
         #[clap(short, long, multiple_occurrences = true)]
         participant: Vec<Pubkey>,
+        /// The `0x` prefixed Ethereum addresses of participants to add.
+        #[clap(long, multiple_occurrences = true, parse(try_from_str = parse_eth_address))]
+        eth_participant: Vec<[u8; 20]>,
+        /// A named group of participants with its own quorum as
+        /// `NAME:THRESHOLD:PUBKEY,PUBKEY,...`, where each pubkey
+        /// must also be given as a `--participant`.
+        #[clap(long, multiple_occurrences = true, parse(try_from_str = parse_group))]
+        group: Vec<GroupArg>,
+        /// The unix timestamp of the effective date before which
+        /// the document cannot be finalized.
+        #[clap(long)]
+        not_before: Option<u64>,
+        /// Path to a CSV of participant pubkeys to commit to as a Merkle root instead.
+        #[clap(
+            long,
+            value_hint = ValueHint::FilePath,
+            conflicts_with_all = &["participant", "eth-participant", "requires-acceptance"]
+        )]
+        participants_csv: Option<String>,
+        /// Path to write the inclusion proofs for the participants CSV to.
+        #[clap(long, value_hint = ValueHint::FilePath, default_value = "proofs.json")]
+        proofs_out: String,
+        /// The document pubkeys that must be finalized before this one.
+        #[clap(long, multiple_occurrences = true)]
+        prerequisite: Vec<Pubkey>,
+        /// Require participants to accept their invitation before signing.
+        #[clap(long)]
+        requires_acceptance: bool,
+        /// The summed participant weight required to finalize the document
+        /// instead of requiring every participant to sign.
+        #[clap(long, requires = "participant")]
+        required_weight: Option<u64>,
+        /// The named sections that each participant must sign separately.
+        #[clap(short, long, multiple_occurrences = true)]
+        section: Vec<String>,
         /// Title of the new document.
         #[clap(long)]
         title: String,
-        /// URI of the document content in storage.
+        /// URI of the document content in storage using
+        /// the `ar://`, `ipfs://` or `https://` scheme.
         #[clap(long)]
         uri: String,
+        /// The weight of each participant in the same order as `--participant`.
+        #[clap(long, multiple_occurrences = true, requires = "required-weight")]
+        weight: Vec<u64>,
+    },
+    /// Remove an attachment from a document before it is signed.
+    Detach {
+        /// The pubkey of the document to update.
+        address: Pubkey,
+        /// The label of the attachment to remove.
+        #[clap(long)]
+        label: String,
+    },
+    /// Print the message an Ethereum participant must personal sign for a section.
+    EthMessage {
+        /// The pubkey of the document to be signed.
+        address: Pubkey,
+        /// The index of the document section being signed.
+        #[clap(long, default_value_t = 0)]
+        section: u8,
+    },
+    /// Move the signing deadline of a participant to a later time.
+    Extend {
+        /// The pubkey of the document to update.
+        address: Pubkey,
+        /// The pubkey of the participant with the deadline.
+        #[clap(long)]
+        participant: Pubkey,
+        /// The new unix timestamp the participant must sign by.
+        #[clap(long)]
+        deadline: u64,
     },
     /// Attempt to finalize a fully signed document.
     Finalize {
@@ -50,34 +174,271 @@ pub enum DocumentCommand {
         #[clap(long)]
         pretty: bool,
     },
+    /// Migrate a document from the legacy account layout to the current version.
+    Migrate {
+        /// The pubkey of the document.
+        address: Pubkey,
+    },
+    /// Open a draft document for signing, locking its participants and settings.
+    Open {
+        /// The pubkey of the draft document.
+        address: Pubkey,
+    },
+    /// Replace a participant on a document with a new public key.
+    Rotate {
+        /// The pubkey of the document to update.
+        address: Pubkey,
+        /// Path to the keypair of either the old or new participant co-signing the rotation.
+        #[clap(long, value_hint = ValueHint::FilePath)]
+        cosigner: String,
+        /// The pubkey of the participant currently listed on the document.
+        #[clap(long)]
+        old: Pubkey,
+        /// The pubkey of the participant replacing the old one.
+        #[clap(long)]
+        new: Pubkey,
+        /// Clear any signature already recorded for the old participant.
+        #[clap(long)]
+        reset_signature: bool,
+    },
     /// Sign a document program account.
     Sign {
         /// The pubkey of the document account to sign.
         address: Pubkey,
+        /// A short note attached to the signature (hashed if longer than 32 bytes),
+        /// which cannot be given by members of a Merkle committed participant set.
+        #[clap(long, conflicts_with = "proofs")]
+        memo: Option<String>,
+        /// The index of the document section being signed.
+        #[clap(long, default_value_t = 0)]
+        section: u8,
+        /// The pubkey of the participant that delegated signing to you.
+        #[clap(long)]
+        on_behalf_of: Option<Pubkey>,
+        /// Path to the proofs JSON of a Merkle committed participant set.
+        #[clap(long, value_hint = ValueHint::FilePath)]
+        proofs: Option<String>,
+    },
+    /// Submit the personal signature of an Ethereum participant.
+    SignEth {
+        /// The pubkey of the document account that was signed.
+        address: Pubkey,
+        /// The `0x` prefixed Ethereum address of the participant.
+        #[clap(long, parse(try_from_str = parse_eth_address))]
+        eth_address: [u8; 20],
+        /// The index of the document section that was signed.
+        #[clap(long, default_value_t = 0)]
+        section: u8,
+        /// The `0x` prefixed 65 byte signature returned by the Ethereum wallet.
+        #[clap(long, parse(try_from_str = parse_eth_signature))]
+        signature: [u8; 65],
+    },
+    /// Verify the inclusion of a participant against the receipt of a compressed document.
+    Verify {
+        /// The pubkey of the compressed document.
+        address: Pubkey,
+        /// The pubkey of the participant to verify.
+        #[clap(long)]
+        participant: Pubkey,
+        /// Path to a CSV of the document participants in their listed order.
+        #[clap(
+            long,
+            value_hint = ValueHint::FilePath,
+            required_unless_present = "proofs",
+            conflicts_with = "proofs"
+        )]
+        participants_csv: Option<String>,
+        /// Path to the proofs JSON of a Merkle committed participant set.
+        #[clap(long, value_hint = ValueHint::FilePath)]
+        proofs: Option<String>,
+    },
+    /// Update the URI of a document or the participants and settings of a draft.
+    Update {
+        /// The pubkey of the document to update.
+        address: Pubkey,
+        /// The new URI of the document content, which invalidates
+        /// every signature if the document has already been signed.
+        #[clap(long)]
+        uri: Option<String>,
+        /// The participant pubkeys replacing those of the draft.
+        #[clap(short, long, multiple_occurrences = true)]
+        participant: Vec<Pubkey>,
+        /// Whether participants of the draft must accept their invitation before signing.
+        #[clap(long)]
+        requires_acceptance: Option<bool>,
     },
 }
 
 pub fn entry(cfg: &Config, subcmd: &DocumentCommand) -> Result<()> {
     match subcmd {
+        DocumentCommand::Accept { address } => process_accept(cfg, address),
         DocumentCommand::Add {
             address,
             participant,
         } => process_add(cfg, address, participant),
+        DocumentCommand::Attach {
+            address,
+            label,
+            uri,
+            file,
+        } => process_attach(cfg, address, label, uri, file),
+        DocumentCommand::Claim {
+            address,
+            participant,
+        } => process_claim(cfg, address, participant),
+        DocumentCommand::Compress { address } => process_compress(cfg, address),
         DocumentCommand::Create {
+            deadline,
+            draft,
+            group,
+            not_before,
             participant,
+            eth_participant,
+            participants_csv,
+            proofs_out,
+            prerequisite,
+            requires_acceptance,
+            required_weight,
+            section,
             title,
             uri,
-        } => process_create(cfg, participant, title, uri),
+            weight,
+        } => {
+            if !weight.is_empty() && weight.len() != participant.len() {
+                return Err(anyhow!("provide one weight for each participant"));
+            }
+
+            let groups = group
+                .iter()
+                .map(|g| g.to_signer_group(participant))
+                .collect::<Result<Vec<SignerGroup>>>()?;
+
+            let mut settings = DocumentSettings {
+                requires_acceptance: *requires_acceptance,
+                draft: *draft,
+                required_weight: required_weight.unwrap_or_default(),
+                not_before: *not_before,
+                ..DocumentSettings::default()
+            };
+
+            if let Some(csv) = participants_csv {
+                let (members, tree) = read_participants_csv(csv)?;
+                settings.participant_root = Some(tree.root());
+                settings.participant_count = members.len() as u32;
+
+                write_proofs(proofs_out, &members, &tree)?;
+                println!(
+                    "Wrote the inclusion proofs of {} participants to {}",
+                    members.len(),
+                    proofs_out
+                );
+            }
+
+            let deadlines = match deadline.len() {
+                0 => vec![],
+                1 => vec![deadline[0]; participant.len()],
+                n if n == participant.len() => deadline.clone(),
+                _ => return Err(anyhow!("provide one deadline or one for each participant")),
+            };
+
+            process_create(
+                cfg,
+                ParticipantSet {
+                    participants: participant.clone(),
+                    eth_participants: eth_participant.clone(),
+                    deadlines,
+                    weights: weight.clone(),
+                    groups,
+                },
+                section,
+                prerequisite,
+                title,
+                uri,
+                settings,
+            )
+        }
+        DocumentCommand::Detach { address, label } => process_detach(cfg, address, label),
+        DocumentCommand::EthMessage { address, section } => {
+            process_eth_message(cfg, address, *section)
+        }
+        DocumentCommand::Extend {
+            address,
+            participant,
+            deadline,
+        } => process_extend(cfg, address, participant, *deadline),
         DocumentCommand::Finalize { address } => process_finalize(cfg, address),
         DocumentCommand::Get {
             address,
             json,
             pretty,
         } => process_get(cfg, address, DisplayOptions::from_args(*json, *pretty)),
-        DocumentCommand::Sign { address } => process_sign(cfg, address),
+        DocumentCommand::Migrate { address } => process_migrate(cfg, address),
+        DocumentCommand::Open { address } => process_open(cfg, address),
+        DocumentCommand::Rotate {
+            address,
+            cosigner,
+            old,
+            new,
+            reset_signature,
+        } => process_rotate(cfg, address, cosigner, old, new, *reset_signature),
+        DocumentCommand::Sign {
+            address,
+            memo,
+            section,
+            on_behalf_of,
+            proofs,
+        } => process_sign(cfg, address, memo, *section, on_behalf_of, proofs),
+        DocumentCommand::SignEth {
+            address,
+            eth_address,
+            section,
+            signature,
+        } => process_sign_eth(cfg, address, eth_address, *section, signature),
+        DocumentCommand::Update {
+            address,
+            uri,
+            participant,
+            requires_acceptance,
+        } => process_update(cfg, address, uri, participant, *requires_acceptance),
+        DocumentCommand::Verify {
+            address,
+            participant,
+            participants_csv,
+            proofs,
+        } => process_verify(cfg, address, participant, participants_csv, proofs),
+    }
+}
+
+/// Returns the account that proves the argued prerequisite document is finalized,
+/// which is its `Receipt` once the document has been compressed.
+pub fn find_prerequisite_address(program: &Program, prerequisite: &Pubkey) -> Result<Pubkey> {
+    if account_exists(program, prerequisite)? {
+        Ok(*prerequisite)
+    } else {
+        Ok(Receipt::address(prerequisite))
     }
 }
 
+fn process_accept(cfg: &Config, address: &Pubkey) -> Result<()> {
+    let (program, signer) = create_program_client(cfg);
+
+    assert_exists!(&program, arbiter::state::Document, address);
+
+    send_with_approval(
+        cfg,
+        program
+            .request()
+            .accounts(arbiter::accounts::AcceptInvitation {
+                participant: signer.pubkey(),
+                config: find_config_address(&program.id()),
+                document: *address,
+            })
+            .args(arbiter::instruction::AcceptInvitation {})
+            .signer(signer.as_ref()),
+        vec!["arbiter::AcceptInvitation"],
+    )
+}
+
 fn process_add(cfg: &Config, address: &Pubkey, participants: &[Pubkey]) -> Result<()> {
     let (program, signer) = create_program_client(cfg);
 
@@ -91,6 +452,7 @@ fn process_add(cfg: &Config, address: &Pubkey, participants: &[Pubkey]) -> Resul
                 .accounts(arbiter::accounts::AddParticipant {
                     authority: signer.pubkey(),
                     payer: signer.pubkey(),
+                    config: find_config_address(&program.id()),
                     document: *address,
                     system_program: system_program::ID,
                 })
@@ -111,6 +473,7 @@ fn process_add(cfg: &Config, address: &Pubkey, participants: &[Pubkey]) -> Resul
             arbiter::accounts::AddParticipant {
                 authority: signer.pubkey(),
                 payer: signer.pubkey(),
+                config: find_config_address(&program.id()),
                 document: *address,
                 system_program: system_program::ID,
             }
@@ -125,7 +488,146 @@ fn process_add(cfg: &Config, address: &Pubkey, participants: &[Pubkey]) -> Resul
     )
 }
 
-fn process_create(cfg: &Config, participants: &[Pubkey], title: &str, uri: &str) -> Result<()> {
+fn process_attach(
+    cfg: &Config,
+    address: &Pubkey,
+    label: &str,
+    uri: &str,
+    file: &str,
+) -> Result<()> {
+    let (program, signer) = create_program_client(cfg);
+
+    assert_exists!(&program, arbiter::state::Document, address);
+
+    let config_addr = find_config_address(&program.id());
+    let config = program.account::<arbiter::state::Config>(config_addr)?;
+
+    arbiter::uri::validate(uri, config.limits.max_uri_length as usize)
+        .map_err(|e| anyhow!("invalid attachment uri: {}", e))?;
+
+    let content_hash = hash(&fs::read(&*shellexpand::tilde(file))?).to_bytes();
+
+    send_with_approval(
+        cfg,
+        program
+            .request()
+            .accounts(arbiter::accounts::AddAttachment {
+                authority: signer.pubkey(),
+                payer: signer.pubkey(),
+                config: config_addr,
+                document: *address,
+                system_program: system_program::ID,
+            })
+            .args(arbiter::instruction::AddAttachment {
+                label: label.into(),
+                uri: uri.into(),
+                content_hash,
+            })
+            .signer(signer.as_ref()),
+        vec!["arbiter::AddAttachment"],
+    )
+}
+
+fn process_claim(cfg: &Config, address: &Pubkey, participant: &Option<Pubkey>) -> Result<()> {
+    let (program, signer) = create_program_client(cfg);
+
+    assert_exists!(&program, arbiter::state::Document, address);
+
+    let participant = participant.unwrap_or_else(|| signer.pubkey());
+    let mint = Pubkey::find_program_address(
+        &[arbiter::seeds::MINT, address.as_ref(), participant.as_ref()],
+        &program.id(),
+    )
+    .0;
+
+    if account_exists(&program, &mint)? {
+        return Err(anyhow!(
+            "the receipt of {} has already been claimed",
+            participant
+        ));
+    }
+
+    let receipt = token_2022::get_associated_token_address(&participant, &mint);
+
+    send_with_approval(
+        cfg,
+        program
+            .request()
+            .accounts(arbiter::accounts::ClaimReceipt {
+                payer: signer.pubkey(),
+                participant,
+                config: find_config_address(&program.id()),
+                document: *address,
+                mint,
+                receipt,
+                metadata: token_metadata::find_metadata_address(&mint),
+                token_program: token_2022::ID,
+                associated_token_program: token_2022::associated_token::ID,
+                token_metadata_program: token_metadata::ID,
+                system_program: system_program::ID,
+            })
+            .args(arbiter::instruction::ClaimReceipt {})
+            .signer(signer.as_ref()),
+        vec!["arbiter::ClaimReceipt"],
+    )?;
+
+    println!("Receipt {} minted to {}", mint, receipt);
+    Ok(())
+}
+
+fn process_compress(cfg: &Config, address: &Pubkey) -> Result<()> {
+    let (program, signer) = create_program_client(cfg);
+
+    let doc = fetch_document(&program, address)?;
+    if !doc.is_finalized() {
+        return Err(anyhow!("only finalized documents can be compressed"));
+    }
+
+    if doc.has_unclaimed_receipts() {
+        return Err(anyhow!(
+            "every signed participant must claim their receipt before compressing"
+        ));
+    }
+
+    let clerk = Pubkey::find_program_address(
+        &[arbiter::seeds::CLERK, signer.pubkey().as_ref()],
+        &program.id(),
+    )
+    .0;
+
+    let receipt = Receipt::address(address);
+
+    send_with_approval(
+        cfg,
+        program
+            .request()
+            .accounts(arbiter::accounts::CompressDocument {
+                authority: signer.pubkey(),
+                payer: signer.pubkey(),
+                config: find_config_address(&program.id()),
+                clerk,
+                document: *address,
+                receipt,
+                system_program: system_program::ID,
+            })
+            .args(arbiter::instruction::CompressDocument {})
+            .signer(signer.as_ref()),
+        vec!["arbiter::CompressDocument"],
+    )?;
+
+    println!("Document {} compressed into receipt {}", address, receipt);
+    Ok(())
+}
+
+fn process_create(
+    cfg: &Config,
+    participant_set: ParticipantSet,
+    sections: &[String],
+    prerequisites: &[Pubkey],
+    title: &str,
+    uri: &str,
+    settings: DocumentSettings,
+) -> Result<()> {
     let (program, signer) = create_program_client(cfg);
 
     let clerk_addr = Pubkey::find_program_address(
@@ -146,24 +648,119 @@ fn process_create(cfg: &Config, participants: &[Pubkey], title: &str, uri: &str)
 
     assert_not_exists!(&program, arbiter::state::Document, &doc_addr);
 
+    let not_before = settings.not_before;
+
+    let config_addr = find_config_address(&program.id());
+    let config = program.account::<arbiter::state::Config>(config_addr)?;
+
+    arbiter::uri::validate(uri, config.limits.max_uri_length as usize)
+        .map_err(|e| anyhow!("invalid document uri: {}", e))?;
+
+    let waiver_addr = find_waiver_address(&program.id(), &clerk_addr);
+    let waived = account_exists(&program, &waiver_addr)?;
+
+    let mut accounts = arbiter::accounts::InitDocument {
+        authority: signer.pubkey(),
+        payer: signer.pubkey(),
+        config: config_addr,
+        treasury: config.treasury,
+        clerk: clerk_addr,
+        document: doc_addr,
+        system_program: system_program::ID,
+    }
+    .to_account_metas(None);
+
+    if waived {
+        accounts.push(AccountMeta::new_readonly(waiver_addr, false));
+    }
+
+    send_with_fee_approval(
+        cfg,
+        program
+            .request()
+            .instruction(Instruction::new_with_borsh(
+                program.id(),
+                &arbiter::instruction::InitDocument {
+                    title: title.into(),
+                    uri: uri.into(),
+                    participant_set,
+                    sections: sections.to_vec(),
+                    prerequisites: prerequisites.to_vec(),
+                    settings,
+                },
+                accounts,
+            ))
+            .signer(signer.as_ref()),
+        vec!["arbiter::InitDocument"],
+        Some(config.document_fee).filter(|&fee| fee > 0 && !waived),
+    )?;
+
+    if let Some(t) = not_before {
+        println!(
+            "Reminder: {} can be finalized no earlier than {}",
+            doc_addr,
+            display_finalize_time(t, unix_now()?)
+        );
+    }
+
+    Ok(())
+}
+
+fn process_detach(cfg: &Config, address: &Pubkey, label: &str) -> Result<()> {
+    let (program, signer) = create_program_client(cfg);
+
+    assert_exists!(&program, arbiter::state::Document, address);
+
     send_with_approval(
         cfg,
         program
             .request()
-            .accounts(arbiter::accounts::InitDocument {
+            .accounts(arbiter::accounts::RemoveAttachment {
                 authority: signer.pubkey(),
-                payer: signer.pubkey(),
-                clerk: clerk_addr,
-                document: doc_addr,
-                system_program: system_program::ID,
+                config: find_config_address(&program.id()),
+                document: *address,
             })
-            .args(arbiter::instruction::InitDocument {
-                title: title.into(),
-                uri: uri.into(),
-                participants: participants.to_vec(),
+            .args(arbiter::instruction::RemoveAttachment {
+                label: label.into(),
             })
             .signer(signer.as_ref()),
-        vec!["arbiter::InitDocument"],
+        vec!["arbiter::RemoveAttachment"],
+    )
+}
+
+fn process_eth_message(cfg: &Config, address: &Pubkey, section: u8) -> Result<()> {
+    let (program, _) = create_program_client(cfg);
+    let doc = fetch_document(&program, address)?;
+
+    println!("0x{}", encode_hex(&doc.eth_payload(address, section)));
+    Ok(())
+}
+
+fn process_extend(
+    cfg: &Config,
+    address: &Pubkey,
+    participant: &Pubkey,
+    deadline: u64,
+) -> Result<()> {
+    let (program, signer) = create_program_client(cfg);
+
+    assert_exists!(&program, arbiter::state::Document, address);
+
+    send_with_approval(
+        cfg,
+        program
+            .request()
+            .accounts(arbiter::accounts::ExtendDeadline {
+                authority: signer.pubkey(),
+                config: find_config_address(&program.id()),
+                document: *address,
+            })
+            .args(arbiter::instruction::ExtendDeadline {
+                participant: *participant,
+                deadline,
+            })
+            .signer(signer.as_ref()),
+        vec!["arbiter::ExtendDeadline"],
     )
 }
 
@@ -178,17 +775,48 @@ fn process_finalize(cfg: &Config, address: &Pubkey) -> Result<()> {
     )
     .0;
 
+    let doc = fetch_document(&program, address)?;
+
+    if let Some(bundle) = doc.bundle {
+        return Err(anyhow!(
+            "the document can only be finalized with its bundle {}",
+            bundle
+        ));
+    }
+
+    let now = unix_now()?;
+    if !doc.can_finalize_at(now) {
+        return Err(anyhow!(
+            "the document cannot be finalized until {}",
+            display_finalize_time(doc.settings.not_before.unwrap_or_default(), now)
+        ));
+    }
+
+    let mut accounts = arbiter::accounts::Finalize {
+        authority: signer.pubkey(),
+        payer: signer.pubkey(),
+        config: find_config_address(&program.id()),
+        clerk,
+        document: *address,
+    }
+    .to_account_metas(None);
+
+    for p in &doc.prerequisites {
+        accounts.push(AccountMeta::new_readonly(
+            find_prerequisite_address(&program, p)?,
+            false,
+        ));
+    }
+
     send_with_approval(
         cfg,
         program
             .request()
-            .accounts(arbiter::accounts::Finalize {
-                authority: signer.pubkey(),
-                payer: signer.pubkey(),
-                clerk,
-                document: *address,
-            })
-            .args(arbiter::instruction::Finalize {})
+            .instruction(Instruction::new_with_borsh(
+                program.id(),
+                &arbiter::instruction::Finalize {},
+                accounts,
+            ))
             .signer(signer.as_ref()),
         vec!["arbiter::Finalize"],
     )
@@ -196,14 +824,172 @@ fn process_finalize(cfg: &Config, address: &Pubkey) -> Result<()> {
 
 fn process_get(cfg: &Config, address: &Pubkey, display: DisplayOptions) -> Result<()> {
     let (program, _) = create_program_client(cfg);
+    let doc = fetch_document(&program, address)?;
 
-    print_serialized(
-        program.account::<arbiter::state::Document>(*address)?,
-        &display,
-    )
+    print_serialized(&doc, &display)?;
+
+    if !display.is_json() {
+        if doc.version == arbiter::migration::LEGACY_VERSION {
+            println!("\nThe document uses the legacy account layout and must be migrated");
+        }
+
+        println!();
+        print_signature_matrix(&doc);
+
+        if doc.is_merkle() {
+            let signed = doc
+                .signed_bitmap
+                .iter()
+                .map(|b| b.count_ones())
+                .sum::<u32>();
+
+            println!(
+                "\nMerkle participant signatures: {} of {}",
+                signed,
+                doc.settings.participant_count as usize * doc.section_count()
+            );
+        }
+
+        if !doc.groups.is_empty() {
+            println!();
+            print_group_status(&doc);
+        }
+
+        if doc.is_weighted() {
+            println!(
+                "\nSigned weight: {} of {} required ({} total)",
+                doc.signed_weight(),
+                doc.settings.required_weight,
+                doc.total_weight()
+            );
+        }
+
+        let now = unix_now()?;
+        if let Some(t) = doc.settings.not_before.filter(|_| !doc.is_finalized()) {
+            println!("\nEarliest finalization: {}", display_finalize_time(t, now));
+        }
+
+        let overdue = doc.overdue_participants(now);
+        if !overdue.is_empty() {
+            println!("\nParticipants past their signing deadline:");
+            for p in overdue {
+                println!("  {}", p);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Prints a table of the signature timestamps for each participant (rows)
+/// and document section (columns), where unsigned pairs are shown as `-`.
+fn print_signature_matrix(doc: &arbiter::state::Document) {
+    let sections = if doc.sections.is_empty() {
+        vec!["Document".to_string()]
+    } else {
+        doc.sections
+            .iter()
+            .enumerate()
+            .map(|(i, s)| format!("[{}] {}", i, s))
+            .collect()
+    };
+
+    let widths = sections
+        .iter()
+        .map(|s| s.len().max(10))
+        .collect::<Vec<usize>>();
+
+    print!("{:<44}", "Participant");
+    for (s, w) in sections.iter().zip(&widths) {
+        print!(" | {:<w$}", s, w = w);
+    }
+    println!();
+
+    let rows = doc
+        .participants
+        .iter()
+        .map(|p| p.to_string())
+        .chain(doc.eth_participants.iter().map(display_eth_address));
+
+    for (i, p) in rows.enumerate() {
+        print!("{:<44}", p);
+        for (j, w) in widths.iter().enumerate() {
+            let ts = doc.signature_timestamps[i * sections.len() + j];
+            let cell = if ts == 0 {
+                "-".to_string()
+            } else {
+                ts.to_string()
+            };
+            print!(" | {:<w$}", cell, w = w);
+        }
+        println!();
+    }
 }
 
-fn process_sign(cfg: &Config, address: &Pubkey) -> Result<()> {
+/// Prints the quorum status of each signer group followed by the members
+/// of the group and whether they have signed every document section.
+fn print_group_status(doc: &arbiter::state::Document) {
+    for g in &doc.groups {
+        let signed = doc.group_signatures(g);
+        println!(
+            "{}: {} of {} signed, {} required{}",
+            g.name,
+            signed,
+            g.members.len(),
+            g.threshold,
+            if signed >= g.threshold as usize {
+                " (met)"
+            } else {
+                ""
+            }
+        );
+
+        for &m in &g.members {
+            let i = m as usize;
+            println!(
+                "  {:<44} {}",
+                doc.participants[i].to_string(),
+                if doc.has_signed_all_sections(i) {
+                    "signed"
+                } else {
+                    "pending"
+                }
+            );
+        }
+    }
+}
+
+fn process_migrate(cfg: &Config, address: &Pubkey) -> Result<()> {
+    let (program, signer) = create_program_client(cfg);
+
+    if fetch_document(&program, address)?.version != arbiter::migration::LEGACY_VERSION {
+        println!("Document {} is already migrated", address);
+        return Ok(());
+    }
+
+    // Documents with many participants grow over several instructions
+    // and remain in the legacy layout until the final one.
+    while fetch_document(&program, address)?.version == arbiter::migration::LEGACY_VERSION {
+        send_with_approval(
+            cfg,
+            program
+                .request()
+                .accounts(arbiter::accounts::MigrateDocument {
+                    payer: signer.pubkey(),
+                    config: find_config_address(&program.id()),
+                    document: *address,
+                    system_program: system_program::ID,
+                })
+                .args(arbiter::instruction::MigrateDocument {})
+                .signer(signer.as_ref()),
+            vec!["arbiter::MigrateDocument"],
+        )?;
+    }
+
+    Ok(())
+}
+
+fn process_open(cfg: &Config, address: &Pubkey) -> Result<()> {
     let (program, signer) = create_program_client(cfg);
 
     assert_exists!(&program, arbiter::state::Document, address);
@@ -212,12 +998,454 @@ fn process_sign(cfg: &Config, address: &Pubkey) -> Result<()> {
         cfg,
         program
             .request()
-            .accounts(arbiter::accounts::AddSignature {
-                participant: signer.pubkey(),
+            .accounts(arbiter::accounts::OpenForSigning {
+                authority: signer.pubkey(),
+                config: find_config_address(&program.id()),
                 document: *address,
             })
-            .args(arbiter::instruction::AddSignature {})
+            .args(arbiter::instruction::OpenForSigning {})
+            .signer(signer.as_ref()),
+        vec!["arbiter::OpenForSigning"],
+    )
+}
+
+fn process_rotate(
+    cfg: &Config,
+    address: &Pubkey,
+    cosigner: &str,
+    old: &Pubkey,
+    new: &Pubkey,
+    reset_signature: bool,
+) -> Result<()> {
+    let (program, signer) = create_program_client(cfg);
+
+    assert_exists!(&program, arbiter::state::Document, address);
+
+    let cosigner_keypair = read_keypair_file(&*shellexpand::tilde(cosigner))
+        .map_err(|e| anyhow!("failed to read cosigner keypair: {}", e))?;
+
+    if cosigner_keypair.pubkey() != *old && cosigner_keypair.pubkey() != *new {
+        return Err(anyhow!(
+            "cosigner {} must be either the old or new participant",
+            cosigner_keypair.pubkey()
+        ));
+    }
+
+    let mut accounts = arbiter::accounts::RotateParticipant {
+        authority: signer.pubkey(),
+        old_participant: *old,
+        new_participant: *new,
+        config: find_config_address(&program.id()),
+        document: *address,
+    }
+    .to_account_metas(None);
+
+    accounts
+        .iter_mut()
+        .filter(|m| m.pubkey == cosigner_keypair.pubkey())
+        .for_each(|m| m.is_signer = true);
+
+    send_with_approval(
+        cfg,
+        program
+            .request()
+            .instruction(Instruction::new_with_borsh(
+                program.id(),
+                &arbiter::instruction::RotateParticipant { reset_signature },
+                accounts,
+            ))
+            .signer(signer.as_ref())
+            .signer(&cosigner_keypair),
+        vec!["arbiter::RotateParticipant"],
+    )
+}
+
+fn process_sign(
+    cfg: &Config,
+    address: &Pubkey,
+    memo: &Option<String>,
+    section: u8,
+    on_behalf_of: &Option<Pubkey>,
+    proofs: &Option<String>,
+) -> Result<()> {
+    let (program, signer) = create_program_client(cfg);
+
+    assert_exists!(&program, arbiter::state::Document, address);
+
+    let participant = on_behalf_of.unwrap_or_else(|| signer.pubkey());
+
+    let mut accounts = arbiter::accounts::AddSignature {
+        signer: signer.pubkey(),
+        participant,
+        config: find_config_address(&program.id()),
+        document: *address,
+    }
+    .to_account_metas(None);
+
+    if participant != signer.pubkey() {
+        let delegation = find_delegation_address(&program.id(), &participant, &signer.pubkey());
+        assert_exists!(&program, arbiter::state::Delegation, &delegation);
+        accounts.push(AccountMeta::new_readonly(delegation, false));
+    }
+
+    let proof = proofs
+        .as_deref()
+        .map(|path| read_proof(path, &participant))
+        .transpose()?;
+
+    send_with_approval(
+        cfg,
+        program
+            .request()
+            .instruction(Instruction::new_with_borsh(
+                program.id(),
+                &arbiter::instruction::AddSignature {
+                    section,
+                    memo: memo.as_deref().map(encode_memo),
+                    proof,
+                },
+                accounts,
+            ))
             .signer(signer.as_ref()),
         vec!["arbiter::AddSignature"],
     )
 }
+
+fn process_sign_eth(
+    cfg: &Config,
+    address: &Pubkey,
+    eth_address: &[u8; 20],
+    section: u8,
+    signature: &[u8; 65],
+) -> Result<()> {
+    let (program, signer) = create_program_client(cfg);
+
+    assert_exists!(&program, arbiter::state::Document, address);
+
+    let doc = fetch_document(&program, address)?;
+    if !doc.eth_participants.contains(eth_address) {
+        return Err(anyhow!(
+            "{} is not a participant of the document",
+            display_eth_address(eth_address)
+        ));
+    }
+
+    send_with_approval(
+        cfg,
+        program
+            .request()
+            .instruction(new_secp256k1_instruction(
+                eth_address,
+                signature,
+                &doc.eth_message(address, section),
+            ))
+            .accounts(arbiter::accounts::SubmitEthSignature {
+                submitter: signer.pubkey(),
+                config: find_config_address(&program.id()),
+                document: *address,
+                instructions_sysvar: sysvar::instructions::ID,
+            })
+            .args(arbiter::instruction::SubmitEthSignature {
+                eth_address: *eth_address,
+                section,
+            })
+            .signer(signer.as_ref()),
+        vec!["secp256k1::Verify", "arbiter::SubmitEthSignature"],
+    )
+}
+
+fn process_update(
+    cfg: &Config,
+    address: &Pubkey,
+    uri: &Option<String>,
+    participants: &[Pubkey],
+    requires_acceptance: Option<bool>,
+) -> Result<()> {
+    let (program, signer) = create_program_client(cfg);
+    let doc = fetch_document(&program, address)?;
+
+    if !doc.is_draft() && (!participants.is_empty() || requires_acceptance.is_some()) {
+        return Err(anyhow!(
+            "only the uri can be updated once a document is open for signing"
+        ));
+    }
+
+    let config_addr = find_config_address(&program.id());
+
+    if let Some(u) = uri {
+        let config = program.account::<arbiter::state::Config>(config_addr)?;
+        arbiter::uri::validate(u, config.limits.max_uri_length as usize)
+            .map_err(|e| anyhow!("invalid document uri: {}", e))?;
+
+        if doc.has_signatures() {
+            println!("Changing the URI will invalidate every existing signature\n");
+        }
+    }
+
+    let settings = requires_acceptance.map(|r| DocumentSettings {
+        requires_acceptance: r,
+        ..doc.settings
+    });
+
+    send_with_approval(
+        cfg,
+        program
+            .request()
+            .accounts(arbiter::accounts::UpdateDocument {
+                authority: signer.pubkey(),
+                payer: signer.pubkey(),
+                config: config_addr,
+                document: *address,
+                system_program: system_program::ID,
+            })
+            .args(arbiter::instruction::UpdateDocument {
+                uri: uri.clone(),
+                participants: Some(participants.to_vec()).filter(|p| !p.is_empty()),
+                settings,
+            })
+            .signer(signer.as_ref()),
+        vec!["arbiter::UpdateDocument"],
+    )
+}
+
+fn process_verify(
+    cfg: &Config,
+    address: &Pubkey,
+    participant: &Pubkey,
+    participants_csv: &Option<String>,
+    proofs: &Option<String>,
+) -> Result<()> {
+    let (program, _) = create_program_client(cfg);
+
+    let receipt_addr = Receipt::address(address);
+    assert_exists!(&program, Receipt, &receipt_addr);
+
+    let receipt = program.account::<Receipt>(receipt_addr)?;
+
+    let proof = match (participants_csv, proofs) {
+        (Some(csv), _) => {
+            let (members, tree) = read_participants_csv(csv)?;
+            let index = members
+                .iter()
+                .position(|m| m == participant)
+                .ok_or_else(|| anyhow!("{} is not in the participants csv", participant))?;
+
+            ParticipantProof {
+                index: index as u32,
+                path: tree.proof(index),
+            }
+        }
+        (None, Some(path)) => read_proof(path, participant)?,
+        (None, None) => unreachable!(),
+    };
+
+    if !receipt.verify_participant(participant, proof.index, &proof.path) {
+        return Err(anyhow!(
+            "{} is not a participant of document {}",
+            participant,
+            address
+        ));
+    }
+
+    println!(
+        "{} is participant {} of document {}",
+        participant, proof.index, address
+    );
+    Ok(())
+}
+
+/// Builds a secp256k1 program instruction at the first transaction index that
+/// verifies a single recoverable Ethereum signature of the argued message.
+fn new_secp256k1_instruction(
+    eth_address: &[u8; 20],
+    signature: &[u8; 65],
+    message: &[u8],
+) -> Instruction {
+    const HEADER_SIZE: u16 = 12;
+
+    let address_offset = HEADER_SIZE;
+    let signature_offset = address_offset + 20;
+    let message_offset = signature_offset + 65;
+
+    let mut data = vec![1];
+    data.extend_from_slice(&signature_offset.to_le_bytes());
+    data.push(0);
+    data.extend_from_slice(&address_offset.to_le_bytes());
+    data.push(0);
+    data.extend_from_slice(&message_offset.to_le_bytes());
+    data.extend_from_slice(&(message.len() as u16).to_le_bytes());
+    data.push(0);
+
+    data.extend_from_slice(eth_address);
+    data.extend_from_slice(&signature[..64]);
+    data.push(match signature[64] {
+        v @ 27..=28 => v - 27,
+        v => v,
+    });
+    data.extend_from_slice(message);
+
+    Instruction {
+        program_id: secp256k1_program::ID,
+        accounts: vec![],
+        data,
+    }
+}
+
+/// Returns the current unix timestamp of the local system clock.
+fn unix_now() -> Result<u64> {
+    Ok(SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs())
+}
+
+/// Displays the earliest finalization unix timestamp along with
+/// the time remaining until it is reached from the argued `now`.
+fn display_finalize_time(not_before: u64, now: u64) -> String {
+    if not_before <= now {
+        return format!("{} (now)", not_before);
+    }
+
+    let secs = not_before - now;
+    format!(
+        "{} (in {}d {}h {}m)",
+        not_before,
+        secs / 86_400,
+        secs % 86_400 / 3_600,
+        secs % 3_600 / 60
+    )
+}
+
+/// Decodes a `0x` prefixed hex string into the bytes it represents.
+fn decode_hex(s: &str) -> Result<Vec<u8>> {
+    let s = s.strip_prefix("0x").unwrap_or(s);
+    if s.len() % 2 != 0 {
+        return Err(anyhow!("hex string has an odd length"));
+    }
+
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).map_err(|e| anyhow!(e)))
+        .collect()
+}
+
+/// Encodes the bytes as a lowercase hex string without a prefix.
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// A signer group command line argument whose members are participant pubkeys.
+#[derive(Clone, Debug)]
+pub struct GroupArg {
+    name: String,
+    threshold: u8,
+    members: Vec<Pubkey>,
+}
+
+impl GroupArg {
+    /// Converts the argument into a `SignerGroup` by replacing each member
+    /// pubkey with its index in the argued list of participants.
+    fn to_signer_group(&self, participants: &[Pubkey]) -> Result<SignerGroup> {
+        let members = self
+            .members
+            .iter()
+            .map(|m| {
+                participants
+                    .iter()
+                    .position(|p| p == m)
+                    .and_then(|i| u8::try_from(i).ok())
+                    .ok_or_else(|| anyhow!("group member {} is not a listed participant", m))
+            })
+            .collect::<Result<Vec<u8>>>()?;
+
+        Ok(SignerGroup {
+            name: self.name.clone(),
+            threshold: self.threshold,
+            members,
+        })
+    }
+}
+
+/// Parses a `NAME:THRESHOLD:PUBKEY,PUBKEY,...` signer group command line argument.
+fn parse_group(s: &str) -> Result<GroupArg> {
+    let parts = s.splitn(3, ':').collect::<Vec<&str>>();
+    if parts.len() != 3 {
+        return Err(anyhow!(
+            "groups must be formatted as NAME:THRESHOLD:PUBKEY,..."
+        ));
+    }
+
+    Ok(GroupArg {
+        name: parts[0].into(),
+        threshold: parts[1].parse()?,
+        members: parts[2]
+            .split(',')
+            .map(|m| m.trim().parse::<Pubkey>().map_err(|e| anyhow!(e)))
+            .collect::<Result<Vec<Pubkey>>>()?,
+    })
+}
+
+/// Parses an Ethereum address command line argument.
+fn parse_eth_address(s: &str) -> Result<[u8; 20]> {
+    decode_hex(s)?
+        .try_into()
+        .map_err(|_| anyhow!("ethereum addresses must be 20 bytes"))
+}
+
+/// Parses a recoverable Ethereum signature command line argument.
+fn parse_eth_signature(s: &str) -> Result<[u8; 65]> {
+    decode_hex(s)?
+        .try_into()
+        .map_err(|_| anyhow!("ethereum signatures must be 65 bytes"))
+}
+
+/// Encodes a signature memo into the fixed size bytes stored on chain, where
+/// memos that are too long to fit are replaced by their SHA-256 hash.
+fn encode_memo(memo: &str) -> [u8; 32] {
+    let bytes = memo.as_bytes();
+    if bytes.len() > arbiter::state::Document::MEMO_SIZE {
+        return hash(bytes).to_bytes();
+    }
+
+    let mut encoded = [0; 32];
+    encoded[..bytes.len()].copy_from_slice(bytes);
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_memo_is_stored_as_text() {
+        let memo = encode_memo("subject to clause 4");
+        assert_eq!(&memo[..19], b"subject to clause 4");
+        assert!(memo[19..].iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn eth_address_parsing() {
+        let address = parse_eth_address("0x52908400098527886E0F7030069857D2E4169EE7").unwrap();
+        assert_eq!(address[0], 0x52);
+        assert_eq!(address[19], 0xe7);
+
+        assert!(parse_eth_address("0x5290").is_err());
+        assert!(parse_eth_address("0xzz908400098527886E0F7030069857D2E4169EE7").is_err());
+    }
+
+    #[test]
+    fn secp256k1_instruction_layout() {
+        let mut signature = [0x22; 65];
+        signature[64] = 28;
+
+        let ix = new_secp256k1_instruction(&[0x11; 20], &signature, b"message");
+        assert_eq!(ix.data[0], 1);
+        assert_eq!(&ix.data[12..32], &[0x11; 20]);
+        assert_eq!(&ix.data[32..96], &[0x22; 64]);
+        assert_eq!(ix.data[96], 1);
+        assert_eq!(&ix.data[97..], b"message");
+    }
+
+    #[test]
+    fn long_memo_is_hashed() {
+        let text = "signed subject to the clause 4 redline from the counterparty";
+        assert_eq!(encode_memo(text), hash(text.as_bytes()).to_bytes());
+    }
+}
@@ -1,2 +1,5 @@
+pub mod admin;
+pub mod bundle;
 pub mod clerk;
+pub mod delegate;
 pub mod document;
@@ -5,6 +5,7 @@ mod cmd;
 mod config;
 mod macros;
 mod program;
+mod proofs;
 mod terminal;
 
 use cmd::*;
@@ -26,12 +27,30 @@ pub struct Opts {
 /// The variants for each subcommand group to attach to the top-level handler.
 #[derive(Parser)]
 enum Command {
+    /// Administer the global program configuration.
+    Admin {
+        /// The subcommand to invoke as the program admin.
+        #[clap(subcommand)]
+        subcmd: admin::AdminCommand,
+    },
+    /// Interact with a bundle of linked documents.
+    Bundle {
+        /// The subcommand to invoke on the bundle.
+        #[clap(subcommand)]
+        subcmd: bundle::BundleCommand,
+    },
     /// Interact with a clerk program account.
     Clerk {
         /// The subcommand to invoke on the clerk.
         #[clap(subcommand)]
         subcmd: clerk::ClerkCommand,
     },
+    /// Manage the delegates authorized to sign on your behalf.
+    Delegate {
+        /// The subcommand to invoke on the delegation.
+        #[clap(subcommand)]
+        subcmd: delegate::DelegateCommand,
+    },
     /// Interact with a document program account.
     Document {
         /// The subcommand to invoke on the document.
@@ -43,7 +62,10 @@ enum Command {
 pub fn run(opts: Opts) -> Result<()> {
     let cfg = Config::new(&opts.cfg)?;
     match opts.cmd {
+        Command::Admin { subcmd } => admin::entry(&cfg, &subcmd),
+        Command::Bundle { subcmd } => bundle::entry(&cfg, &subcmd),
         Command::Clerk { subcmd } => clerk::entry(&cfg, &subcmd),
+        Command::Delegate { subcmd } => delegate::entry(&cfg, &subcmd),
         Command::Document { subcmd } => document::entry(&cfg, &subcmd),
     }
 }
@@ -2,7 +2,9 @@ use anchor_client::solana_sdk::commitment_config::CommitmentConfig;
 use anchor_client::solana_sdk::pubkey::Pubkey;
 use anchor_client::solana_sdk::signature::Keypair;
 use anchor_client::{Client, Program, RequestBuilder};
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use arbiter::migration;
+use arbiter::state::{Clerk, Document};
 use std::rc::Rc;
 
 use super::config::Config;
@@ -15,6 +17,20 @@ pub fn account_exists(program: &Program, public_key: &Pubkey) -> Result<bool> {
     Ok(info.value.is_some())
 }
 
+/// Fetches the `Clerk` program account at the argued public key, which is
+/// read with the `LEGACY_VERSION` if it has not yet been migrated.
+pub fn fetch_clerk(program: &Program, address: &Pubkey) -> Result<Clerk> {
+    let data = program.rpc().get_account_data(address)?;
+    migration::try_deserialize_clerk(address, &data).map_err(|e| anyhow!("{}", e))
+}
+
+/// Fetches the `Document` program account at the argued public key, which is
+/// read with the `LEGACY_VERSION` if it has not yet been migrated.
+pub fn fetch_document(program: &Program, address: &Pubkey) -> Result<Document> {
+    let data = program.rpc().get_account_data(address)?;
+    migration::try_deserialize_document(address, &data).map_err(|e| anyhow!("{}", e))
+}
+
 /// Handle the instantiation of a program client and the
 /// designating signer keypair for the argued config and program ID.
 pub fn create_program_client(config: &Config) -> (Program, Rc<Keypair>) {
@@ -33,7 +49,19 @@ pub fn create_program_client(config: &Config) -> (Program, Rc<Keypair>) {
 /// sent, confirmed and log the signature hash based on the
 /// detected verbosity setting in the exposed configuration.
 pub fn send_with_approval(config: &Config, req: RequestBuilder, ix_names: Vec<&str>) -> Result<()> {
-    request_approval(config, Some(ix_names))?;
+    send_with_fee_approval(config, req, ix_names, None)
+}
+
+/// Identical to `send_with_approval` but displays the protocol
+/// fee in lamports that the transaction will charge, if any,
+/// in the approval prompt before it is sent.
+pub fn send_with_fee_approval(
+    config: &Config,
+    req: RequestBuilder,
+    ix_names: Vec<&str>,
+    fee: Option<u64>,
+) -> Result<()> {
+    request_approval(config, Some(ix_names), fee)?;
 
     let sp = Spinner::new("Sending transaction");
     let sig = req.send()?;
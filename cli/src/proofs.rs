@@ -0,0 +1,130 @@
+use anchor_client::solana_sdk::pubkey::Pubkey;
+use anyhow::{anyhow, Result};
+use arbiter::merkle::MerkleTree;
+use arbiter::state::ParticipantProof;
+use serde_json::{json, Map, Value};
+use std::fs;
+use std::str::FromStr;
+
+/// Parses the participant public keys from the first column of each CSV
+/// row, where a first row that is not a valid public key is a header.
+pub fn parse_participants_csv(contents: &str) -> Result<Vec<Pubkey>> {
+    let mut participants = Vec::new();
+
+    for (i, line) in contents.lines().enumerate() {
+        let field = line.split(',').next().unwrap_or_default().trim();
+        if field.is_empty() {
+            continue;
+        }
+
+        match Pubkey::from_str(field) {
+            Ok(p) => participants.push(p),
+            Err(_) if i == 0 => continue,
+            Err(_) => return Err(anyhow!("invalid participant pubkey on line {}", i + 1)),
+        }
+    }
+
+    if participants.is_empty() {
+        return Err(anyhow!("no participants were found in the csv"));
+    }
+
+    Ok(participants)
+}
+
+/// Reads the participants CSV at the argued path and builds its Merkle tree.
+pub fn read_participants_csv(path: &str) -> Result<(Vec<Pubkey>, MerkleTree)> {
+    let participants = parse_participants_csv(&fs::read_to_string(&*shellexpand::tilde(path))?)?;
+    let tree = MerkleTree::new(&participants);
+    Ok((participants, tree))
+}
+
+/// Serializes the root and the inclusion proof of every participant in the
+/// tree into the JSON file that is distributed to the signers.
+pub fn write_proofs(path: &str, participants: &[Pubkey], tree: &MerkleTree) -> Result<()> {
+    let proofs = participants
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            let proof = json!({
+                "index": i,
+                "path": tree.proof(i).iter().map(encode).collect::<Vec<String>>(),
+            });
+            (p.to_string(), proof)
+        })
+        .collect::<Map<String, Value>>();
+
+    let contents = json!({
+        "root": encode(&tree.root()),
+        "proofs": proofs,
+    });
+
+    fs::write(
+        &*shellexpand::tilde(path),
+        serde_json::to_string_pretty(&contents)?,
+    )?;
+
+    Ok(())
+}
+
+/// Reads the inclusion proof of the argued participant from a proofs JSON file.
+pub fn read_proof(path: &str, participant: &Pubkey) -> Result<ParticipantProof> {
+    let contents: Value = serde_json::from_str(&fs::read_to_string(&*shellexpand::tilde(path))?)?;
+    let proof = &contents["proofs"][participant.to_string()];
+
+    let index = proof["index"]
+        .as_u64()
+        .ok_or_else(|| anyhow!("no proof was found for {}", participant))?;
+
+    let path = proof["path"]
+        .as_array()
+        .ok_or_else(|| anyhow!("the proof for {} has no path", participant))?
+        .iter()
+        .map(|h| decode(h.as_str().unwrap_or_default()))
+        .collect::<Result<Vec<[u8; 32]>>>()?;
+
+    Ok(ParticipantProof {
+        index: index as u32,
+        path,
+    })
+}
+
+/// Encodes a hash as a lowercase hex string.
+fn encode(hash: &[u8; 32]) -> String {
+    hash.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Decodes a hex string into a hash.
+fn decode(s: &str) -> Result<[u8; 32]> {
+    if s.len() != 64 {
+        return Err(anyhow!("proof hashes must be 32 bytes"));
+    }
+
+    let mut hash = [0; 32];
+    for (i, b) in hash.iter_mut().enumerate() {
+        *b = u8::from_str_radix(&s[i * 2..i * 2 + 2], 16)?;
+    }
+    Ok(hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_header_is_skipped() {
+        let a = Pubkey::new_unique();
+        let b = Pubkey::new_unique();
+        let csv = format!("pubkey,name\n{},Alice\n\n{}, Bob\n", a, b);
+
+        assert_eq!(parse_participants_csv(&csv).unwrap(), vec![a, b]);
+        assert!(parse_participants_csv("pubkey\n").is_err());
+        assert!(parse_participants_csv(&format!("{}\nnot-a-key\n", a)).is_err());
+    }
+
+    #[test]
+    fn hash_hex_roundtrip() {
+        let hash = [0xa5; 32];
+        assert_eq!(decode(&encode(&hash)).unwrap(), hash);
+        assert!(decode("a5").is_err());
+    }
+}
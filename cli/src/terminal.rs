@@ -1,3 +1,4 @@
+use anchor_client::solana_sdk::native_token::LAMPORTS_PER_SOL;
 use anyhow::{anyhow, Result};
 use dialoguer::Confirm;
 use indicatif::{ProgressBar, ProgressStyle};
@@ -20,6 +21,11 @@ impl DisplayOptions {
     pub fn from_args(json: bool, pretty: bool) -> Self {
         Self { json, pretty }
     }
+
+    /// Whether the data is being displayed as JSON.
+    pub fn is_json(&self) -> bool {
+        self.json
+    }
 }
 
 /// Internal wrapper for the `indicatif::ProgressBar`.
@@ -76,14 +82,24 @@ pub fn print_serialized(s: impl Debug + Serialize, opts: &DisplayOptions) -> Res
     Ok(())
 }
 
+/// Formats an amount of lamports as its SOL value along with the raw lamports.
+pub fn display_lamports(lamports: u64) -> String {
+    format!(
+        "{} SOL ({} lamports)",
+        lamports as f64 / LAMPORTS_PER_SOL as f64,
+        lamports
+    )
+}
+
 /// Provides the user a confirmation `(y/N)` option in their terminal
 /// to request approval to sign and send the compiled transaction(s)
 /// using the configured keypair that was discovered or pointed to
 /// based on the auto-approval flag set/unset in the command.
 ///
 /// This should be called prior to sending any transactions on
-/// behalf of the end user.
-pub fn request_approval(config: &Config, ixs: Option<Vec<&str>>) -> Result<()> {
+/// behalf of the end user. Any protocol `fee` in lamports that the
+/// transaction(s) will charge is displayed before the prompt.
+pub fn request_approval(config: &Config, ixs: Option<Vec<&str>>, fee: Option<u64>) -> Result<()> {
     if let Some(names) = ixs {
         println!("Instructions to be processed:");
         names
@@ -93,6 +109,11 @@ pub fn request_approval(config: &Config, ixs: Option<Vec<&str>>) -> Result<()> {
         println!();
     }
 
+    if let Some(lamports) = fee {
+        println!("Protocol fee: {}", display_lamports(lamports));
+        println!();
+    }
+
     if config.auto_approved {
         return Ok(());
     }
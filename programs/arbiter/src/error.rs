@@ -1,5 +1,7 @@
 use anchor_lang::prelude::error_code;
 
+// New variants are appended after the existing ones so that the
+// error codes already known to deployed clients do not change.
 #[error_code]
 pub enum ErrorCode {
     #[msg("The clerk account's list of documents has reached the current limit.")]
@@ -23,7 +25,7 @@ pub enum ErrorCode {
     #[msg("The document does not have all participant signatuers.")]
     DocumentIsMissingSignatures,
 
-    #[msg("The participants list for the new document was empty.")]
+    #[msg("The participants lists for the new document were both empty.")]
     EmptyDocumentParticipants,
 
     #[msg("The title provided for the new document was empty.")]
@@ -44,9 +46,209 @@ pub enum ErrorCode {
     #[msg("The participant has already signed the provided document.")]
     ParticipantAlreadySigned,
 
-    #[msg("The list of participant public keys contain duplicates.")]
+    #[msg("The list of participant public keys or Ethereum addresses contain duplicates.")]
     ParticipantsAreNotUnique,
 
     #[msg("The participant public key is not associated with the document.")]
     ParticipantNotAssociated,
+
+    #[msg("The delegate public key provided is the same as the delegating participant.")]
+    DelegateIsParticipant,
+
+    #[msg("The delegation provided does not authorize the signer for the participant.")]
+    DelegationAuthorityMisMatch,
+
+    #[msg("The delegation provided is scoped to a different document.")]
+    DelegationDocumentMisMatch,
+
+    #[msg("The delegation provided has passed its expiration timestamp.")]
+    DelegationExpired,
+
+    #[msg("The expiration timestamp provided for the delegation is not in the future.")]
+    DelegationExpiryInPast,
+
+    #[msg("The signer is not the participant and no delegation account was provided.")]
+    DelegationNotProvided,
+
+    #[msg("Neither the current nor the replacement participant signed the rotation.")]
+    ParticipantRotationNotApproved,
+
+    #[msg("The document does not require participants to accept their invitations.")]
+    DocumentDoesNotRequireAcceptance,
+
+    #[msg("The participant has already accepted their invitation to the document.")]
+    ParticipantAlreadyAccepted,
+
+    #[msg("The participant has not accepted their invitation to the document.")]
+    ParticipantHasNotAccepted,
+
+    #[msg("A section name provided for the new document was empty.")]
+    EmptyDocumentSection,
+
+    #[msg("The section index provided does not exist on the document.")]
+    InvalidDocumentSection,
+
+    #[msg("The number of sections provided for the new document exceeds the maximum.")]
+    TooManyDocumentSections,
+
+    #[msg("The list of document public keys for the bundle contain duplicates.")]
+    BundleDocumentsAreNotUnique,
+
+    #[msg("The document accounts provided do not match the documents in the bundle.")]
+    BundleDocumentsMisMatch,
+
+    #[msg("The bundle has already been finalized along with all of its documents.")]
+    BundleIsAlreadyFinalized,
+
+    #[msg("The documents list for the new bundle was empty.")]
+    EmptyBundleDocuments,
+
+    #[msg("The name provided for the new bundle was empty.")]
+    EmptyBundleName,
+
+    #[msg("The document belongs to a bundle and can only be finalized along with it.")]
+    DocumentIsBundled,
+
+    #[msg("A prerequisite document has not been finalized.")]
+    PrerequisiteNotFinalized,
+
+    #[msg("A prerequisite document account was not provided.")]
+    PrerequisiteNotProvided,
+
+    #[msg(
+        "The list of prerequisite document public keys contain duplicates or the document itself."
+    )]
+    PrerequisitesAreNotUnique,
+
+    #[msg("The verified secp256k1 signature does not match the participant and document message.")]
+    EthSignatureMisMatch,
+
+    #[msg("The preceding instruction is not a single secp256k1 signature verification.")]
+    InvalidEthSignatureInstruction,
+
+    #[msg("The proof provided does not include the participant in the document's Merkle participant set.")]
+    InvalidParticipantProof,
+
+    #[msg("A Merkle participant set must have a root and non-zero count, be exclusive of listed participants and not require acceptance.")]
+    InvalidParticipantSet,
+
+    #[msg("The document limit for the clerk exceeds the configured maximum.")]
+    ClerkLimitTooLarge,
+
+    #[msg("The signer is not the upgrade authority of the program.")]
+    ConfigAdminMisMatch,
+
+    #[msg("The title provided for the new document exceeds the configured maximum length.")]
+    DocumentTitleTooLong,
+
+    #[msg("The URI provided for the new document exceeds the configured maximum length.")]
+    DocumentUriTooLong,
+
+    #[msg("Every configured limit must be greater than zero.")]
+    InvalidConfigLimits,
+
+    #[msg("The program data account provided does not belong to the program.")]
+    ProgramDataMisMatch,
+
+    #[msg("The number of participants on the document exceeds the configured maximum.")]
+    TooManyDocumentParticipants,
+
+    #[msg("The program has been paused by the admin.")]
+    ProgramPaused,
+
+    #[msg("The fee waiver provided was not granted to the document's clerk.")]
+    FeeWaiverMisMatch,
+
+    #[msg("The treasury account provided does not match the configured treasury.")]
+    TreasuryMisMatch,
+
+    #[msg("The URI provided for the new document contains whitespace or control characters.")]
+    DocumentUriHasInvalidCharacters,
+
+    #[msg("The URI provided for the new document has no location after its scheme.")]
+    InvalidDocumentUri,
+
+    #[msg("The URI provided for the new document does not use an allowed scheme.")]
+    InvalidDocumentUriScheme,
+
+    #[msg("An attachment with the provided label already exists on the document.")]
+    AttachmentLabelAlreadyUsed,
+
+    #[msg("The label provided for the attachment exceeds the maximum length.")]
+    AttachmentLabelTooLong,
+
+    #[msg("No attachment with the provided label exists on the document.")]
+    AttachmentNotFound,
+
+    #[msg("The document cannot be changed after a participant has signed it.")]
+    DocumentHasSignatures,
+
+    #[msg("The label provided for the attachment was empty.")]
+    EmptyAttachmentLabel,
+
+    #[msg("The document already holds the maximum number of attachments.")]
+    TooManyDocumentAttachments,
+
+    #[msg("The document is a draft that has not been opened for signing.")]
+    DocumentIsDraft,
+
+    #[msg("The document has already been opened for signing.")]
+    DocumentIsNotDraft,
+
+    #[msg("The new deadline is not later than the participant's existing deadline.")]
+    DeadlineNotExtended,
+
+    #[msg(
+        "The deadlines provided must be empty or one per participant, each zero or in the future."
+    )]
+    InvalidParticipantDeadlines,
+
+    #[msg("The participant's deadline for signing the document has passed.")]
+    ParticipantDeadlinePassed,
+
+    #[msg("The weights provided must be empty or one per participant.")]
+    InvalidParticipantWeights,
+
+    #[msg("The required weight must be reachable by the listed participants and cannot be set with Ethereum or Merkle participants.")]
+    InvalidRequiredWeight,
+
+    #[msg("Signer groups must have unique names, reachable thresholds and members listed in at most one group.")]
+    InvalidSignerGroups,
+
+    #[msg("The document cannot be finalized before its not before timestamp.")]
+    FinalizationTooEarly,
+
+    #[msg("The document has not been finalized.")]
+    DocumentIsNotFinalized,
+
+    #[msg("The participant has not signed every section of the document.")]
+    ParticipantHasNotSigned,
+
+    #[msg("The participant has already claimed their receipt for the document.")]
+    ReceiptAlreadyClaimed,
+
+    #[msg("The document URI exceeds the maximum length of a receipt's token metadata URI.")]
+    ReceiptUriTooLong,
+
+    #[msg("A participant that signed the document has not claimed their receipt.")]
+    ReceiptsAreUnclaimed,
+
+    #[msg("The program account is already stored in the current layout version.")]
+    AccountAlreadyMigrated,
+
+    #[msg("The participants of a large document must be added in ascending order.")]
+    ParticipantsAreNotSorted,
+
+    #[msg("A memo cannot be attached to a signature made with a participant proof.")]
+    MemoNotAllowedWithProof,
+}
+
+/// Returns the code number of an error returned by the program, which is compared
+/// in tests because `anchor_lang::error::Error` does not implement `PartialEq`.
+#[cfg(test)]
+pub fn error_code_number(err: anchor_lang::error::Error) -> u32 {
+    match err {
+        anchor_lang::error::Error::AnchorError(e) => e.error_code_number,
+        anchor_lang::error::Error::ProgramError(e) => panic!("unexpected {:?}", e),
+    }
 }
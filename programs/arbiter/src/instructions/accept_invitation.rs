@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::seeds;
+use crate::state::{Config, Document};
+
+#[derive(Accounts)]
+pub struct AcceptInvitation<'info> {
+    /// The participant listed on the `document` that is
+    /// consenting to be a required signer.
+    pub participant: Signer<'info>,
+
+    /// The global `Config` program account that must not be paused.
+    #[account(
+        seeds = [seeds::CONFIG],
+        bump = config.bump[0],
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The `Document` program account that the `participant`
+    /// is accepting their invitation to.
+    #[account(
+        mut,
+        seeds = [
+            seeds::DOCUMENT,
+            document.authority.as_ref(),
+            Document::title_seed(&document.title),
+        ],
+        bump = document.bump[0],
+        constraint = !document.is_finalized() @ ErrorCode::DocumentIsAlreadyFinalized,
+        constraint = document.settings.requires_acceptance @ ErrorCode::DocumentDoesNotRequireAcceptance,
+    )]
+    pub document: Account<'info, Document>,
+}
+
+impl<'info> AcceptInvitation<'info> {
+    /// Instruction prevalidation for `accept_invitation`.
+    pub fn prevalidate(ctx: &Context<Self>) -> Result<()> {
+        require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+        Ok(())
+    }
+}
+
+#[event]
+pub struct InvitationAccepted {
+    pub document: Pubkey,
+    pub participant: Pubkey,
+}
+
+/// Instruction entrypoint handler for `accept_invitation`.
+pub fn accept_invitation_handler(ctx: Context<AcceptInvitation>) -> Result<()> {
+    let AcceptInvitation {
+        participant,
+        document,
+        ..
+    } = ctx.accounts;
+
+    document.try_accept(&participant.key())?;
+
+    emit!(InvitationAccepted {
+        document: document.key(),
+        participant: participant.key(),
+    });
+
+    Ok(())
+}
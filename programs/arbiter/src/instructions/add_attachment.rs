@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::error::ErrorCode;
+use crate::seeds;
+use crate::state::{Attachment, Config, Document};
+use crate::uri;
+
+#[derive(Accounts)]
+#[instruction(label: String)]
+pub struct AddAttachment<'info> {
+    /// The system account that is signing the transaction and
+    /// is the authority of the `document` being updated.
+    pub authority: Signer<'info>,
+
+    /// The wallet paying for the reallocation rent of the `document`.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The global `Config` program account with the enforced limits
+    /// that must not be paused.
+    #[account(
+        seeds = [seeds::CONFIG],
+        bump = config.bump[0],
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The `Document` program account that the attachment is being added to.
+    #[account(
+        mut,
+        seeds = [
+            seeds::DOCUMENT,
+            authority.key().as_ref(),
+            Document::title_seed(&document.title),
+        ],
+        bump = document.bump[0],
+        has_one = authority,
+        constraint = !document.is_finalized() @ ErrorCode::DocumentIsAlreadyFinalized,
+        constraint = !document.has_signatures() @ ErrorCode::DocumentHasSignatures,
+        constraint = document.try_find_attachment(&label).is_err() @ ErrorCode::AttachmentLabelAlreadyUsed,
+    )]
+    pub document: Account<'info, Document>,
+
+    /// The global system program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AddAttachment<'info> {
+    /// Instruction prevalidation for `add_attachment`.
+    pub fn prevalidate(ctx: &Context<Self>, label: &str, uri: &str) -> Result<()> {
+        require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+
+        require!(!label.is_empty(), ErrorCode::EmptyAttachmentLabel);
+        require_gte!(
+            Attachment::MAX_LABEL_LENGTH,
+            label.len(),
+            ErrorCode::AttachmentLabelTooLong,
+        );
+
+        uri::validate(uri, ctx.accounts.config.limits.max_uri_length as usize)?;
+
+        require_gt!(
+            Document::MAX_ATTACHMENTS,
+            ctx.accounts.document.attachments.len(),
+            ErrorCode::TooManyDocumentAttachments,
+        );
+
+        Ok(())
+    }
+}
+
+#[event]
+pub struct AttachmentAdded {
+    pub document: Pubkey,
+    pub label: String,
+    pub content_hash: [u8; 32],
+}
+
+/// Instruction entrypoint handler for `add_attachment`.
+pub fn add_attachment_handler(
+    ctx: Context<AddAttachment>,
+    label: String,
+    uri: String,
+    content_hash: [u8; 32],
+) -> Result<()> {
+    let AddAttachment {
+        document,
+        payer,
+        system_program,
+        ..
+    } = ctx.accounts;
+
+    let rent = Rent::get()?;
+
+    let delta_bytes = Attachment::space(label.len(), uri.len());
+    let new_size = document
+        .to_account_info()
+        .data_len()
+        .checked_add(delta_bytes)
+        .unwrap();
+
+    transfer(
+        CpiContext::new(
+            system_program.to_account_info(),
+            Transfer {
+                from: payer.to_account_info(),
+                to: document.to_account_info(),
+            },
+        ),
+        rent.minimum_balance(delta_bytes),
+    )?;
+
+    document.to_account_info().realloc(new_size, false)?;
+    document.attachments.push(Attachment {
+        label: label.clone(),
+        uri: uri::normalize(&uri),
+        content_hash,
+    });
+
+    emit!(AttachmentAdded {
+        document: document.key(),
+        label,
+        content_hash,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::seeds;
+use crate::state::{Config, LargeDocument};
+
+#[derive(Accounts)]
+pub struct AddLargeParticipants<'info> {
+    /// The system account that is signing the transaction and
+    /// is the authority of the `document` being updated.
+    pub authority: Signer<'info>,
+
+    /// The global `Config` program account with the enforced limits
+    /// that must not be paused.
+    #[account(
+        seeds = [seeds::CONFIG],
+        bump = config.bump[0],
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The `LargeDocument` program account that the participants are being
+    /// appended to, which cannot change once any participant has signed.
+    #[account(
+        mut,
+        has_one = authority,
+        constraint = document.load()?.signature_count == 0 @ ErrorCode::DocumentHasSignatures,
+    )]
+    pub document: AccountLoader<'info, LargeDocument>,
+}
+
+impl<'info> AddLargeParticipants<'info> {
+    /// Instruction prevalidation for `add_large_participants`.
+    pub fn prevalidate(ctx: &Context<Self>, participants: &[Pubkey]) -> Result<()> {
+        require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+
+        require!(
+            !participants.is_empty(),
+            ErrorCode::EmptyDocumentParticipants,
+        );
+        require_gte!(
+            ctx.accounts.config.limits.max_participants as usize,
+            ctx.accounts.document.load()?.participant_count as usize + participants.len(),
+            ErrorCode::TooManyDocumentParticipants,
+        );
+        Ok(())
+    }
+}
+
+/// Instruction entrypoint handler for `add_large_participants`.
+pub fn add_large_participants_handler(
+    ctx: Context<AddLargeParticipants>,
+    participants: Vec<Pubkey>,
+) -> Result<()> {
+    ctx.accounts
+        .document
+        .load_mut()?
+        .try_push_participants(&participants)
+}
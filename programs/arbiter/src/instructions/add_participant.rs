@@ -3,7 +3,7 @@ use anchor_lang::system_program::{transfer, Transfer};
 
 use crate::error::ErrorCode;
 use crate::seeds;
-use crate::state::Document;
+use crate::state::{Config, Document, ParticipantSpace};
 
 #[derive(Accounts)]
 #[instruction(participant: Pubkey)]
@@ -16,6 +16,14 @@ pub struct AddParticipant<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
+    /// The global `Config` program account with the enforced limits
+    /// that must not be paused.
+    #[account(
+        seeds = [seeds::CONFIG],
+        bump = config.bump[0],
+    )]
+    pub config: Account<'info, Config>,
+
     /// The `Document` program account that is being updated with
     /// new participants. This could be an addition or removal update.
     #[account(
@@ -28,6 +36,7 @@ pub struct AddParticipant<'info> {
         bump = document.bump[0],
         has_one = authority,
         constraint = !document.is_finalized() @ ErrorCode::DocumentIsAlreadyFinalized,
+        constraint = document.settings.participant_root.is_none() @ ErrorCode::InvalidParticipantSet,
         constraint = document.try_find_participant(&participant).is_err() @ ErrorCode::ParticipantAlreadyAssociated,
     )]
     pub document: Account<'info, Document>,
@@ -38,7 +47,15 @@ pub struct AddParticipant<'info> {
 
 impl<'info> AddParticipant<'info> {
     /// Instruction prevalidation for `add_participant`.
-    pub fn prevalidate(_ctx: &Context<Self>) -> Result<()> {
+    pub fn prevalidate(ctx: &Context<Self>) -> Result<()> {
+        require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+
+        let document = &ctx.accounts.document;
+        require_gt!(
+            ctx.accounts.config.limits.max_participants as usize,
+            document.participants.len() + document.eth_participants.len(),
+            ErrorCode::TooManyDocumentParticipants,
+        );
         Ok(())
     }
 }
@@ -64,7 +81,15 @@ pub fn add_participant_handler(ctx: Context<AddParticipant>, participant: Pubkey
     let new_size = Document::space(
         document.title.len(),
         document.uri.len(),
-        new_participant_len,
+        &document.attachments,
+        &ParticipantSpace {
+            participants: new_participant_len,
+            eth_participants: document.eth_participants.len(),
+            merkle_participants: document.settings.participant_count as usize,
+            groups: &document.groups,
+        },
+        &document.sections,
+        document.prerequisites.len(),
     );
 
     let delta_bytes = new_size
@@ -83,8 +108,7 @@ pub fn add_participant_handler(ctx: Context<AddParticipant>, participant: Pubkey
     )?;
 
     document.to_account_info().realloc(new_size, false)?;
-    document.participants.push(participant);
-    document.signature_timestamps.push(0);
+    document.push_participant(participant);
 
     emit!(ParticipantAdded {
         document: document.key(),
@@ -2,17 +2,30 @@ use anchor_lang::prelude::*;
 
 use crate::error::ErrorCode;
 use crate::seeds;
-use crate::state::Document;
+use crate::state::{Config, Delegation, Document, ParticipantProof};
 
 #[derive(Accounts)]
+#[instruction(section: u8, memo: Option<[u8; 32]>, proof: Option<ParticipantProof>)]
 pub struct AddSignature<'info> {
-    /// The participant required to sign the document that is
-    /// submitting the transaction to grant their signature
-    /// on the `document` account.
-    pub participant: Signer<'info>,
+    /// The wallet submitting the transaction to grant the signature,
+    /// which is either the `participant` or one of their delegates.
+    pub signer: Signer<'info>,
+
+    /// CHECK: The participant required to sign the document that the
+    /// signature is being granted for. The key is verified to be associated
+    /// with the `document` and, if it is not the `signer`, to have authorized
+    /// the `signer` through a `Delegation` provided as a remaining account.
+    pub participant: UncheckedAccount<'info>,
+
+    /// The global `Config` program account that must not be paused.
+    #[account(
+        seeds = [seeds::CONFIG],
+        bump = config.bump[0],
+    )]
+    pub config: Account<'info, Config>,
 
     /// The `Document` program account that the `participant`
-    /// is proving their signature on.
+    /// is proving their signature on for the `section`.
     #[account(
         mut,
         seeds = [
@@ -22,36 +35,72 @@ pub struct AddSignature<'info> {
         ],
         bump = document.bump[0],
         constraint = !document.is_finalized() @ ErrorCode::DocumentIsAlreadyFinalized,
-        constraint = !document.try_has_signed(&participant)? @ ErrorCode::ParticipantAlreadySigned,
+        constraint = !document.is_draft() @ ErrorCode::DocumentIsDraft,
+        constraint = !document.try_has_signed(&participant.key(), section, proof.as_ref())? @ ErrorCode::ParticipantAlreadySigned,
+        constraint = document.try_has_accepted(&participant.key())? @ ErrorCode::ParticipantHasNotAccepted,
     )]
     pub document: Account<'info, Document>,
 }
 
 impl<'info> AddSignature<'info> {
     /// Instruction prevalidation for `add_signature`.
-    pub fn prevalidate(_ctx: &Context<Self>) -> Result<()> {
-        Ok(())
+    pub fn prevalidate(ctx: &Context<Self>) -> Result<()> {
+        require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+
+        let signer = ctx.accounts.signer.key();
+        let participant = ctx.accounts.participant.key();
+
+        if signer == participant {
+            return Ok(());
+        }
+
+        let delegation = Account::<Delegation>::try_from(
+            ctx.remaining_accounts
+                .first()
+                .ok_or(ErrorCode::DelegationNotProvided)?,
+        )?;
+
+        delegation.try_authorize(&participant, &signer, &ctx.accounts.document.key())
     }
 }
 
 #[event]
 pub struct SignatureAdded {
     document: Pubkey,
+    participant: Pubkey,
+    section: u8,
     signer: Pubkey,
+    memo: Option<[u8; 32]>,
 }
 
 /// Instruction entrypoint handler for `add_signature`.
-pub fn add_signature_handler(ctx: Context<AddSignature>) -> Result<()> {
+pub fn add_signature_handler(
+    ctx: Context<AddSignature>,
+    section: u8,
+    memo: Option<[u8; 32]>,
+    proof: Option<ParticipantProof>,
+) -> Result<()> {
     let AddSignature {
+        signer,
         participant,
         document,
+        ..
     } = ctx.accounts;
 
-    document.try_sign(participant)?;
+    document.try_sign(
+        &participant.key(),
+        section,
+        &signer.key(),
+        memo,
+        proof.as_ref(),
+    )?;
 
     emit!(SignatureAdded {
         document: document.key(),
-        signer: participant.key(),
+        participant: participant.key(),
+        section,
+        signer: signer.key(),
+        memo,
     });
 
     Ok(())
@@ -0,0 +1,272 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use anchor_lang::system_program;
+
+use crate::error::ErrorCode;
+use crate::seeds;
+use crate::state::{Config, Document};
+use crate::token_2022;
+use crate::token_metadata;
+
+#[derive(Accounts)]
+pub struct ClaimReceipt<'info> {
+    /// The wallet paying for the initialization of the receipt
+    /// `mint` and the `receipt` token account.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: The listed participant of the `document` receiving the receipt,
+    /// which is verified in prevalidation to have signed every section.
+    pub participant: UncheckedAccount<'info>,
+
+    /// The global `Config` program account that must not be paused.
+    #[account(
+        seeds = [seeds::CONFIG],
+        bump = config.bump[0],
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The finalized `Document` program account that is the mint authority
+    /// of the receipt `mint` and counts the receipts that have been claimed.
+    #[account(
+        mut,
+        seeds = [
+            seeds::DOCUMENT,
+            document.authority.as_ref(),
+            Document::title_seed(&document.title),
+        ],
+        bump = document.bump[0],
+        constraint = document.is_finalized() @ ErrorCode::DocumentIsNotFinalized,
+    )]
+    pub document: Account<'info, Document>,
+
+    /// CHECK: The non-transferable Token-2022 mint of the participant's receipt
+    /// for the `document` that is created and initialized in the handler.
+    #[account(
+        mut,
+        seeds = [
+            seeds::MINT,
+            document.key().as_ref(),
+            participant.key().as_ref(),
+        ],
+        bump,
+        constraint = mint.data_is_empty() @ ErrorCode::ReceiptAlreadyClaimed,
+    )]
+    pub mint: UncheckedAccount<'info>,
+
+    /// CHECK: The associated token account of the `participant` for the
+    /// `mint` that is created in the handler to hold the receipt.
+    #[account(
+        mut,
+        address = token_2022::get_associated_token_address(&participant.key(), &mint.key()),
+    )]
+    pub receipt: UncheckedAccount<'info>,
+
+    /// CHECK: The token metadata account of the `mint` that is created in the
+    /// handler to describe the receipt with the title and URI of the `document`.
+    #[account(
+        mut,
+        address = token_metadata::find_metadata_address(&mint.key()),
+    )]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: The Token-2022 program that owns the receipt `mint`.
+    #[account(address = token_2022::ID)]
+    pub token_program: UncheckedAccount<'info>,
+
+    /// CHECK: The associated token account program creating the `receipt`.
+    #[account(address = token_2022::associated_token::ID)]
+    pub associated_token_program: UncheckedAccount<'info>,
+
+    /// CHECK: The token metadata program creating the receipt `metadata`.
+    #[account(address = token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    /// The global system program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimReceipt<'info> {
+    /// Instruction prevalidation for `claim_receipt`.
+    pub fn prevalidate(ctx: &Context<Self>) -> Result<()> {
+        require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+
+        let document = &ctx.accounts.document;
+        let i = document.try_find_participant(&ctx.accounts.participant.key())?;
+        require!(
+            document.has_signed_all_sections(i),
+            ErrorCode::ParticipantHasNotSigned,
+        );
+
+        require_gte!(
+            token_metadata::MAX_URI_LENGTH,
+            document.uri.len(),
+            ErrorCode::ReceiptUriTooLong,
+        );
+
+        Ok(())
+    }
+}
+
+#[event]
+pub struct ReceiptClaimed {
+    pub document: Pubkey,
+    pub participant: Pubkey,
+    pub mint: Pubkey,
+}
+
+/// Instruction entrypoint handler for `claim_receipt`.
+pub fn claim_receipt_handler(ctx: Context<ClaimReceipt>) -> Result<()> {
+    let ClaimReceipt {
+        payer,
+        participant,
+        document,
+        mint,
+        receipt,
+        metadata,
+        token_program,
+        associated_token_program,
+        token_metadata_program,
+        system_program,
+        ..
+    } = ctx.accounts;
+
+    let document_key = document.key();
+    let participant_key = participant.key();
+    let mint_bump = [*ctx.bumps.get("mint").unwrap()];
+    let mint_seeds: &[&[u8]] = &[
+        seeds::MINT,
+        document_key.as_ref(),
+        participant_key.as_ref(),
+        &mint_bump,
+    ];
+
+    // The mint address can be funded by anyone before the claim, which would fail
+    // a `create_account`, so only the missing rent is transferred before the space
+    // of the mint is allocated and assigned to the Token-2022 program.
+    let rent = Rent::get()?.minimum_balance(token_2022::NON_TRANSFERABLE_MINT_SPACE);
+    let shortfall = rent.saturating_sub(mint.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: mint.to_account_info(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            system_program::Allocate {
+                account_to_allocate: mint.to_account_info(),
+            },
+            &[mint_seeds],
+        ),
+        token_2022::NON_TRANSFERABLE_MINT_SPACE as u64,
+    )?;
+
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            system_program::Assign {
+                account_to_assign: mint.to_account_info(),
+            },
+            &[mint_seeds],
+        ),
+        &token_2022::ID,
+    )?;
+
+    invoke(
+        &token_2022::initialize_non_transferable_mint(&mint.key()),
+        &[mint.to_account_info(), token_program.to_account_info()],
+    )?;
+
+    invoke(
+        &token_2022::initialize_mint2(&mint.key(), &document_key),
+        &[mint.to_account_info(), token_program.to_account_info()],
+    )?;
+
+    invoke(
+        &token_2022::create_associated_token_account(&payer.key(), &participant_key, &mint.key()),
+        &[
+            payer.to_account_info(),
+            receipt.to_account_info(),
+            participant.to_account_info(),
+            mint.to_account_info(),
+            system_program.to_account_info(),
+            token_program.to_account_info(),
+            associated_token_program.to_account_info(),
+        ],
+    )?;
+
+    invoke_signed(
+        &token_2022::mint_to(&mint.key(), &receipt.key(), &document_key, 1),
+        &[
+            mint.to_account_info(),
+            receipt.to_account_info(),
+            document.to_account_info(),
+            token_program.to_account_info(),
+        ],
+        &[&document.signer_seeds()],
+    )?;
+
+    // The participant and the other signers of the document are listed as creators
+    // after the document itself, but only the participant can verify their entry
+    // and only when they have also signed for the claim of their receipt.
+    let signers = (0..document.participants.len())
+        .filter(|&i| document.has_signed_all_sections(i))
+        .map(|i| document.participants[i]);
+    let creators = token_metadata::receipt_creators(
+        document_key,
+        std::iter::once(participant_key).chain(signers),
+    );
+
+    invoke_signed(
+        &token_metadata::create_metadata_account_v3(
+            &metadata.key(),
+            &mint.key(),
+            &document_key,
+            &payer.key(),
+            &document_key,
+            token_metadata::truncate_name(&document.title),
+            token_metadata::RECEIPT_SYMBOL,
+            &document.uri,
+            &creators,
+        ),
+        &[
+            metadata.to_account_info(),
+            mint.to_account_info(),
+            document.to_account_info(),
+            payer.to_account_info(),
+            system_program.to_account_info(),
+            token_metadata_program.to_account_info(),
+        ],
+        &[&document.signer_seeds()],
+    )?;
+
+    if participant.is_signer {
+        invoke(
+            &token_metadata::sign_metadata(&metadata.key(), &participant_key),
+            &[
+                metadata.to_account_info(),
+                participant.to_account_info(),
+                token_metadata_program.to_account_info(),
+            ],
+        )?;
+    }
+
+    document.receipts_claimed = document.receipts_claimed.checked_add(1).unwrap();
+
+    emit!(ReceiptClaimed {
+        document: document_key,
+        participant: participant_key,
+        mint: mint.key(),
+    });
+
+    Ok(())
+}
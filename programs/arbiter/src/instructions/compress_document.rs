@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::seeds;
+use crate::state::{Clerk, Config, Document, Receipt};
+
+#[derive(Accounts)]
+pub struct CompressDocument<'info> {
+    /// The transaction signer and owner of the `document` and `clerk`
+    /// program accounts that receives the rent freed from the `document`.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The wallet paying for the initialization of the `receipt` account.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The global `Config` program account that must not be paused.
+    #[account(
+        seeds = [seeds::CONFIG],
+        bump = config.bump[0],
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The `Clerk` program account that is the holder of the `document`
+    /// and has its slot freed once the document is compressed.
+    #[account(
+        mut,
+        seeds = [
+            seeds::CLERK,
+            clerk.authority.as_ref(),
+        ],
+        bump = clerk.bump[0],
+        has_one = authority,
+        constraint = clerk.holds(&document.key()) @ ErrorCode::ClerkDoesNotHoldDocument,
+    )]
+    pub clerk: Account<'info, Clerk>,
+
+    /// The finalized `Document` program account that is being shrunk to a
+    /// tombstone with the excess rent being returned to the `authority`.
+    #[account(
+        mut,
+        seeds = [
+            seeds::DOCUMENT,
+            document.authority.as_ref(),
+            Document::title_seed(&document.title),
+        ],
+        bump = document.bump[0],
+        has_one = authority,
+        constraint = document.is_finalized() @ ErrorCode::DocumentIsNotFinalized,
+    )]
+    pub document: Account<'info, Document>,
+
+    /// The `Receipt` program account that is being initialized to
+    /// permanently record the finalized `document`.
+    #[account(
+        init,
+        payer = payer,
+        seeds = [
+            seeds::RECEIPT,
+            document.key().as_ref(),
+        ],
+        bump,
+        space = Receipt::SPACE,
+    )]
+    pub receipt: Account<'info, Receipt>,
+
+    /// The global system program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CompressDocument<'info> {
+    /// Instruction prevalidation for `compress_document`.
+    pub fn prevalidate(ctx: &Context<Self>) -> Result<()> {
+        require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+        require!(
+            !ctx.accounts.document.has_unclaimed_receipts(),
+            ErrorCode::ReceiptsAreUnclaimed,
+        );
+        Ok(())
+    }
+}
+
+#[event]
+pub struct DocumentCompressed {
+    pub document: Pubkey,
+    pub receipt: Pubkey,
+}
+
+/// Instruction entrypoint handler for `compress_document`.
+pub fn compress_document_handler(ctx: Context<CompressDocument>) -> Result<()> {
+    let Context {
+        accounts:
+            CompressDocument {
+                authority,
+                clerk,
+                document,
+                receipt,
+                ..
+            },
+        bumps,
+        ..
+    } = ctx;
+
+    **receipt = Receipt {
+        document: document.key(),
+        content_hash: document.content_hash(),
+        participants_root: document.participants_root(),
+        created_at: document.created_at,
+        finalization_timestamp: document.finalization_timestamp,
+        bump: [*bumps.get("receipt").unwrap()],
+    };
+
+    clerk.release(&document.key());
+
+    // The document account is kept as a finalized tombstone rather than closed so
+    // that its title cannot be initialized again at the same address, with the
+    // rent of the cleared participant and signature data refunded to the authority.
+    let new_size = document.compress();
+    let info = document.to_account_info();
+    let refund = info
+        .lamports()
+        .checked_sub(Rent::get()?.minimum_balance(new_size))
+        .unwrap();
+
+    **info.try_borrow_mut_lamports()? -= refund;
+    **authority.to_account_info().try_borrow_mut_lamports()? += refund;
+    info.realloc(new_size, false)?;
+
+    emit!(DocumentCompressed {
+        document: document.key(),
+        receipt: receipt.key(),
+    });
+
+    Ok(())
+}
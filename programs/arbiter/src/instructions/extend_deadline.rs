@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::seeds;
+use crate::state::{Config, Document};
+
+#[derive(Accounts)]
+pub struct ExtendDeadline<'info> {
+    /// The system account that is signing the transaction and
+    /// is the authority of the `document` being updated.
+    pub authority: Signer<'info>,
+
+    /// The global `Config` program account that must not be paused.
+    #[account(
+        seeds = [seeds::CONFIG],
+        bump = config.bump[0],
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The `Document` program account holding the participant deadline.
+    #[account(
+        mut,
+        seeds = [
+            seeds::DOCUMENT,
+            authority.key().as_ref(),
+            Document::title_seed(&document.title),
+        ],
+        bump = document.bump[0],
+        has_one = authority,
+        constraint = !document.is_finalized() @ ErrorCode::DocumentIsAlreadyFinalized,
+    )]
+    pub document: Account<'info, Document>,
+}
+
+impl<'info> ExtendDeadline<'info> {
+    /// Instruction prevalidation for `extend_deadline`.
+    pub fn prevalidate(ctx: &Context<Self>) -> Result<()> {
+        require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+        Ok(())
+    }
+}
+
+#[event]
+pub struct DeadlineExtended {
+    pub document: Pubkey,
+    pub participant: Pubkey,
+    pub deadline: u64,
+}
+
+/// Instruction entrypoint handler for `extend_deadline`.
+pub fn extend_deadline_handler(
+    ctx: Context<ExtendDeadline>,
+    participant: Pubkey,
+    deadline: u64,
+) -> Result<()> {
+    let document = &mut ctx.accounts.document;
+    document.try_extend_deadline(&participant, deadline)?;
+
+    emit!(DeadlineExtended {
+        document: document.key(),
+        participant,
+        deadline,
+    });
+
+    Ok(())
+}
@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 
 use crate::error::ErrorCode;
 use crate::seeds;
-use crate::state::{Clerk, Document};
+use crate::state::{Clerk, Config, Document};
 
 #[derive(Accounts)]
 pub struct Finalize<'info> {
@@ -15,6 +15,13 @@ pub struct Finalize<'info> {
     #[account(mut)]
     pub payer: SystemAccount<'info>,
 
+    /// The global `Config` program account that must not be paused.
+    #[account(
+        seeds = [seeds::CONFIG],
+        bump = config.bump[0],
+    )]
+    pub config: Account<'info, Config>,
+
     /// The `Clerk` program account that is the holder of the `document`.
     #[account(
         seeds = [
@@ -29,6 +36,7 @@ pub struct Finalize<'info> {
 
     /// The `Document` program account that should contain all required
     /// participant signatures and is being finalized by the `authority`.
+    /// Each of its prerequisite documents must be provided as a remaining account.
     #[account(
         mut,
         seeds = [
@@ -39,6 +47,8 @@ pub struct Finalize<'info> {
         bump = document.bump[0],
         has_one = authority,
         constraint = !document.is_finalized() @ ErrorCode::DocumentIsAlreadyFinalized,
+        constraint = !document.is_draft() @ ErrorCode::DocumentIsDraft,
+        constraint = document.bundle.is_none() @ ErrorCode::DocumentIsBundled,
         constraint = document.has_all_signatures() @ ErrorCode::DocumentIsMissingSignatures,
     )]
     pub document: Account<'info, Document>,
@@ -46,8 +56,12 @@ pub struct Finalize<'info> {
 
 impl<'info> Finalize<'info> {
     /// Instruction prevalidation for `finalize`.
-    pub fn prevalidate(_ctx: &Context<Self>) -> Result<()> {
-        Ok(())
+    pub fn prevalidate(ctx: &Context<Self>) -> Result<()> {
+        require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+
+        ctx.accounts
+            .document
+            .try_check_prerequisites(ctx.remaining_accounts, &[])
     }
 }
 
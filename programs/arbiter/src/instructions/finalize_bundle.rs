@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::seeds;
+use crate::state::{Bundle, Clerk, Config, Document};
+
+#[derive(Accounts)]
+pub struct FinalizeBundle<'info> {
+    /// The transaction signer and owner of the `bundle`, `clerk`
+    /// and every bundled document program account.
+    pub authority: Signer<'info>,
+
+    /// The global `Config` program account that must not be paused.
+    #[account(
+        seeds = [seeds::CONFIG],
+        bump = config.bump[0],
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The `Clerk` program account that is the holder of the bundled documents.
+    #[account(
+        seeds = [
+            seeds::CLERK,
+            clerk.authority.as_ref(),
+        ],
+        bump = clerk.bump[0],
+        has_one = authority,
+    )]
+    pub clerk: Account<'info, Clerk>,
+
+    /// The `Bundle` program account whose documents are all being finalized.
+    /// Each bundled `Document` must be provided as a writable remaining
+    /// account in the same order as they are listed in the bundle, followed
+    /// by any prerequisite documents that are not part of the bundle.
+    #[account(
+        mut,
+        seeds = [
+            seeds::BUNDLE,
+            clerk.key().as_ref(),
+            Bundle::name_seed(&bundle.name),
+        ],
+        bump = bundle.bump[0],
+        has_one = authority,
+        has_one = clerk,
+        constraint = !bundle.is_finalized() @ ErrorCode::BundleIsAlreadyFinalized,
+    )]
+    pub bundle: Account<'info, Bundle>,
+}
+
+impl<'info> FinalizeBundle<'info> {
+    /// Instruction prevalidation for `finalize_bundle`.
+    pub fn prevalidate(ctx: &Context<Self>) -> Result<()> {
+        require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+
+        let documents = &ctx.accounts.bundle.documents;
+
+        require_gte!(
+            ctx.remaining_accounts.len(),
+            documents.len(),
+            ErrorCode::BundleDocumentsMisMatch,
+        );
+
+        require!(
+            ctx.remaining_accounts
+                .iter()
+                .zip(documents.iter())
+                .all(|(info, d)| info.key == d && info.is_writable),
+            ErrorCode::BundleDocumentsMisMatch,
+        );
+
+        Ok(())
+    }
+}
+
+#[event]
+pub struct BundleFinalized {
+    pub bundle: Pubkey,
+    pub documents: Vec<Pubkey>,
+}
+
+/// Instruction entrypoint handler for `finalize_bundle`.
+pub fn finalize_bundle_handler(ctx: Context<FinalizeBundle>) -> Result<()> {
+    let Context {
+        accounts: FinalizeBundle {
+            authority, bundle, ..
+        },
+        remaining_accounts,
+        ..
+    } = ctx;
+
+    let (members, prerequisites) = remaining_accounts.split_at(bundle.documents.len());
+
+    let mut documents = members
+        .iter()
+        .map(Account::<Document>::try_from)
+        .collect::<Result<Vec<_>>>()?;
+
+    for doc in &documents {
+        require_keys_eq!(
+            doc.authority,
+            authority.key(),
+            ErrorCode::BundleDocumentsMisMatch,
+        );
+        require!(
+            doc.bundle == Some(bundle.key()),
+            ErrorCode::BundleDocumentsMisMatch,
+        );
+        require!(!doc.is_finalized(), ErrorCode::DocumentIsAlreadyFinalized);
+        require!(!doc.is_draft(), ErrorCode::DocumentIsDraft);
+        require!(
+            doc.has_all_signatures(),
+            ErrorCode::DocumentIsMissingSignatures,
+        );
+        doc.try_check_prerequisites(prerequisites, &bundle.documents)?;
+    }
+
+    for doc in documents.iter_mut() {
+        doc.try_finalize()?;
+        doc.exit(&crate::ID)?;
+    }
+
+    bundle.try_finalize()?;
+
+    emit!(BundleFinalized {
+        bundle: bundle.key(),
+        documents: bundle.documents.clone(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::seeds;
+use crate::state::{Clerk, Config, LargeDocument};
+
+#[derive(Accounts)]
+pub struct FinalizeLargeDocument<'info> {
+    /// The transaction signer and owner of the `document`
+    /// and `clerk` program accounts.
+    pub authority: Signer<'info>,
+
+    /// The global `Config` program account that must not be paused.
+    #[account(
+        seeds = [seeds::CONFIG],
+        bump = config.bump[0],
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The `Clerk` program account that is the holder of the `document`.
+    #[account(
+        seeds = [
+            seeds::CLERK,
+            clerk.authority.as_ref(),
+        ],
+        bump = clerk.bump[0],
+        has_one = authority,
+        constraint = clerk.holds(&document.key()) @ ErrorCode::ClerkDoesNotHoldDocument,
+    )]
+    pub clerk: Account<'info, Clerk>,
+
+    /// The `LargeDocument` program account that should be signed by
+    /// every participant and is being finalized by the `authority`.
+    #[account(
+        mut,
+        has_one = authority,
+        constraint = !document.load()?.is_finalized() @ ErrorCode::DocumentIsAlreadyFinalized,
+        constraint = document.load()?.has_all_signatures() @ ErrorCode::DocumentIsMissingSignatures,
+    )]
+    pub document: AccountLoader<'info, LargeDocument>,
+}
+
+impl<'info> FinalizeLargeDocument<'info> {
+    /// Instruction prevalidation for `finalize_large_document`.
+    pub fn prevalidate(ctx: &Context<Self>) -> Result<()> {
+        require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+        Ok(())
+    }
+}
+
+/// Instruction entrypoint handler for `finalize_large_document`.
+pub fn finalize_large_document_handler(ctx: Context<FinalizeLargeDocument>) -> Result<()> {
+    ctx.accounts.document.load_mut()?.try_finalize()
+}
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::seeds;
+use crate::state::{Config, Delegation};
+
+#[derive(Accounts)]
+pub struct GrantDelegation<'info> {
+    /// The participant wallet that is signing the transaction
+    /// and granting their signing authority to the `delegate`.
+    pub participant: Signer<'info>,
+
+    /// The wallet paying for the initialization of the `delegation` account.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The global `Config` program account that must not be paused.
+    #[account(
+        seeds = [seeds::CONFIG],
+        bump = config.bump[0],
+    )]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: The public key being authorized to sign on behalf of the
+    /// `participant`. It is only used as a key and is never read or written.
+    pub delegate: UncheckedAccount<'info>,
+
+    /// The `Delegation` program account that is being initialized
+    /// to link the `participant` and `delegate` public keys.
+    #[account(
+        init,
+        payer = payer,
+        seeds = [
+            seeds::DELEGATION,
+            participant.key().as_ref(),
+            delegate.key().as_ref(),
+        ],
+        bump,
+        space = Delegation::SPACE,
+    )]
+    pub delegation: Account<'info, Delegation>,
+
+    /// The global system program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> GrantDelegation<'info> {
+    /// Instruction prevalidation for `grant_delegation`.
+    pub fn prevalidate(ctx: &Context<Self>, expires_at: Option<u64>) -> Result<()> {
+        require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+
+        require_keys_neq!(
+            ctx.accounts.participant.key(),
+            ctx.accounts.delegate.key(),
+            ErrorCode::DelegateIsParticipant,
+        );
+
+        if let Some(e) = expires_at {
+            require_gt!(
+                e,
+                Clock::get()?.unix_timestamp as u64,
+                ErrorCode::DelegationExpiryInPast,
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[event]
+pub struct DelegationGranted {
+    pub participant: Pubkey,
+    pub delegate: Pubkey,
+    pub document: Option<Pubkey>,
+}
+
+/// Instruction entrypoint handler for `grant_delegation`.
+pub fn grant_delegation_handler(
+    ctx: Context<GrantDelegation>,
+    document: Option<Pubkey>,
+    expires_at: Option<u64>,
+) -> Result<()> {
+    let Context {
+        accounts:
+            GrantDelegation {
+                participant,
+                delegate,
+                delegation,
+                ..
+            },
+        bumps,
+        ..
+    } = ctx;
+
+    **delegation = Delegation {
+        participant: participant.key(),
+        delegate: delegate.key(),
+        document,
+        expires_at,
+        bump: [*bumps.get("delegation").unwrap()],
+    };
+
+    emit!(DelegationGranted {
+        participant: participant.key(),
+        delegate: delegate.key(),
+        document,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::seeds;
+use crate::state::{Clerk, Config, FeeWaiver};
+
+#[derive(Accounts)]
+pub struct GrantFeeWaiver<'info> {
+    /// The current admin of the global configuration.
+    pub admin: Signer<'info>,
+
+    /// The wallet paying for the initialization of the `waiver` account.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The global `Config` program account that the `admin` is verified against.
+    #[account(
+        seeds = [seeds::CONFIG],
+        bump = config.bump[0],
+        has_one = admin,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The `Clerk` program account whose documents are being exempted from the fee.
+    #[account(
+        seeds = [
+            seeds::CLERK,
+            clerk.authority.as_ref(),
+        ],
+        bump = clerk.bump[0],
+    )]
+    pub clerk: Account<'info, Clerk>,
+
+    /// The `FeeWaiver` program account that is being initialized for the `clerk`.
+    #[account(
+        init,
+        payer = payer,
+        seeds = [
+            seeds::WAIVER,
+            clerk.key().as_ref(),
+        ],
+        bump,
+        space = FeeWaiver::SPACE,
+    )]
+    pub waiver: Account<'info, FeeWaiver>,
+
+    /// The global system program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> GrantFeeWaiver<'info> {
+    /// Instruction prevalidation for `grant_fee_waiver`.
+    pub fn prevalidate(_ctx: &Context<Self>) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[event]
+pub struct FeeWaiverGranted {
+    pub clerk: Pubkey,
+}
+
+/// Instruction entrypoint handler for `grant_fee_waiver`.
+pub fn grant_fee_waiver_handler(ctx: Context<GrantFeeWaiver>) -> Result<()> {
+    let Context {
+        accounts: GrantFeeWaiver { clerk, waiver, .. },
+        bumps,
+        ..
+    } = ctx;
+
+    **waiver = FeeWaiver {
+        clerk: clerk.key(),
+        bump: [*bumps.get("waiver").unwrap()],
+    };
+
+    emit!(FeeWaiverGranted { clerk: clerk.key() });
+
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+
+use super::init_document::is_unique;
+use crate::error::ErrorCode;
+use crate::seeds;
+use crate::state::{Bundle, Clerk, Config, Document};
+
+#[derive(Accounts)]
+#[instruction(name: String, documents: Vec<Pubkey>)]
+pub struct InitBundle<'info> {
+    /// The system account that is signing the transaction and
+    /// is the owner of the `clerk` and every bundled document.
+    pub authority: Signer<'info>,
+
+    /// The wallet paying for the initialization of the `bundle` account.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The global `Config` program account that must not be paused.
+    #[account(
+        seeds = [seeds::CONFIG],
+        bump = config.bump[0],
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The `Clerk` program account that holds all of the bundled documents.
+    #[account(
+        seeds = [
+            seeds::CLERK,
+            clerk.authority.as_ref(),
+        ],
+        bump = clerk.bump[0],
+        has_one = authority,
+    )]
+    pub clerk: Account<'info, Clerk>,
+
+    /// The `Bundle` program account that is being initialized to
+    /// link the `documents` for atomic finalization. Each of the `documents`
+    /// must be provided as a writable remaining account in the same order.
+    #[account(
+        init,
+        payer = payer,
+        seeds = [
+            seeds::BUNDLE,
+            clerk.key().as_ref(),
+            Bundle::name_seed(&name),
+        ],
+        bump,
+        space = Bundle::space(name.len(), documents.len()),
+    )]
+    pub bundle: Account<'info, Bundle>,
+
+    /// The global system program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitBundle<'info> {
+    /// Instruction prevalidation for `init_bundle`.
+    pub fn prevalidate(ctx: &Context<Self>, name: &str, documents: &[Pubkey]) -> Result<()> {
+        require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+
+        require!(!name.is_empty(), ErrorCode::EmptyBundleName);
+        require!(!documents.is_empty(), ErrorCode::EmptyBundleDocuments);
+        require!(is_unique(documents), ErrorCode::BundleDocumentsAreNotUnique);
+
+        require!(
+            documents.iter().all(|d| ctx.accounts.clerk.holds(d)),
+            ErrorCode::ClerkDoesNotHoldDocument,
+        );
+
+        require!(
+            ctx.remaining_accounts.len() == documents.len()
+                && ctx
+                    .remaining_accounts
+                    .iter()
+                    .zip(documents.iter())
+                    .all(|(info, d)| info.key == d && info.is_writable),
+            ErrorCode::BundleDocumentsMisMatch,
+        );
+
+        Ok(())
+    }
+}
+
+/// Instruction entrypoint handler for `init_bundle`.
+pub fn init_bundle_handler(
+    ctx: Context<InitBundle>,
+    name: String,
+    documents: Vec<Pubkey>,
+) -> Result<()> {
+    let Context {
+        accounts:
+            InitBundle {
+                authority,
+                clerk,
+                bundle,
+                ..
+            },
+        bumps,
+        remaining_accounts,
+        ..
+    } = ctx;
+
+    for info in remaining_accounts {
+        let mut doc = Account::<Document>::try_from(info)?;
+
+        require_keys_eq!(
+            doc.authority,
+            authority.key(),
+            ErrorCode::BundleDocumentsMisMatch,
+        );
+        require!(!doc.is_finalized(), ErrorCode::DocumentIsAlreadyFinalized);
+        require!(doc.bundle.is_none(), ErrorCode::DocumentIsBundled);
+
+        doc.bundle = Some(bundle.key());
+        doc.exit(&crate::ID)?;
+    }
+
+    **bundle = Bundle {
+        authority: authority.key(),
+        clerk: clerk.key(),
+        name,
+        documents,
+        finalization_timestamp: 0,
+        bump: [*bumps.get("bundle").unwrap()],
+    };
+
+    Ok(())
+}
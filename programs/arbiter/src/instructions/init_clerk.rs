@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 
 use crate::error::ErrorCode;
 use crate::seeds;
-use crate::state::Clerk;
+use crate::state::{Clerk, Config};
 
 #[derive(Accounts)]
 #[instruction(limit: u8)]
@@ -16,6 +16,14 @@ pub struct InitClerk<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
+    /// The global `Config` program account with the enforced limits
+    /// that must not be paused.
+    #[account(
+        seeds = [seeds::CONFIG],
+        bump = config.bump[0],
+    )]
+    pub config: Account<'info, Config>,
+
     /// The `Clerk` program account that is being initialized
     /// for the `authority` wallet.
     #[account(
@@ -36,8 +44,15 @@ pub struct InitClerk<'info> {
 
 impl<'info> InitClerk<'info> {
     /// Instruction prevalidation for `init_clerk`.
-    pub fn prevalidate(_ctx: &Context<Self>, limit: u8) -> Result<()> {
+    pub fn prevalidate(ctx: &Context<Self>, limit: u8) -> Result<()> {
+        require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+
         require_gt!(limit, 0, ErrorCode::ClerkLimitIsZero);
+        require_gte!(
+            ctx.accounts.config.limits.max_clerk_limit,
+            limit,
+            ErrorCode::ClerkLimitTooLarge,
+        );
         Ok(())
     }
 }
@@ -53,6 +68,7 @@ pub fn init_clerk_handler(ctx: Context<InitClerk>, limit: u8) -> Result<()> {
     } = ctx;
 
     **clerk = Clerk {
+        version: Clerk::VERSION,
         authority: authority.key(),
         documents: vec![Pubkey::default(); limit as usize],
         upgrades: 0,
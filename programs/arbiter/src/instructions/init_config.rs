@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::program::Arbiter;
+use crate::seeds;
+use crate::state::{Config, ConfigLimits};
+
+#[derive(Accounts)]
+pub struct InitConfig<'info> {
+    /// The upgrade authority of the program that is
+    /// set as the admin of the global configuration.
+    pub admin: Signer<'info>,
+
+    /// The wallet paying for the initialization of the `config` account.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The global `Config` program account that is being initialized.
+    #[account(
+        init,
+        payer = payer,
+        seeds = [seeds::CONFIG],
+        bump,
+        space = Config::SPACE,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The deployed program whose upgrade authority is being verified.
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()) @ ErrorCode::ProgramDataMisMatch)]
+    pub program: Program<'info, Arbiter>,
+
+    /// The program data account that holds the upgrade authority of the `program`.
+    #[account(constraint = program_data.upgrade_authority_address == Some(admin.key()) @ ErrorCode::ConfigAdminMisMatch)]
+    pub program_data: Account<'info, ProgramData>,
+
+    /// The global system program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitConfig<'info> {
+    /// Instruction prevalidation for `init_config`.
+    pub fn prevalidate(_ctx: &Context<Self>, limits: &ConfigLimits) -> Result<()> {
+        require!(limits.is_valid(), ErrorCode::InvalidConfigLimits);
+        Ok(())
+    }
+}
+
+/// Instruction entrypoint handler for `init_config`.
+pub fn init_config_handler(ctx: Context<InitConfig>, limits: ConfigLimits) -> Result<()> {
+    let Context {
+        accounts: InitConfig { admin, config, .. },
+        bumps,
+        ..
+    } = ctx;
+
+    **config = Config {
+        admin: admin.key(),
+        limits,
+        paused: false,
+        treasury: admin.key(),
+        document_fee: 0,
+        bump: [*bumps.get("config").unwrap()],
+    };
+
+    Ok(())
+}
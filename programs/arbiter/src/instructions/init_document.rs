@@ -1,11 +1,23 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
 
 use crate::error::ErrorCode;
 use crate::seeds;
-use crate::state::{Clerk, Document};
+use crate::state::{
+    Clerk, Config, ConfigLimits, Document, DocumentSettings, FeeWaiver, ParticipantSet,
+    ParticipantSpace, SignerGroup,
+};
+use crate::uri;
 
 #[derive(Accounts)]
-#[instruction(title: String, uri: String, participants: Vec<Pubkey>)]
+#[instruction(
+    title: String,
+    uri: String,
+    participant_set: ParticipantSet,
+    sections: Vec<String>,
+    prerequisites: Vec<Pubkey>,
+    settings: DocumentSettings,
+)]
 pub struct InitDocument<'info> {
     /// The system account that is signing the transaction and
     /// will be set as the `document` owner.
@@ -15,6 +27,22 @@ pub struct InitDocument<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
+    /// The global `Config` program account with the enforced limits
+    /// that must not be paused.
+    #[account(
+        seeds = [seeds::CONFIG],
+        bump = config.bump[0],
+    )]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: The wallet receiving the document creation fee, which
+    /// is verified to be the treasury set in the global `config`.
+    #[account(
+        mut,
+        address = config.treasury @ ErrorCode::TreasuryMisMatch,
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
     /// The `Clerk` program account that the `document` will be assigned to.
     #[account(
         mut,
@@ -39,7 +67,19 @@ pub struct InitDocument<'info> {
             Document::title_seed(&title),
         ],
         bump,
-        space = Document::space(title.len(), uri.len(), participants.len()),
+        space = Document::space(
+            title.len(),
+            uri.len(),
+            &[],
+            &ParticipantSpace {
+                participants: participant_set.participants.len(),
+                eth_participants: participant_set.eth_participants.len(),
+                merkle_participants: settings.participant_count as usize,
+                groups: &participant_set.groups,
+            },
+            &sections,
+            prerequisites.len(),
+        ),
     )]
     pub document: Account<'info, Document>,
 
@@ -50,27 +90,212 @@ pub struct InitDocument<'info> {
 impl<'info> InitDocument<'info> {
     /// Instruction prevalidation for `init_document`.
     pub fn prevalidate(
-        _ctx: &Context<Self>,
+        ctx: &Context<Self>,
         title: &str,
         uri: &str,
-        participants: &[Pubkey],
+        participant_set: &ParticipantSet,
+        sections: &[String],
+        prerequisites: &[Pubkey],
+        settings: &DocumentSettings,
     ) -> Result<()> {
+        require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+
+        let limits = &ctx.accounts.config.limits;
+        let ParticipantSet {
+            participants,
+            eth_participants,
+            deadlines,
+            weights,
+            groups,
+        } = participant_set;
+
         require!(!title.is_empty(), ErrorCode::EmptyDocumentTitle);
-        require!(!uri.is_empty(), ErrorCode::EmptyDocumentUri);
+        require_gte!(
+            limits.max_title_length as usize,
+            title.len(),
+            ErrorCode::DocumentTitleTooLong,
+        );
 
+        uri::validate(uri, limits.max_uri_length as usize)?;
+        validate_participant_set(limits, participants, eth_participants, settings)?;
+
+        let now = Clock::get()?.unix_timestamp as u64;
         require!(
-            !participants.is_empty(),
-            ErrorCode::EmptyDocumentParticipants,
+            (deadlines.is_empty() || deadlines.len() == participants.len())
+                && deadlines.iter().all(|&d| d == 0 || d > now),
+            ErrorCode::InvalidParticipantDeadlines,
         );
 
-        require!(is_unique(participants), ErrorCode::ParticipantsAreNotUnique);
+        require!(
+            weights.is_empty() || weights.len() == participants.len(),
+            ErrorCode::InvalidParticipantWeights,
+        );
+
+        if weights.is_empty() {
+            validate_required_weight(&vec![1; participants.len()], eth_participants, settings)?;
+        } else {
+            validate_required_weight(weights, eth_participants, settings)?;
+        }
+
+        validate_signer_groups(groups, participants.len(), settings)?;
+
+        require_gte!(
+            u8::MAX as usize,
+            sections.len(),
+            ErrorCode::TooManyDocumentSections,
+        );
+
+        require!(
+            sections.iter().all(|s| !s.is_empty()),
+            ErrorCode::EmptyDocumentSection,
+        );
+
+        require!(
+            is_unique(prerequisites) && !prerequisites.contains(&ctx.accounts.document.key()),
+            ErrorCode::PrerequisitesAreNotUnique,
+        );
+
+        if let Some(acc) = ctx.remaining_accounts.first() {
+            let waiver = Account::<FeeWaiver>::try_from(acc)?;
+            require_keys_eq!(
+                waiver.clerk,
+                ctx.accounts.clerk.key(),
+                ErrorCode::FeeWaiverMisMatch,
+            );
+        }
 
         Ok(())
     }
+
+    /// Transfers the configured document fee from the `payer` to the
+    /// `treasury` unless a `FeeWaiver` for the clerk was provided.
+    fn charge_fee(&self, waived: bool) -> Result<()> {
+        if waived || self.config.document_fee == 0 {
+            return Ok(());
+        }
+
+        system_program::transfer(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: self.payer.to_account_info(),
+                    to: self.treasury.to_account_info(),
+                },
+            ),
+            self.config.document_fee,
+        )
+    }
 }
 
-/// Checks if the argued vector of public keys contains any duplicates.
-fn is_unique(v: &[Pubkey]) -> bool {
+/// Validates that the argued participants, Ethereum participants and the
+/// settings describing them form a valid set within the configured limits.
+pub(crate) fn validate_participant_set(
+    limits: &ConfigLimits,
+    participants: &[Pubkey],
+    eth_participants: &[[u8; 20]],
+    settings: &DocumentSettings,
+) -> Result<()> {
+    if settings.participant_root.is_some() {
+        require!(
+            settings.participant_count > 0
+                && participants.is_empty()
+                && eth_participants.is_empty()
+                && !settings.requires_acceptance,
+            ErrorCode::InvalidParticipantSet,
+        );
+    } else {
+        require!(
+            settings.participant_count == 0,
+            ErrorCode::InvalidParticipantSet,
+        );
+        require!(
+            !participants.is_empty() || !eth_participants.is_empty(),
+            ErrorCode::EmptyDocumentParticipants,
+        );
+    }
+
+    require_gte!(
+        limits.max_participants as usize,
+        participants.len() + eth_participants.len(),
+        ErrorCode::TooManyDocumentParticipants,
+    );
+
+    require!(
+        is_unique(participants) && is_unique(eth_participants),
+        ErrorCode::ParticipantsAreNotUnique,
+    );
+
+    Ok(())
+}
+
+/// Validates that the required weight of a weighted document is reachable by the
+/// argued participant weights, where weighted documents cannot include Ethereum
+/// or Merkle committed participants whose signatures carry no weight.
+pub(crate) fn validate_required_weight(
+    weights: &[u64],
+    eth_participants: &[[u8; 20]],
+    settings: &DocumentSettings,
+) -> Result<()> {
+    if settings.required_weight == 0 {
+        return Ok(());
+    }
+
+    require!(
+        eth_participants.is_empty()
+            && settings.participant_root.is_none()
+            && weights
+                .iter()
+                .try_fold(0u64, |acc, &w| acc.checked_add(w))
+                .map_or(false, |total| total >= settings.required_weight),
+        ErrorCode::InvalidRequiredWeight,
+    );
+
+    Ok(())
+}
+
+/// Validates that each signer group has a unique name, a reachable threshold and
+/// members that are listed participants not already assigned to another group.
+pub(crate) fn validate_signer_groups(
+    groups: &[SignerGroup],
+    num_participants: usize,
+    settings: &DocumentSettings,
+) -> Result<()> {
+    if groups.is_empty() {
+        return Ok(());
+    }
+
+    require!(
+        groups.len() <= Document::MAX_GROUPS && settings.required_weight == 0,
+        ErrorCode::InvalidSignerGroups,
+    );
+
+    let mut assigned = vec![false; num_participants];
+
+    for (i, g) in groups.iter().enumerate() {
+        require!(
+            !g.name.is_empty()
+                && g.name.len() <= SignerGroup::MAX_NAME_LENGTH
+                && groups.iter().skip(i + 1).all(|o| o.name != g.name)
+                && g.threshold > 0
+                && g.threshold as usize <= g.members.len(),
+            ErrorCode::InvalidSignerGroups,
+        );
+
+        for &m in &g.members {
+            let m = m as usize;
+            require!(
+                m < num_participants && !assigned[m],
+                ErrorCode::InvalidSignerGroups,
+            );
+            assigned[m] = true;
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks if the argued vector of public keys or addresses contains any duplicates.
+pub(crate) fn is_unique<T: PartialEq>(v: &[T]) -> bool {
     for (i, p) in v.iter().enumerate() {
         if v.iter().skip(i + 1).any(|e| e == p) {
             return false;
@@ -84,8 +309,14 @@ pub fn init_document_handler(
     ctx: Context<InitDocument>,
     title: String,
     uri: String,
-    participants: Vec<Pubkey>,
+    participant_set: ParticipantSet,
+    sections: Vec<String>,
+    prerequisites: Vec<Pubkey>,
+    settings: DocumentSettings,
 ) -> Result<()> {
+    ctx.accounts
+        .charge_fee(!ctx.remaining_accounts.is_empty())?;
+
     let Context {
         accounts:
             InitDocument {
@@ -98,16 +329,50 @@ pub fn init_document_handler(
         ..
     } = ctx;
 
+    let ParticipantSet {
+        participants,
+        eth_participants,
+        deadlines,
+        weights,
+        groups,
+    } = participant_set;
+
     let now = Clock::get()?.unix_timestamp as u64;
     let num_participants = participants.len();
+    let num_slots = (num_participants + eth_participants.len()) * sections.len().max(1);
+    let bitmap_size =
+        Document::bitmap_size(settings.participant_count as usize, sections.len().max(1));
 
     **document = Document {
+        version: Document::VERSION,
         authority: authority.key(),
         title,
-        uri,
+        uri: uri::normalize(&uri),
+        attachments: vec![],
         created_at: now,
+        settings,
+        sections,
+        prerequisites,
+        bundle: None,
         participants,
-        signature_timestamps: vec![0; num_participants],
+        eth_participants,
+        signature_timestamps: vec![0; num_slots],
+        deadlines: if deadlines.is_empty() {
+            vec![0; num_participants]
+        } else {
+            deadlines
+        },
+        weights: if weights.is_empty() {
+            vec![1; num_participants]
+        } else {
+            weights
+        },
+        groups,
+        signers: vec![Pubkey::default(); num_slots],
+        memos: vec![[0; Document::MEMO_SIZE]; num_slots],
+        signed_bitmap: vec![0; bitmap_size],
+        acceptance_timestamps: vec![0; num_participants],
+        receipts_claimed: 0,
         finalization_timestamp: 0,
         bump: [*bumps.get("document").unwrap()],
     };
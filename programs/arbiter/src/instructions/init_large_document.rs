@@ -0,0 +1,135 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::error::ErrorCode;
+use crate::seeds;
+use crate::state::{Clerk, Config, FeeWaiver, LargeDocument};
+use crate::uri;
+
+#[derive(Accounts)]
+pub struct InitLargeDocument<'info> {
+    /// The system account that is signing the transaction and
+    /// will be set as the `document` owner.
+    pub authority: Signer<'info>,
+
+    /// The wallet paying the document creation fee.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The global `Config` program account with the enforced limits
+    /// that must not be paused.
+    #[account(
+        seeds = [seeds::CONFIG],
+        bump = config.bump[0],
+    )]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: The wallet receiving the document creation fee, which
+    /// is verified to be the treasury set in the global `config`.
+    #[account(
+        mut,
+        address = config.treasury @ ErrorCode::TreasuryMisMatch,
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    /// The `Clerk` program account that the `document` will be assigned to.
+    #[account(
+        mut,
+        seeds = [
+            seeds::CLERK,
+            clerk.authority.as_ref(),
+        ],
+        bump = clerk.bump[0],
+        has_one = authority,
+        constraint = !clerk.is_full() @ ErrorCode::ClerkDocumentListIsFull,
+    )]
+    pub clerk: Account<'info, Clerk>,
+
+    /// The `LargeDocument` program account that is being initialized, which
+    /// was created by the client with `LargeDocument::SPACE` bytes in the same
+    /// transaction since it is too large to be allocated by the program.
+    #[account(zero)]
+    pub document: AccountLoader<'info, LargeDocument>,
+
+    /// The global system program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitLargeDocument<'info> {
+    /// Instruction prevalidation for `init_large_document`.
+    pub fn prevalidate(ctx: &Context<Self>, title: &str, uri: &str) -> Result<()> {
+        require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+
+        let limits = &ctx.accounts.config.limits;
+
+        require!(!title.is_empty(), ErrorCode::EmptyDocumentTitle);
+        require_gte!(
+            (limits.max_title_length as usize).min(LargeDocument::MAX_TITLE_LENGTH),
+            title.len(),
+            ErrorCode::DocumentTitleTooLong,
+        );
+
+        uri::validate(
+            uri,
+            (limits.max_uri_length as usize).min(LargeDocument::MAX_URI_LENGTH),
+        )?;
+
+        if let Some(acc) = ctx.remaining_accounts.first() {
+            let waiver = Account::<FeeWaiver>::try_from(acc)?;
+            require_keys_eq!(
+                waiver.clerk,
+                ctx.accounts.clerk.key(),
+                ErrorCode::FeeWaiverMisMatch,
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Transfers the configured document fee from the `payer` to the
+    /// `treasury` unless a `FeeWaiver` for the clerk was provided.
+    fn charge_fee(&self, waived: bool) -> Result<()> {
+        if waived || self.config.document_fee == 0 {
+            return Ok(());
+        }
+
+        system_program::transfer(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: self.payer.to_account_info(),
+                    to: self.treasury.to_account_info(),
+                },
+            ),
+            self.config.document_fee,
+        )
+    }
+}
+
+/// Instruction entrypoint handler for `init_large_document`.
+pub fn init_large_document_handler(
+    ctx: Context<InitLargeDocument>,
+    title: String,
+    uri: String,
+) -> Result<()> {
+    ctx.accounts
+        .charge_fee(!ctx.remaining_accounts.is_empty())?;
+
+    let InitLargeDocument {
+        authority,
+        clerk,
+        document,
+        ..
+    } = ctx.accounts;
+
+    document.load_init()?.init(
+        authority.key(),
+        &title,
+        &uri::normalize(&uri),
+        Clock::get()?.unix_timestamp as u64,
+    );
+
+    clerk.try_assign(document.key())?;
+
+    Ok(())
+}
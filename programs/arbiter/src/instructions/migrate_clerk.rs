@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::rent::Rent;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::error::ErrorCode;
+use crate::migration;
+use crate::seeds;
+use crate::state::{Clerk, Config};
+
+#[derive(Accounts)]
+pub struct MigrateClerk<'info> {
+    /// The wallet paying for the rent of the additional account space,
+    /// which does not need to be the `authority` of the clerk.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The global `Config` program account that must not be paused.
+    #[account(
+        seeds = [seeds::CONFIG],
+        bump = config.bump[0],
+    )]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: The `Clerk` program account stored in the legacy layout, which
+    /// cannot be deserialized as a `Clerk` and is validated by the handler.
+    #[account(mut, owner = crate::ID)]
+    pub clerk: UncheckedAccount<'info>,
+
+    /// The global system program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateClerk<'info> {
+    /// Instruction prevalidation for `migrate_clerk`.
+    pub fn prevalidate(ctx: &Context<Self>) -> Result<()> {
+        require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+        Ok(())
+    }
+}
+
+#[event]
+pub struct ClerkMigrated {
+    pub clerk: Pubkey,
+    pub version: u8,
+}
+
+/// Instruction entrypoint handler for `migrate_clerk`.
+pub fn migrate_clerk_handler(ctx: Context<MigrateClerk>) -> Result<()> {
+    let MigrateClerk {
+        payer,
+        clerk,
+        system_program,
+        ..
+    } = ctx.accounts;
+
+    let mut migrated = migration::try_deserialize_clerk(clerk.key, &clerk.try_borrow_data()?)?;
+    require_eq!(
+        migrated.version,
+        migration::LEGACY_VERSION,
+        ErrorCode::AccountAlreadyMigrated,
+    );
+    migrated.version = Clerk::VERSION;
+
+    let new_size = Clerk::space(migrated.documents.len());
+
+    let shortfall = Rent::get()?
+        .minimum_balance(new_size)
+        .saturating_sub(clerk.lamports());
+    if shortfall > 0 {
+        transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                Transfer {
+                    from: payer.to_account_info(),
+                    to: clerk.to_account_info(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+
+    clerk.realloc(new_size, false)?;
+    migrated.try_serialize(&mut &mut clerk.try_borrow_mut_data()?[..])?;
+
+    emit!(ClerkMigrated {
+        clerk: clerk.key(),
+        version: migrated.version,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::rent::Rent;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::error::ErrorCode;
+use crate::migration;
+use crate::seeds;
+use crate::state::{Config, Document, ParticipantSpace};
+
+#[derive(Accounts)]
+pub struct MigrateDocument<'info> {
+    /// The wallet paying for the rent of the additional account space,
+    /// which does not need to be the `authority` of the document.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The global `Config` program account that must not be paused.
+    #[account(
+        seeds = [seeds::CONFIG],
+        bump = config.bump[0],
+    )]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: The `Document` program account stored in the legacy layout, which
+    /// cannot be deserialized as a `Document` and is validated by the handler.
+    #[account(mut, owner = crate::ID)]
+    pub document: UncheckedAccount<'info>,
+
+    /// The global system program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateDocument<'info> {
+    /// Instruction prevalidation for `migrate_document`.
+    pub fn prevalidate(ctx: &Context<Self>) -> Result<()> {
+        require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+        Ok(())
+    }
+}
+
+#[event]
+pub struct DocumentMigrated {
+    pub document: Pubkey,
+    pub version: u8,
+}
+
+/// Instruction entrypoint handler for `migrate_document`.
+pub fn migrate_document_handler(ctx: Context<MigrateDocument>) -> Result<()> {
+    let MigrateDocument {
+        payer,
+        document,
+        system_program,
+        ..
+    } = ctx.accounts;
+
+    let mut migrated =
+        migration::try_deserialize_document(document.key, &document.try_borrow_data()?)?;
+    require_eq!(
+        migrated.version,
+        migration::LEGACY_VERSION,
+        ErrorCode::AccountAlreadyMigrated,
+    );
+
+    // The legacy layout predates every field after the signatures, so the account
+    // is resized to hold the defaults that were sized to its participants.
+    let new_size = Document::space(
+        migrated.title.len(),
+        migrated.uri.len(),
+        &migrated.attachments,
+        &ParticipantSpace {
+            participants: migrated.participants.len(),
+            eth_participants: migrated.eth_participants.len(),
+            merkle_participants: migrated.settings.participant_count as usize,
+            groups: &migrated.groups,
+        },
+        &migrated.sections,
+        migrated.prerequisites.len(),
+    );
+
+    let next_size = migration::next_data_len(document.data_len(), new_size);
+
+    let shortfall = Rent::get()?
+        .minimum_balance(next_size)
+        .saturating_sub(document.lamports());
+    if shortfall > 0 {
+        transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                Transfer {
+                    from: payer.to_account_info(),
+                    to: document.to_account_info(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+
+    document.realloc(next_size, false)?;
+
+    // An account that needs to grow by more than a single instruction allows is
+    // left in the legacy layout, which still deserializes with the added space,
+    // until another call grows it to the full size of the migrated layout.
+    if next_size < new_size {
+        return Ok(());
+    }
+
+    migrated.version = Document::VERSION;
+    migrated.try_serialize(&mut &mut document.try_borrow_mut_data()?[..])?;
+
+    emit!(DocumentMigrated {
+        document: document.key(),
+        version: migrated.version,
+    });
+
+    Ok(())
+}
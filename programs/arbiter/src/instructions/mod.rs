@@ -1,13 +1,65 @@
+mod accept_invitation;
+mod add_attachment;
+mod add_large_participants;
 mod add_participant;
 mod add_signature;
+mod claim_receipt;
+mod compress_document;
+mod extend_deadline;
 mod finalize;
+mod finalize_bundle;
+mod finalize_large_document;
+mod grant_delegation;
+mod grant_fee_waiver;
+mod init_bundle;
 mod init_clerk;
+mod init_config;
 mod init_document;
+mod init_large_document;
+mod migrate_clerk;
+mod migrate_document;
+mod open_for_signing;
+mod remove_attachment;
+mod revoke_delegation;
+mod revoke_fee_waiver;
+mod rotate_participant;
+mod set_fee;
+mod set_paused;
+mod sign_large_document;
+mod submit_eth_signature;
+mod update_config;
+mod update_document;
 mod upgrade;
 
+pub use accept_invitation::*;
+pub use add_attachment::*;
+pub use add_large_participants::*;
 pub use add_participant::*;
 pub use add_signature::*;
+pub use claim_receipt::*;
+pub use compress_document::*;
+pub use extend_deadline::*;
 pub use finalize::*;
+pub use finalize_bundle::*;
+pub use finalize_large_document::*;
+pub use grant_delegation::*;
+pub use grant_fee_waiver::*;
+pub use init_bundle::*;
 pub use init_clerk::*;
+pub use init_config::*;
 pub use init_document::*;
+pub use init_large_document::*;
+pub use migrate_clerk::*;
+pub use migrate_document::*;
+pub use open_for_signing::*;
+pub use remove_attachment::*;
+pub use revoke_delegation::*;
+pub use revoke_fee_waiver::*;
+pub use rotate_participant::*;
+pub use set_fee::*;
+pub use set_paused::*;
+pub use sign_large_document::*;
+pub use submit_eth_signature::*;
+pub use update_config::*;
+pub use update_document::*;
 pub use upgrade::*;
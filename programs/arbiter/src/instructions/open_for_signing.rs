@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::seeds;
+use crate::state::{Config, Document};
+
+#[derive(Accounts)]
+pub struct OpenForSigning<'info> {
+    /// The system account that is signing the transaction and
+    /// is the authority of the `document` being opened.
+    pub authority: Signer<'info>,
+
+    /// The global `Config` program account that must not be paused.
+    #[account(
+        seeds = [seeds::CONFIG],
+        bump = config.bump[0],
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The draft `Document` program account that is being opened for signing.
+    #[account(
+        mut,
+        seeds = [
+            seeds::DOCUMENT,
+            authority.key().as_ref(),
+            Document::title_seed(&document.title),
+        ],
+        bump = document.bump[0],
+        has_one = authority,
+        constraint = document.is_draft() @ ErrorCode::DocumentIsNotDraft,
+    )]
+    pub document: Account<'info, Document>,
+}
+
+impl<'info> OpenForSigning<'info> {
+    /// Instruction prevalidation for `open_for_signing`.
+    pub fn prevalidate(ctx: &Context<Self>) -> Result<()> {
+        require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+        Ok(())
+    }
+}
+
+#[event]
+pub struct DocumentOpened {
+    pub document: Pubkey,
+}
+
+/// Instruction entrypoint handler for `open_for_signing`.
+pub fn open_for_signing_handler(ctx: Context<OpenForSigning>) -> Result<()> {
+    let document = &mut ctx.accounts.document;
+    document.settings.draft = false;
+
+    emit!(DocumentOpened {
+        document: document.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::seeds;
+use crate::state::{Attachment, Config, Document};
+
+#[derive(Accounts)]
+#[instruction(label: String)]
+pub struct RemoveAttachment<'info> {
+    /// The system account that is signing the transaction, is the authority
+    /// of the `document` being updated and receives the freed rent.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The global `Config` program account that must not be paused.
+    #[account(
+        seeds = [seeds::CONFIG],
+        bump = config.bump[0],
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The `Document` program account that the attachment is being removed from.
+    #[account(
+        mut,
+        seeds = [
+            seeds::DOCUMENT,
+            authority.key().as_ref(),
+            Document::title_seed(&document.title),
+        ],
+        bump = document.bump[0],
+        has_one = authority,
+        constraint = !document.is_finalized() @ ErrorCode::DocumentIsAlreadyFinalized,
+        constraint = !document.has_signatures() @ ErrorCode::DocumentHasSignatures,
+    )]
+    pub document: Account<'info, Document>,
+}
+
+impl<'info> RemoveAttachment<'info> {
+    /// Instruction prevalidation for `remove_attachment`.
+    pub fn prevalidate(ctx: &Context<Self>, label: &str) -> Result<()> {
+        require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+        ctx.accounts.document.try_find_attachment(label)?;
+        Ok(())
+    }
+}
+
+#[event]
+pub struct AttachmentRemoved {
+    pub document: Pubkey,
+    pub label: String,
+}
+
+/// Instruction entrypoint handler for `remove_attachment`.
+pub fn remove_attachment_handler(ctx: Context<RemoveAttachment>, label: String) -> Result<()> {
+    let RemoveAttachment {
+        authority,
+        document,
+        ..
+    } = ctx.accounts;
+
+    let i = document.try_find_attachment(&label)?;
+    let removed = document.attachments.remove(i);
+
+    let new_size = document
+        .to_account_info()
+        .data_len()
+        .checked_sub(Attachment::space(removed.label.len(), removed.uri.len()))
+        .unwrap();
+
+    let refund = document
+        .to_account_info()
+        .lamports()
+        .checked_sub(Rent::get()?.minimum_balance(new_size))
+        .unwrap();
+
+    document.to_account_info().realloc(new_size, false)?;
+    **document.to_account_info().try_borrow_mut_lamports()? -= refund;
+    **authority.to_account_info().try_borrow_mut_lamports()? += refund;
+
+    emit!(AttachmentRemoved {
+        document: document.key(),
+        label,
+    });
+
+    Ok(())
+}
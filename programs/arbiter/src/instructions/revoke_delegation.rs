@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::seeds;
+use crate::state::Delegation;
+
+#[derive(Accounts)]
+pub struct RevokeDelegation<'info> {
+    /// The participant wallet that is signing the transaction and
+    /// originally granted the `delegation` that is being revoked.
+    #[account(mut)]
+    pub participant: Signer<'info>,
+
+    /// The `Delegation` program account that is being closed with
+    /// its rent being returned to the `participant`.
+    #[account(
+        mut,
+        seeds = [
+            seeds::DELEGATION,
+            participant.key().as_ref(),
+            delegation.delegate.as_ref(),
+        ],
+        bump = delegation.bump[0],
+        has_one = participant,
+        close = participant,
+    )]
+    pub delegation: Account<'info, Delegation>,
+}
+
+impl<'info> RevokeDelegation<'info> {
+    /// Instruction prevalidation for `revoke_delegation`.
+    pub fn prevalidate(_ctx: &Context<Self>) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[event]
+pub struct DelegationRevoked {
+    pub participant: Pubkey,
+    pub delegate: Pubkey,
+}
+
+/// Instruction entrypoint handler for `revoke_delegation`.
+pub fn revoke_delegation_handler(ctx: Context<RevokeDelegation>) -> Result<()> {
+    let RevokeDelegation {
+        participant,
+        delegation,
+    } = ctx.accounts;
+
+    emit!(DelegationRevoked {
+        participant: participant.key(),
+        delegate: delegation.delegate,
+    });
+
+    Ok(())
+}
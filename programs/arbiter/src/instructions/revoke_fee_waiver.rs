@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::seeds;
+use crate::state::{Config, FeeWaiver};
+
+#[derive(Accounts)]
+pub struct RevokeFeeWaiver<'info> {
+    /// The current admin of the global configuration.
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// The global `Config` program account that the `admin` is verified against.
+    #[account(
+        seeds = [seeds::CONFIG],
+        bump = config.bump[0],
+        has_one = admin,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The `FeeWaiver` program account that is being closed with
+    /// its rent being returned to the `admin`.
+    #[account(
+        mut,
+        seeds = [
+            seeds::WAIVER,
+            waiver.clerk.as_ref(),
+        ],
+        bump = waiver.bump[0],
+        close = admin,
+    )]
+    pub waiver: Account<'info, FeeWaiver>,
+}
+
+impl<'info> RevokeFeeWaiver<'info> {
+    /// Instruction prevalidation for `revoke_fee_waiver`.
+    pub fn prevalidate(_ctx: &Context<Self>) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[event]
+pub struct FeeWaiverRevoked {
+    pub clerk: Pubkey,
+}
+
+/// Instruction entrypoint handler for `revoke_fee_waiver`.
+pub fn revoke_fee_waiver_handler(ctx: Context<RevokeFeeWaiver>) -> Result<()> {
+    emit!(FeeWaiverRevoked {
+        clerk: ctx.accounts.waiver.clerk,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::seeds;
+use crate::state::{Config, Document};
+
+#[derive(Accounts)]
+pub struct RotateParticipant<'info> {
+    /// The system account that is signing the transaction and
+    /// is the authority of the `document` being updated.
+    pub authority: Signer<'info>,
+
+    /// CHECK: The participant public key currently listed on the `document`
+    /// that is being replaced. Either this or the `new_participant` must
+    /// be a signer of the transaction, which is checked in prevalidation.
+    pub old_participant: UncheckedAccount<'info>,
+
+    /// CHECK: The public key that is replacing the `old_participant` on the
+    /// `document`. Either this or the `old_participant` must be a signer of
+    /// the transaction, which is checked in prevalidation.
+    pub new_participant: UncheckedAccount<'info>,
+
+    /// The global `Config` program account that must not be paused.
+    #[account(
+        seeds = [seeds::CONFIG],
+        bump = config.bump[0],
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The `Document` program account that is having one of
+    /// its participant public keys rotated.
+    #[account(
+        mut,
+        seeds = [
+            seeds::DOCUMENT,
+            authority.key().as_ref(),
+            Document::title_seed(&document.title),
+        ],
+        bump = document.bump[0],
+        has_one = authority,
+        constraint = !document.is_finalized() @ ErrorCode::DocumentIsAlreadyFinalized,
+        constraint = document.try_find_participant(&new_participant.key()).is_err() @ ErrorCode::ParticipantAlreadyAssociated,
+    )]
+    pub document: Account<'info, Document>,
+}
+
+impl<'info> RotateParticipant<'info> {
+    /// Instruction prevalidation for `rotate_participant`.
+    pub fn prevalidate(ctx: &Context<Self>) -> Result<()> {
+        require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+
+        require!(
+            ctx.accounts.old_participant.is_signer || ctx.accounts.new_participant.is_signer,
+            ErrorCode::ParticipantRotationNotApproved,
+        );
+        Ok(())
+    }
+}
+
+#[event]
+pub struct ParticipantRotated {
+    pub document: Pubkey,
+    pub old_participant: Pubkey,
+    pub new_participant: Pubkey,
+    pub signature_reset: bool,
+}
+
+/// Instruction entrypoint handler for `rotate_participant`.
+pub fn rotate_participant_handler(
+    ctx: Context<RotateParticipant>,
+    reset_signature: bool,
+) -> Result<()> {
+    let RotateParticipant {
+        old_participant,
+        new_participant,
+        document,
+        ..
+    } = ctx.accounts;
+
+    document.try_rotate(
+        &old_participant.key(),
+        new_participant.key(),
+        reset_signature,
+    )?;
+
+    emit!(ParticipantRotated {
+        document: document.key(),
+        old_participant: old_participant.key(),
+        new_participant: new_participant.key(),
+        signature_reset: reset_signature,
+    });
+
+    Ok(())
+}
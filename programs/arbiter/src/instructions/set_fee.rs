@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::seeds;
+use crate::state::Config;
+
+#[derive(Accounts)]
+pub struct SetFee<'info> {
+    /// The current admin of the global configuration.
+    pub admin: Signer<'info>,
+
+    /// The global `Config` program account whose document fee is being set.
+    #[account(
+        mut,
+        seeds = [seeds::CONFIG],
+        bump = config.bump[0],
+        has_one = admin,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+impl<'info> SetFee<'info> {
+    /// Instruction prevalidation for `set_fee`.
+    pub fn prevalidate(_ctx: &Context<Self>) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[event]
+pub struct FeeUpdated {
+    pub admin: Pubkey,
+    pub treasury: Pubkey,
+    pub document_fee: u64,
+}
+
+/// Instruction entrypoint handler for `set_fee`.
+pub fn set_fee_handler(ctx: Context<SetFee>, document_fee: u64, treasury: Pubkey) -> Result<()> {
+    let SetFee { admin, config } = ctx.accounts;
+
+    config.document_fee = document_fee;
+    config.treasury = treasury;
+
+    emit!(FeeUpdated {
+        admin: admin.key(),
+        treasury,
+        document_fee,
+    });
+
+    Ok(())
+}
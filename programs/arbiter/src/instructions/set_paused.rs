@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::seeds;
+use crate::state::Config;
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    /// The current admin of the global configuration.
+    pub admin: Signer<'info>,
+
+    /// The global `Config` program account whose pause state is being set.
+    #[account(
+        mut,
+        seeds = [seeds::CONFIG],
+        bump = config.bump[0],
+        has_one = admin,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+impl<'info> SetPaused<'info> {
+    /// Instruction prevalidation for `set_paused`.
+    pub fn prevalidate(_ctx: &Context<Self>) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[event]
+pub struct PauseStateChanged {
+    pub admin: Pubkey,
+    pub paused: bool,
+}
+
+/// Instruction entrypoint handler for `set_paused`.
+pub fn set_paused_handler(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+    let SetPaused { admin, config } = ctx.accounts;
+
+    config.paused = paused;
+
+    emit!(PauseStateChanged {
+        admin: admin.key(),
+        paused,
+    });
+
+    Ok(())
+}
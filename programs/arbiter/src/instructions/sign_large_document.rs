@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::seeds;
+use crate::state::{Config, LargeDocument};
+
+#[derive(Accounts)]
+pub struct SignLargeDocument<'info> {
+    /// The participant signing the `document`.
+    pub signer: Signer<'info>,
+
+    /// The global `Config` program account that must not be paused.
+    #[account(
+        seeds = [seeds::CONFIG],
+        bump = config.bump[0],
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The `LargeDocument` program account that the `signer` is a participant of.
+    #[account(
+        mut,
+        constraint = !document.load()?.is_finalized() @ ErrorCode::DocumentIsAlreadyFinalized,
+    )]
+    pub document: AccountLoader<'info, LargeDocument>,
+}
+
+impl<'info> SignLargeDocument<'info> {
+    /// Instruction prevalidation for `sign_large_document`.
+    pub fn prevalidate(ctx: &Context<Self>) -> Result<()> {
+        require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+        Ok(())
+    }
+}
+
+#[event]
+pub struct LargeDocumentSigned {
+    pub document: Pubkey,
+    pub participant: Pubkey,
+}
+
+/// Instruction entrypoint handler for `sign_large_document`.
+pub fn sign_large_document_handler(ctx: Context<SignLargeDocument>) -> Result<()> {
+    let SignLargeDocument {
+        signer, document, ..
+    } = ctx.accounts;
+
+    document.load_mut()?.try_sign(&signer.key())?;
+
+    emit!(LargeDocumentSigned {
+        document: document.key(),
+        participant: signer.key(),
+    });
+
+    Ok(())
+}
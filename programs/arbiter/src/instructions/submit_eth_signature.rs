@@ -0,0 +1,144 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::secp256k1_program;
+use anchor_lang::solana_program::sysvar;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+
+use crate::error::ErrorCode;
+use crate::seeds;
+use crate::state::{Config, Document};
+
+/// The byte size of the secp256k1 instruction header with a single signature.
+const SECP256K1_HEADER_SIZE: usize = 12;
+
+#[derive(Accounts)]
+#[instruction(eth_address: [u8; 20], section: u8)]
+pub struct SubmitEthSignature<'info> {
+    /// The wallet relaying the Ethereum participant's signature
+    /// and paying for the transaction.
+    pub submitter: Signer<'info>,
+
+    /// The global `Config` program account that must not be paused.
+    #[account(
+        seeds = [seeds::CONFIG],
+        bump = config.bump[0],
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The `Document` program account that the Ethereum participant
+    /// is proving their signature on for the `section`.
+    #[account(
+        mut,
+        seeds = [
+            seeds::DOCUMENT,
+            document.authority.as_ref(),
+            Document::title_seed(&document.title),
+        ],
+        bump = document.bump[0],
+        constraint = !document.is_finalized() @ ErrorCode::DocumentIsAlreadyFinalized,
+        constraint = !document.is_draft() @ ErrorCode::DocumentIsDraft,
+    )]
+    pub document: Account<'info, Document>,
+
+    /// CHECK: The instructions sysvar used to read the secp256k1
+    /// signature verification instruction preceding this one.
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+impl<'info> SubmitEthSignature<'info> {
+    /// Instruction prevalidation for `submit_eth_signature`.
+    pub fn prevalidate(ctx: &Context<Self>, eth_address: &[u8; 20], section: u8) -> Result<()> {
+        require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+
+        let instructions = ctx.accounts.instructions_sysvar.to_account_info();
+
+        let current = load_current_index_checked(&instructions)?;
+        require_gt!(current, 0, ErrorCode::InvalidEthSignatureInstruction);
+
+        let index = current - 1;
+        let ix = load_instruction_at_checked(index as usize, &instructions)?;
+
+        require_keys_eq!(
+            ix.program_id,
+            secp256k1_program::ID,
+            ErrorCode::InvalidEthSignatureInstruction,
+        );
+
+        let document = &ctx.accounts.document;
+        verify_secp256k1_data(
+            &ix.data,
+            index as u8,
+            eth_address,
+            &document.eth_message(&document.key(), section),
+        )
+    }
+}
+
+/// Verifies that the data of a secp256k1 program instruction at the argued
+/// transaction index contains exactly one signature of the `message` by the
+/// `eth_address`, and that every offset points into the instruction itself.
+fn verify_secp256k1_data(
+    data: &[u8],
+    index: u8,
+    eth_address: &[u8; 20],
+    message: &[u8],
+) -> Result<()> {
+    require!(
+        data.len() >= SECP256K1_HEADER_SIZE && data[0] == 1,
+        ErrorCode::InvalidEthSignatureInstruction,
+    );
+
+    let read_u16 = |at: usize| u16::from_le_bytes([data[at], data[at + 1]]) as usize;
+
+    require!(
+        data[3] == index && data[6] == index && data[11] == index,
+        ErrorCode::InvalidEthSignatureInstruction,
+    );
+
+    let address_offset = read_u16(4);
+    let message_offset = read_u16(7);
+    let message_size = read_u16(9);
+
+    require!(
+        data.get(address_offset..address_offset + Document::ETH_ADDRESS_SIZE)
+            == Some(&eth_address[..])
+            && data.get(message_offset..message_offset + message_size) == Some(message),
+        ErrorCode::EthSignatureMisMatch,
+    );
+
+    Ok(())
+}
+
+#[event]
+pub struct EthSignatureAdded {
+    document: Pubkey,
+    eth_address: [u8; 20],
+    section: u8,
+    submitter: Pubkey,
+}
+
+/// Instruction entrypoint handler for `submit_eth_signature`.
+pub fn submit_eth_signature_handler(
+    ctx: Context<SubmitEthSignature>,
+    eth_address: [u8; 20],
+    section: u8,
+) -> Result<()> {
+    let SubmitEthSignature {
+        submitter,
+        document,
+        ..
+    } = ctx.accounts;
+
+    document.try_sign_eth(&eth_address, section, &submitter.key())?;
+
+    emit!(EthSignatureAdded {
+        document: document.key(),
+        eth_address,
+        section,
+        submitter: submitter.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::seeds;
+use crate::state::{Config, ConfigLimits};
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    /// The current admin of the global configuration.
+    pub admin: Signer<'info>,
+
+    /// The global `Config` program account that is being updated.
+    #[account(
+        mut,
+        seeds = [seeds::CONFIG],
+        bump = config.bump[0],
+        has_one = admin,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+impl<'info> UpdateConfig<'info> {
+    /// Instruction prevalidation for `update_config`.
+    pub fn prevalidate(_ctx: &Context<Self>, limits: &ConfigLimits) -> Result<()> {
+        require!(limits.is_valid(), ErrorCode::InvalidConfigLimits);
+        Ok(())
+    }
+}
+
+#[event]
+pub struct ConfigUpdated {
+    pub admin: Pubkey,
+    pub limits: ConfigLimits,
+}
+
+/// Instruction entrypoint handler for `update_config`.
+pub fn update_config_handler(
+    ctx: Context<UpdateConfig>,
+    limits: ConfigLimits,
+    new_admin: Option<Pubkey>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    config.limits = limits;
+    if let Some(admin) = new_admin {
+        config.admin = admin;
+    }
+
+    emit!(ConfigUpdated {
+        admin: config.admin,
+        limits,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,203 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use std::cmp::Ordering;
+
+use super::init_document::{
+    validate_participant_set, validate_required_weight, validate_signer_groups,
+};
+use crate::error::ErrorCode;
+use crate::seeds;
+use crate::state::{Config, Document, DocumentSettings, ParticipantSpace};
+use crate::uri;
+
+#[derive(Accounts)]
+pub struct UpdateDocument<'info> {
+    /// The system account that is signing the transaction and
+    /// is the authority of the `document` being updated.
+    pub authority: Signer<'info>,
+
+    /// The wallet paying for or receiving the difference in reallocation
+    /// rent of the `document` for its updated size.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The global `Config` program account with the enforced limits
+    /// that must not be paused.
+    #[account(
+        seeds = [seeds::CONFIG],
+        bump = config.bump[0],
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The `Document` program account that is being updated.
+    #[account(
+        mut,
+        seeds = [
+            seeds::DOCUMENT,
+            authority.key().as_ref(),
+            Document::title_seed(&document.title),
+        ],
+        bump = document.bump[0],
+        has_one = authority,
+        constraint = !document.is_finalized() @ ErrorCode::DocumentIsAlreadyFinalized,
+    )]
+    pub document: Account<'info, Document>,
+
+    /// The global system program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> UpdateDocument<'info> {
+    /// Instruction prevalidation for `update_document`.
+    pub fn prevalidate(
+        ctx: &Context<Self>,
+        uri: &Option<String>,
+        participants: &Option<Vec<Pubkey>>,
+        settings: &Option<DocumentSettings>,
+    ) -> Result<()> {
+        require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+
+        let limits = &ctx.accounts.config.limits;
+        let document = &ctx.accounts.document;
+
+        // Once a document is opened for signing only its URI can be changed,
+        // which invalidates every signature that has already been submitted.
+        require!(
+            document.is_draft() || (participants.is_none() && settings.is_none()),
+            ErrorCode::DocumentIsNotDraft,
+        );
+
+        if let Some(u) = uri {
+            uri::validate(u, limits.max_uri_length as usize)?;
+        }
+
+        if participants.is_none() && settings.is_none() {
+            return Ok(());
+        }
+
+        let settings = settings.as_ref().unwrap_or(&document.settings);
+
+        validate_participant_set(
+            limits,
+            participants.as_ref().unwrap_or(&document.participants),
+            &document.eth_participants,
+            settings,
+        )?;
+
+        match participants {
+            Some(p) => {
+                validate_required_weight(&vec![1; p.len()], &document.eth_participants, settings)
+            }
+            None => {
+                validate_required_weight(&document.weights, &document.eth_participants, settings)?;
+                validate_signer_groups(&document.groups, document.participants.len(), settings)
+            }
+        }
+    }
+
+    /// Reallocates the `document` to the argued size, charging the `payer` the rent
+    /// for any additional bytes or refunding it the rent of the released bytes.
+    fn resize(&self, new_size: usize) -> Result<()> {
+        let document = self.document.to_account_info();
+        let rent = Rent::get()?;
+
+        match new_size.cmp(&document.data_len()) {
+            Ordering::Greater => {
+                let delta_bytes = new_size - document.data_len();
+                transfer(
+                    CpiContext::new(
+                        self.system_program.to_account_info(),
+                        Transfer {
+                            from: self.payer.to_account_info(),
+                            to: document.clone(),
+                        },
+                    ),
+                    rent.minimum_balance(delta_bytes),
+                )?;
+            }
+            Ordering::Less => {
+                let refund = document
+                    .lamports()
+                    .checked_sub(rent.minimum_balance(new_size))
+                    .unwrap();
+
+                **document.try_borrow_mut_lamports()? -= refund;
+                **self.payer.to_account_info().try_borrow_mut_lamports()? += refund;
+            }
+            Ordering::Equal => return Ok(()),
+        }
+
+        document.realloc(new_size, false)?;
+        Ok(())
+    }
+}
+
+#[event]
+pub struct DocumentUpdated {
+    pub document: Pubkey,
+    pub uri: String,
+    pub signatures_reset: bool,
+}
+
+/// Instruction entrypoint handler for `update_document`.
+pub fn update_document_handler(
+    ctx: Context<UpdateDocument>,
+    uri: Option<String>,
+    participants: Option<Vec<Pubkey>>,
+    settings: Option<DocumentSettings>,
+) -> Result<()> {
+    let new_uri = uri.map(|u| uri::normalize(&u));
+    let participants_replaced = participants.is_some();
+    let settings_replaced = settings.is_some();
+
+    let document = &ctx.accounts.document;
+    let new_participants = participants.unwrap_or_else(|| document.participants.clone());
+    let new_groups = if participants_replaced {
+        vec![]
+    } else {
+        document.groups.clone()
+    };
+    let new_settings = DocumentSettings {
+        draft: true,
+        ..settings.unwrap_or(document.settings)
+    };
+
+    ctx.accounts.resize(Document::space(
+        document.title.len(),
+        new_uri.as_ref().unwrap_or(&document.uri).len(),
+        &document.attachments,
+        &ParticipantSpace {
+            participants: new_participants.len(),
+            eth_participants: document.eth_participants.len(),
+            merkle_participants: new_settings.participant_count as usize,
+            groups: &new_groups,
+        },
+        &document.sections,
+        document.prerequisites.len(),
+    ))?;
+
+    let document = &mut ctx.accounts.document;
+    let mut signatures_reset = false;
+
+    if let Some(u) = new_uri {
+        if u != document.uri && document.has_signatures() {
+            document.reset_signatures();
+            signatures_reset = true;
+        }
+        document.uri = u;
+    }
+
+    if participants_replaced {
+        document.reset_participants(new_participants, new_settings);
+    } else if settings_replaced {
+        document.reset_settings(new_settings);
+    }
+
+    emit!(DocumentUpdated {
+        document: document.key(),
+        uri: document.uri.clone(),
+        signatures_reset,
+    });
+
+    Ok(())
+}
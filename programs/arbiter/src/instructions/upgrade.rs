@@ -4,7 +4,7 @@ use anchor_lang::system_program::{transfer, Transfer};
 
 use crate::error::ErrorCode;
 use crate::seeds;
-use crate::state::Clerk;
+use crate::state::{Clerk, Config};
 
 #[derive(Accounts)]
 #[instruction(increase_amount: u8)]
@@ -17,6 +17,14 @@ pub struct Upgrade<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
+    /// The global `Config` program account with the enforced limits
+    /// that must not be paused.
+    #[account(
+        seeds = [seeds::CONFIG],
+        bump = config.bump[0],
+    )]
+    pub config: Account<'info, Config>,
+
     /// The original `Clerk` program account that is associated with
     /// the `authority` and is being reinitialized with a larger limit.
     #[account(
@@ -37,8 +45,15 @@ pub struct Upgrade<'info> {
 
 impl<'info> Upgrade<'info> {
     /// Instruction prevalidation for `upgrade_amount`.
-    pub fn prevalidate(_ctx: &Context<Self>, increase_amount: u8) -> Result<()> {
+    pub fn prevalidate(ctx: &Context<Self>, increase_amount: u8) -> Result<()> {
+        require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+
         require_gt!(increase_amount, 0, ErrorCode::ClerkUpgradeAmountIsZero);
+        require_gte!(
+            ctx.accounts.config.limits.max_clerk_limit as usize,
+            ctx.accounts.clerk.limit() + increase_amount as usize,
+            ErrorCode::ClerkLimitTooLarge,
+        );
         Ok(())
     }
 }
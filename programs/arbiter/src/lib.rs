@@ -2,9 +2,15 @@ use anchor_lang::prelude::*;
 
 mod error;
 mod instructions;
+pub mod merkle;
+pub mod migration;
 pub mod state;
+pub mod token_2022;
+pub mod token_metadata;
+pub mod uri;
 
 use instructions::*;
+use state::{ConfigLimits, DocumentSettings, ParticipantProof, ParticipantSet};
 
 declare_id!("Arbitc5Kwf3EQh1USXjk93KYNXqCUcKgyRHu9HposTLa");
 
@@ -12,10 +18,22 @@ declare_id!("Arbitc5Kwf3EQh1USXjk93KYNXqCUcKgyRHu9HposTLa");
 pub mod seeds {
     use super::constant;
 
+    /// The static seed for `Bundle` program accounts.
+    #[constant]
+    pub const BUNDLE: &[u8] = b"bundle";
+
     /// The static seed for `Clerk` program accounts.
     #[constant]
     pub const CLERK: &[u8] = b"clerk";
 
+    /// The static seed for the global `Config` program account.
+    #[constant]
+    pub const CONFIG: &[u8] = b"config";
+
+    /// The static seed for `Delegation` program accounts.
+    #[constant]
+    pub const DELEGATION: &[u8] = b"delegation";
+
     /// The static seed for `Document` program accounts.
     #[constant]
     pub const DOCUMENT: &[u8] = b"document";
@@ -24,18 +42,167 @@ pub mod seeds {
     #[constant]
     pub const MINT: &[u8] = b"mint";
 
+    /// The static seed for `Receipt` program accounts.
+    #[constant]
+    pub const RECEIPT: &[u8] = b"receipt";
+
     /// The static seed for staged program accounts.
     #[constant]
     pub const STAGED: &[u8] = b"staged";
+
+    /// The static seed for `FeeWaiver` program accounts.
+    #[constant]
+    pub const WAIVER: &[u8] = b"waiver";
 }
 
 #[program]
 pub mod arbiter {
     use super::*;
 
+    #[access_control(AcceptInvitation::prevalidate(&ctx))]
+    pub fn accept_invitation(ctx: Context<AcceptInvitation>) -> Result<()> {
+        instructions::accept_invitation_handler(ctx)
+    }
+
+    #[access_control(AddAttachment::prevalidate(&ctx, &label, &uri))]
+    pub fn add_attachment(
+        ctx: Context<AddAttachment>,
+        label: String,
+        uri: String,
+        content_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::add_attachment_handler(ctx, label, uri, content_hash)
+    }
+
+    #[access_control(AddLargeParticipants::prevalidate(&ctx, &participants))]
+    pub fn add_large_participants(
+        ctx: Context<AddLargeParticipants>,
+        participants: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::add_large_participants_handler(ctx, participants)
+    }
+
+    #[access_control(ClaimReceipt::prevalidate(&ctx))]
+    pub fn claim_receipt(ctx: Context<ClaimReceipt>) -> Result<()> {
+        instructions::claim_receipt_handler(ctx)
+    }
+
+    #[access_control(CompressDocument::prevalidate(&ctx))]
+    pub fn compress_document(ctx: Context<CompressDocument>) -> Result<()> {
+        instructions::compress_document_handler(ctx)
+    }
+
+    #[access_control(ExtendDeadline::prevalidate(&ctx))]
+    pub fn extend_deadline(
+        ctx: Context<ExtendDeadline>,
+        participant: Pubkey,
+        deadline: u64,
+    ) -> Result<()> {
+        instructions::extend_deadline_handler(ctx, participant, deadline)
+    }
+
+    #[access_control(FinalizeBundle::prevalidate(&ctx))]
+    pub fn finalize_bundle(ctx: Context<FinalizeBundle>) -> Result<()> {
+        instructions::finalize_bundle_handler(ctx)
+    }
+
+    #[access_control(FinalizeLargeDocument::prevalidate(&ctx))]
+    pub fn finalize_large_document(ctx: Context<FinalizeLargeDocument>) -> Result<()> {
+        instructions::finalize_large_document_handler(ctx)
+    }
+
+    #[access_control(GrantDelegation::prevalidate(&ctx, expires_at))]
+    pub fn grant_delegation(
+        ctx: Context<GrantDelegation>,
+        document: Option<Pubkey>,
+        expires_at: Option<u64>,
+    ) -> Result<()> {
+        instructions::grant_delegation_handler(ctx, document, expires_at)
+    }
+
+    #[access_control(GrantFeeWaiver::prevalidate(&ctx))]
+    pub fn grant_fee_waiver(ctx: Context<GrantFeeWaiver>) -> Result<()> {
+        instructions::grant_fee_waiver_handler(ctx)
+    }
+
+    #[access_control(InitLargeDocument::prevalidate(&ctx, &title, &uri))]
+    pub fn init_large_document(
+        ctx: Context<InitLargeDocument>,
+        title: String,
+        uri: String,
+    ) -> Result<()> {
+        instructions::init_large_document_handler(ctx, title, uri)
+    }
+
+    #[access_control(MigrateClerk::prevalidate(&ctx))]
+    pub fn migrate_clerk(ctx: Context<MigrateClerk>) -> Result<()> {
+        instructions::migrate_clerk_handler(ctx)
+    }
+
+    #[access_control(MigrateDocument::prevalidate(&ctx))]
+    pub fn migrate_document(ctx: Context<MigrateDocument>) -> Result<()> {
+        instructions::migrate_document_handler(ctx)
+    }
+
+    #[access_control(OpenForSigning::prevalidate(&ctx))]
+    pub fn open_for_signing(ctx: Context<OpenForSigning>) -> Result<()> {
+        instructions::open_for_signing_handler(ctx)
+    }
+
+    #[access_control(RemoveAttachment::prevalidate(&ctx, &label))]
+    pub fn remove_attachment(ctx: Context<RemoveAttachment>, label: String) -> Result<()> {
+        instructions::remove_attachment_handler(ctx, label)
+    }
+
+    #[access_control(RevokeDelegation::prevalidate(&ctx))]
+    pub fn revoke_delegation(ctx: Context<RevokeDelegation>) -> Result<()> {
+        instructions::revoke_delegation_handler(ctx)
+    }
+
+    #[access_control(RevokeFeeWaiver::prevalidate(&ctx))]
+    pub fn revoke_fee_waiver(ctx: Context<RevokeFeeWaiver>) -> Result<()> {
+        instructions::revoke_fee_waiver_handler(ctx)
+    }
+
+    #[access_control(SignLargeDocument::prevalidate(&ctx))]
+    pub fn sign_large_document(ctx: Context<SignLargeDocument>) -> Result<()> {
+        instructions::sign_large_document_handler(ctx)
+    }
+
+    /// Records the signature of a document participant for one of its sections.
+    /// Members of a Merkle committed participant set must provide the `proof`
+    /// of their inclusion, which is `None` for participants stored on the document.
+    ///
+    /// Participants are not required to be wallets. Another program can list one of
+    /// its program derived addresses as a participant and sign on its behalf by
+    /// invoking this instruction with the address as both the `signer` and the
+    /// `participant` through the CPI client that is enabled by the `cpi` feature:
+    ///
+    /// ```ignore
+    /// arbiter::cpi::add_signature(
+    ///     CpiContext::new_with_signer(
+    ///         arbiter_program.to_account_info(),
+    ///         arbiter::cpi::accounts::AddSignature {
+    ///             signer: governance.to_account_info(),
+    ///             participant: governance.to_account_info(),
+    ///             config: config.to_account_info(),
+    ///             document: document.to_account_info(),
+    ///         },
+    ///         &[&[b"governance", realm.as_ref(), &[bump]]],
+    ///     ),
+    ///     section,
+    ///     memo,
+    ///     None,
+    /// )?;
+    /// ```
     #[access_control(AddSignature::prevalidate(&ctx))]
-    pub fn add_signature(ctx: Context<AddSignature>) -> Result<()> {
-        instructions::add_signature_handler(ctx)
+    pub fn add_signature(
+        ctx: Context<AddSignature>,
+        section: u8,
+        memo: Option<[u8; 32]>,
+        proof: Option<ParticipantProof>,
+    ) -> Result<()> {
+        instructions::add_signature_handler(ctx, section, memo, proof)
     }
 
     #[access_control(Finalize::prevalidate(&ctx))]
@@ -43,19 +210,52 @@ pub mod arbiter {
         instructions::finalize_handler(ctx)
     }
 
+    #[access_control(InitBundle::prevalidate(&ctx, &name, &documents))]
+    pub fn init_bundle(
+        ctx: Context<InitBundle>,
+        name: String,
+        documents: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::init_bundle_handler(ctx, name, documents)
+    }
+
     #[access_control(InitClerk::prevalidate(&ctx, limit))]
     pub fn init_clerk(ctx: Context<InitClerk>, limit: u8) -> Result<()> {
         instructions::init_clerk_handler(ctx, limit)
     }
 
-    #[access_control(InitDocument::prevalidate(&ctx, &title, &uri, &participants))]
+    #[access_control(InitConfig::prevalidate(&ctx, &limits))]
+    pub fn init_config(ctx: Context<InitConfig>, limits: ConfigLimits) -> Result<()> {
+        instructions::init_config_handler(ctx, limits)
+    }
+
+    #[access_control(InitDocument::prevalidate(
+        &ctx,
+        &title,
+        &uri,
+        &participant_set,
+        &sections,
+        &prerequisites,
+        &settings,
+    ))]
     pub fn init_document(
         ctx: Context<InitDocument>,
         title: String,
         uri: String,
-        participants: Vec<Pubkey>,
+        participant_set: ParticipantSet,
+        sections: Vec<String>,
+        prerequisites: Vec<Pubkey>,
+        settings: DocumentSettings,
     ) -> Result<()> {
-        instructions::init_document_handler(ctx, title, uri, participants)
+        instructions::init_document_handler(
+            ctx,
+            title,
+            uri,
+            participant_set,
+            sections,
+            prerequisites,
+            settings,
+        )
     }
 
     #[access_control(AddParticipant::prevalidate(&ctx))]
@@ -63,6 +263,52 @@ pub mod arbiter {
         instructions::add_participant_handler(ctx, participant)
     }
 
+    #[access_control(RotateParticipant::prevalidate(&ctx))]
+    pub fn rotate_participant(
+        ctx: Context<RotateParticipant>,
+        reset_signature: bool,
+    ) -> Result<()> {
+        instructions::rotate_participant_handler(ctx, reset_signature)
+    }
+
+    #[access_control(SetFee::prevalidate(&ctx))]
+    pub fn set_fee(ctx: Context<SetFee>, document_fee: u64, treasury: Pubkey) -> Result<()> {
+        instructions::set_fee_handler(ctx, document_fee, treasury)
+    }
+
+    #[access_control(SetPaused::prevalidate(&ctx))]
+    pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+        instructions::set_paused_handler(ctx, paused)
+    }
+
+    #[access_control(SubmitEthSignature::prevalidate(&ctx, &eth_address, section))]
+    pub fn submit_eth_signature(
+        ctx: Context<SubmitEthSignature>,
+        eth_address: [u8; 20],
+        section: u8,
+    ) -> Result<()> {
+        instructions::submit_eth_signature_handler(ctx, eth_address, section)
+    }
+
+    #[access_control(UpdateConfig::prevalidate(&ctx, &limits))]
+    pub fn update_config(
+        ctx: Context<UpdateConfig>,
+        limits: ConfigLimits,
+        new_admin: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::update_config_handler(ctx, limits, new_admin)
+    }
+
+    #[access_control(UpdateDocument::prevalidate(&ctx, &uri, &participants, &settings))]
+    pub fn update_document(
+        ctx: Context<UpdateDocument>,
+        uri: Option<String>,
+        participants: Option<Vec<Pubkey>>,
+        settings: Option<DocumentSettings>,
+    ) -> Result<()> {
+        instructions::update_document_handler(ctx, uri, participants, settings)
+    }
+
     #[access_control(Upgrade::prevalidate(&ctx, increase_amount))]
     pub fn upgrade(ctx: Context<Upgrade>, increase_amount: u8) -> Result<()> {
         instructions::upgrade(ctx, increase_amount)
@@ -0,0 +1,147 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+/// The domain separation prefix for hashing Merkle tree leaves.
+const LEAF_PREFIX: &[u8] = &[0];
+
+/// The domain separation prefix for hashing Merkle tree nodes.
+const NODE_PREFIX: &[u8] = &[1];
+
+/// Returns the Merkle leaf hash of the participant at the argued index of the set.
+pub fn leaf(index: u32, participant: &Pubkey) -> [u8; 32] {
+    hashv(&[LEAF_PREFIX, &index.to_le_bytes(), participant.as_ref()]).to_bytes()
+}
+
+/// Returns the parent hash of two sibling nodes, which are sorted so
+/// that proofs do not need to encode the position of each sibling.
+fn node(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[NODE_PREFIX, left, right]).to_bytes()
+}
+
+/// Verify that the argued leaf hash is included in the tree with the `root`
+/// by hashing it with each of the sibling nodes in the proof `path`.
+pub fn verify(root: &[u8; 32], leaf: [u8; 32], path: &[[u8; 32]]) -> bool {
+    path.iter().fold(leaf, |acc, sibling| node(&acc, sibling)) == *root
+}
+
+/// Returns the root hash of the tree of the argued participants in their listed order,
+/// computed in place without retaining the layers that `MerkleTree` keeps for proofs.
+pub fn root(participants: &[Pubkey]) -> [u8; 32] {
+    let mut layer = participants
+        .iter()
+        .enumerate()
+        .map(|(i, p)| leaf(i as u32, p))
+        .collect::<Vec<[u8; 32]>>();
+
+    while layer.len() > 1 {
+        let len = layer.len();
+        for i in (0..len).step_by(2) {
+            layer[i / 2] = match layer.get(i + 1) {
+                Some(b) => node(&layer[i], b),
+                None => layer[i],
+            };
+        }
+        layer.truncate((len + 1) / 2);
+    }
+
+    layer.first().copied().unwrap_or_default()
+}
+
+/// A Merkle tree of a participant set used to derive its root and the
+/// inclusion proofs that are handed to each participant for signing.
+#[cfg(any(test, feature = "cli"))]
+pub struct MerkleTree {
+    layers: Vec<Vec<[u8; 32]>>,
+}
+
+#[cfg(any(test, feature = "cli"))]
+impl MerkleTree {
+    /// Builds the tree of the argued participants in their listed order, where
+    /// a node without a sibling is promoted to the next layer unchanged.
+    pub fn new(participants: &[Pubkey]) -> Self {
+        let mut layers = vec![participants
+            .iter()
+            .enumerate()
+            .map(|(i, p)| leaf(i as u32, p))
+            .collect::<Vec<[u8; 32]>>()];
+
+        while layers.last().unwrap().len() > 1 {
+            let next = layers
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => node(a, b),
+                    [a] => *a,
+                    _ => unreachable!(),
+                })
+                .collect();
+
+            layers.push(next);
+        }
+
+        Self { layers }
+    }
+
+    /// Returns the root hash of the tree.
+    pub fn root(&self) -> [u8; 32] {
+        self.layers
+            .last()
+            .and_then(|l| l.first())
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Returns the sibling path proving the inclusion of the leaf at the argued index.
+    pub fn proof(&self, index: usize) -> Vec<[u8; 32]> {
+        let mut path = Vec::new();
+        let mut i = index;
+
+        for layer in &self.layers[..self.layers.len() - 1] {
+            if let Some(sibling) = layer.get(i ^ 1) {
+                path.push(*sibling);
+            }
+            i /= 2;
+        }
+
+        path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn proofs_verify_against_root() {
+        let participants = (0..7).map(|_| Pubkey::new_unique()).collect::<Vec<_>>();
+        let tree = MerkleTree::new(&participants);
+
+        for (i, p) in participants.iter().enumerate() {
+            let proof = tree.proof(i);
+            assert!(verify(&tree.root(), leaf(i as u32, p), &proof));
+            assert!(!verify(&tree.root(), leaf(i as u32 + 1, p), &proof));
+        }
+
+        let outsider = Pubkey::new_unique();
+        assert!(!verify(&tree.root(), leaf(0, &outsider), &tree.proof(0)));
+    }
+
+    #[test]
+    fn root_matches_tree_root() {
+        for n in 0..9 {
+            let participants = (0..n).map(|_| Pubkey::new_unique()).collect::<Vec<_>>();
+            assert_eq!(root(&participants), MerkleTree::new(&participants).root());
+        }
+    }
+
+    #[test]
+    fn single_participant_tree() {
+        let participant = Pubkey::new_unique();
+        let tree = MerkleTree::new(&[participant]);
+
+        assert_eq!(tree.root(), leaf(0, &participant));
+        assert!(tree.proof(0).is_empty());
+    }
+}
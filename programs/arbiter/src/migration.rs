@@ -0,0 +1,409 @@
+use anchor_lang::error::ErrorCode as AnchorErrorCode;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+use anchor_lang::Discriminator;
+
+use crate::seeds;
+use crate::state::{Clerk, Document, DocumentSettings};
+
+/// The version given to accounts read from the layout that predates versioning,
+/// which were stored without a version field and must be migrated before use.
+pub const LEGACY_VERSION: u8 = 0;
+
+/// The `Clerk` account layout from before the layout was versioned.
+#[derive(AnchorDeserialize)]
+pub struct ClerkV0 {
+    pub authority: Pubkey,
+    pub documents: Vec<Pubkey>,
+    pub upgrades: u8,
+    pub bump: [u8; 1],
+}
+
+impl From<ClerkV0> for Clerk {
+    fn from(v0: ClerkV0) -> Self {
+        Self {
+            version: LEGACY_VERSION,
+            authority: v0.authority,
+            documents: v0.documents,
+            upgrades: v0.upgrades,
+            bump: v0.bump,
+        }
+    }
+}
+
+/// The `Document` account layout from before the layout was versioned, which
+/// had a single implicit section and one signature slot for each participant.
+#[derive(AnchorDeserialize)]
+pub struct DocumentV0 {
+    pub authority: Pubkey,
+    pub title: String,
+    pub uri: String,
+    pub created_at: u64,
+    pub participants: Vec<Pubkey>,
+    pub signature_timestamps: Vec<u64>,
+    pub finalization_timestamp: u64,
+    pub bump: [u8; 1],
+}
+
+impl From<DocumentV0> for Document {
+    fn from(v0: DocumentV0) -> Self {
+        let num_participants = v0.participants.len();
+
+        // Legacy signatures could only be submitted by the participants themselves.
+        let signers = v0
+            .participants
+            .iter()
+            .zip(&v0.signature_timestamps)
+            .map(|(&p, &t)| if t != 0 { p } else { Pubkey::default() })
+            .collect();
+
+        Self {
+            version: LEGACY_VERSION,
+            authority: v0.authority,
+            title: v0.title,
+            uri: v0.uri,
+            attachments: vec![],
+            created_at: v0.created_at,
+            settings: DocumentSettings::default(),
+            sections: vec![],
+            prerequisites: vec![],
+            bundle: None,
+            participants: v0.participants,
+            eth_participants: vec![],
+            signature_timestamps: v0.signature_timestamps,
+            deadlines: vec![0; num_participants],
+            weights: vec![1; num_participants],
+            groups: vec![],
+            signers,
+            memos: vec![[0; Document::MEMO_SIZE]; num_participants],
+            signed_bitmap: vec![],
+            acceptance_timestamps: vec![0; num_participants],
+            receipts_claimed: 0,
+            finalization_timestamp: v0.finalization_timestamp,
+            bump: v0.bump,
+        }
+    }
+}
+
+/// Returns the data length that a legacy account of the argued length is grown to on
+/// its way to the `new_size` of the current layout, which is limited by the data
+/// increase permitted in a single instruction so larger accounts grow over calls.
+pub fn next_data_len(data_len: usize, new_size: usize) -> usize {
+    new_size.min(data_len + MAX_PERMITTED_DATA_INCREASE)
+}
+
+/// Deserializes the `Clerk` account data at the argued address in either layout,
+/// where data in the legacy layout is given the `LEGACY_VERSION`.
+///
+/// A legacy account can begin with any byte of its authority, so a layout is only
+/// accepted when its authority and bump derive the address of the account.
+pub fn try_deserialize_clerk(address: &Pubkey, data: &[u8]) -> Result<Clerk> {
+    let derives = |clerk: &Clerk| {
+        Pubkey::create_program_address(
+            &[seeds::CLERK, clerk.authority.as_ref(), &clerk.bump],
+            &crate::ID,
+        )
+        .ok()
+            == Some(*address)
+    };
+
+    if let Ok(clerk) = Clerk::try_deserialize(&mut &data[..]) {
+        if clerk.version == Clerk::VERSION && derives(&clerk) {
+            return Ok(clerk);
+        }
+    }
+
+    let clerk: Clerk = try_deserialize_legacy::<Clerk, ClerkV0>(data)?.into();
+    require!(derives(&clerk), AnchorErrorCode::AccountDidNotDeserialize);
+    Ok(clerk)
+}
+
+/// Deserializes the `Document` account data at the argued address in either layout,
+/// where data in the legacy layout is given the `LEGACY_VERSION`.
+pub fn try_deserialize_document(address: &Pubkey, data: &[u8]) -> Result<Document> {
+    let derives = |doc: &Document| {
+        Pubkey::create_program_address(
+            &[
+                seeds::DOCUMENT,
+                doc.authority.as_ref(),
+                Document::title_seed(&doc.title),
+                &doc.bump,
+            ],
+            &crate::ID,
+        )
+        .ok()
+            == Some(*address)
+    };
+
+    if let Ok(doc) = Document::try_deserialize(&mut &data[..]) {
+        if doc.version == Document::VERSION && derives(&doc) {
+            return Ok(doc);
+        }
+    }
+
+    let doc: Document = try_deserialize_legacy::<Document, DocumentV0>(data)?.into();
+    require!(derives(&doc), AnchorErrorCode::AccountDidNotDeserialize);
+    Ok(doc)
+}
+
+/// Deserializes the legacy layout `V` of the account data that
+/// carries the unchanged discriminator of the account type `T`.
+fn try_deserialize_legacy<T: Discriminator, V: AnchorDeserialize>(data: &[u8]) -> Result<V> {
+    require!(
+        data.len() >= 8 && data[..8] == T::discriminator(),
+        AnchorErrorCode::AccountDiscriminatorMismatch,
+    );
+    V::deserialize(&mut &data[8..]).map_err(|_| AnchorErrorCode::AccountDidNotDeserialize.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::error_code_number;
+    use crate::state::ParticipantSpace;
+
+    /// Appends the borsh encoding of a vector length prefix.
+    fn push_len(data: &mut Vec<u8>, len: u32) {
+        data.extend_from_slice(&len.to_le_bytes());
+    }
+
+    /// Builds the legacy `Clerk` account data by hand, which fills its account exactly.
+    fn clerk_v0(authority: &Pubkey, bump: u8, documents: &[Pubkey]) -> Vec<u8> {
+        let mut data = Clerk::discriminator().to_vec();
+        data.extend_from_slice(authority.as_ref());
+        push_len(&mut data, documents.len() as u32);
+        for d in documents {
+            data.extend_from_slice(d.as_ref());
+        }
+        data.push(2);
+        data.push(bump);
+        data
+    }
+
+    /// Builds the legacy `Document` account data by hand in the order of the fields
+    /// of `DocumentV0`, where only the first of the argued participants has signed.
+    fn document_v0(
+        authority: &Pubkey,
+        title: &str,
+        bump: u8,
+        participants: &[Pubkey],
+        finalization_timestamp: u64,
+    ) -> Vec<u8> {
+        let mut data = Document::discriminator().to_vec();
+        data.extend_from_slice(authority.as_ref());
+        for s in [title, "https://arweave.net/legacy"] {
+            push_len(&mut data, s.len() as u32);
+            data.extend_from_slice(s.as_bytes());
+        }
+        data.extend_from_slice(&100u64.to_le_bytes());
+        push_len(&mut data, participants.len() as u32);
+        for p in participants {
+            data.extend_from_slice(p.as_ref());
+        }
+        push_len(&mut data, participants.len() as u32);
+        for i in 0..participants.len() {
+            let timestamp = if i == 0 { 150u64 } else { 0 };
+            data.extend_from_slice(&timestamp.to_le_bytes());
+        }
+        data.extend_from_slice(&finalization_timestamp.to_le_bytes());
+        data.push(bump);
+        data
+    }
+
+    #[test]
+    fn clerk_v0_is_migrated() {
+        let authority = Pubkey::new_unique();
+        let (address, bump) =
+            Pubkey::find_program_address(&[seeds::CLERK, authority.as_ref()], &crate::ID);
+        let documents = [Pubkey::new_unique(), Pubkey::default()];
+
+        let data = clerk_v0(&authority, bump, &documents);
+        let mut clerk = try_deserialize_clerk(&address, &data).unwrap();
+
+        assert_eq!(clerk.version, LEGACY_VERSION);
+        assert_eq!(clerk.authority, authority);
+        assert_eq!(clerk.documents, documents);
+        assert_eq!(clerk.upgrades, 2);
+        assert_eq!(clerk.bump, [bump]);
+
+        clerk.version = Clerk::VERSION;
+        let mut migrated = vec![0; data.len() + 1];
+        clerk.try_serialize(&mut &mut migrated[..]).unwrap();
+        assert_eq!(migrated.len(), Clerk::space(documents.len()));
+
+        let clerk = try_deserialize_clerk(&address, &migrated).unwrap();
+        assert_eq!(clerk.version, Clerk::VERSION);
+        assert_eq!(clerk.authority, authority);
+        assert_eq!(clerk.documents, documents);
+    }
+
+    #[test]
+    fn document_v0_is_migrated() {
+        let authority = Pubkey::new_unique();
+        let participant = Pubkey::new_unique();
+        let title = "Legacy Agreement";
+        let (address, bump) = Pubkey::find_program_address(
+            &[
+                seeds::DOCUMENT,
+                authority.as_ref(),
+                Document::title_seed(title),
+            ],
+            &crate::ID,
+        );
+
+        let data = document_v0(&authority, title, bump, &[participant], 200);
+        let mut doc = try_deserialize_document(&address, &data).unwrap();
+
+        assert_eq!(doc.version, LEGACY_VERSION);
+        assert_eq!(doc.authority, authority);
+        assert_eq!(doc.title, title);
+        assert_eq!(doc.uri, "https://arweave.net/legacy");
+        assert_eq!(doc.created_at, 100);
+        assert_eq!(doc.participants, vec![participant]);
+        assert_eq!(doc.signature_timestamps, vec![150]);
+        assert_eq!(doc.deadlines, vec![0]);
+        assert_eq!(doc.weights, vec![1]);
+        assert_eq!(doc.signers, vec![participant]);
+        assert_eq!(doc.memos, vec![[0; 32]]);
+        assert_eq!(doc.acceptance_timestamps, vec![0]);
+        assert_eq!(doc.finalization_timestamp, 200);
+        assert_eq!(doc.bump, [bump]);
+        assert!(doc.is_finalized());
+
+        doc.version = Document::VERSION;
+        let mut migrated = vec![
+            0;
+            Document::space(
+                title.len(),
+                doc.uri.len(),
+                &[],
+                &ParticipantSpace {
+                    participants: 1,
+                    eth_participants: 0,
+                    merkle_participants: 0,
+                    groups: &[],
+                },
+                &[],
+                0,
+            )
+        ];
+        doc.try_serialize(&mut &mut migrated[..]).unwrap();
+
+        let migrated = try_deserialize_document(&address, &migrated).unwrap();
+        assert_eq!(migrated.version, Document::VERSION);
+        assert_eq!(migrated.title, title);
+        assert_eq!(migrated.participants, vec![participant]);
+        assert_eq!(migrated.signers, vec![participant]);
+        assert_eq!(migrated.finalization_timestamp, 200);
+    }
+
+    #[test]
+    fn document_v0_slots_are_sized_to_the_participants() {
+        let authority = Pubkey::new_unique();
+        let participants = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let title = "Legacy Draft";
+        let (address, bump) = Pubkey::find_program_address(
+            &[
+                seeds::DOCUMENT,
+                authority.as_ref(),
+                Document::title_seed(title),
+            ],
+            &crate::ID,
+        );
+
+        let data = document_v0(&authority, title, bump, &participants, 0);
+        assert_eq!(
+            data.len(),
+            8 + 32 + 4 + title.len() + 4 + 26 + 8 + 4 + 64 + 4 + 16 + 8 + 1
+        );
+
+        let doc = try_deserialize_document(&address, &data).unwrap();
+        assert_eq!(doc.signature_timestamps, vec![150, 0]);
+        assert_eq!(doc.signers, vec![participants[0], Pubkey::default()]);
+        assert_eq!(doc.deadlines.len(), 2);
+        assert_eq!(doc.weights, vec![1, 1]);
+        assert_eq!(doc.memos.len(), 2);
+        assert_eq!(doc.acceptance_timestamps.len(), 2);
+        assert!(!doc.has_all_signatures());
+        assert!(!doc.is_finalized());
+    }
+
+    #[test]
+    fn large_document_v0_grows_across_calls() {
+        let authority = Pubkey::new_unique();
+        let participants = (0..200).map(|_| Pubkey::new_unique()).collect::<Vec<_>>();
+        let title = "Legacy Register";
+        let (address, bump) = Pubkey::find_program_address(
+            &[
+                seeds::DOCUMENT,
+                authority.as_ref(),
+                Document::title_seed(title),
+            ],
+            &crate::ID,
+        );
+
+        let mut data = document_v0(&authority, title, bump, &participants, 0);
+        let new_size = Document::space(
+            title.len(),
+            "https://arweave.net/legacy".len(),
+            &[],
+            &ParticipantSpace {
+                participants: participants.len(),
+                eth_participants: 0,
+                merkle_participants: 0,
+                groups: &[],
+            },
+            &[],
+            0,
+        );
+        assert!(new_size - data.len() > MAX_PERMITTED_DATA_INCREASE);
+
+        let mut calls = 0;
+        while data.len() < new_size {
+            data.resize(next_data_len(data.len(), new_size), 0);
+            calls += 1;
+
+            let doc = try_deserialize_document(&address, &data).unwrap();
+            assert_eq!(doc.version, LEGACY_VERSION);
+            assert_eq!(doc.participants, participants);
+        }
+        assert_eq!(calls, 2);
+
+        let mut doc = try_deserialize_document(&address, &data).unwrap();
+        doc.version = Document::VERSION;
+        doc.try_serialize(&mut &mut data[..]).unwrap();
+
+        let migrated = try_deserialize_document(&address, &data).unwrap();
+        assert_eq!(migrated.version, Document::VERSION);
+        assert_eq!(migrated.weights, vec![1; participants.len()]);
+    }
+
+    #[test]
+    fn legacy_data_requires_the_account_discriminator() {
+        let authority = Pubkey::new_unique();
+        let (address, bump) =
+            Pubkey::find_program_address(&[seeds::CLERK, authority.as_ref()], &crate::ID);
+
+        let mut data = clerk_v0(&authority, bump, &[]);
+        data[..8].copy_from_slice(&Document::discriminator());
+
+        assert_eq!(
+            error_code_number(try_deserialize_clerk(&address, &data).unwrap_err()),
+            u32::from(AnchorErrorCode::AccountDiscriminatorMismatch),
+        );
+    }
+
+    #[test]
+    fn legacy_data_must_derive_the_account_address() {
+        let authority = Pubkey::new_unique();
+        let (_, bump) =
+            Pubkey::find_program_address(&[seeds::CLERK, authority.as_ref()], &crate::ID);
+
+        let data = clerk_v0(&authority, bump, &[]);
+
+        assert_eq!(
+            error_code_number(try_deserialize_clerk(&Pubkey::new_unique(), &data).unwrap_err()),
+            u32::from(AnchorErrorCode::AccountDidNotDeserialize),
+        );
+    }
+}
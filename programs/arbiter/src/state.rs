@@ -1,13 +1,87 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
 #[cfg(any(test, feature = "cli"))]
 use serde::ser::{Serialize, SerializeStruct, Serializer};
 
 use crate::error::ErrorCode;
-use crate::seeds;
+use crate::{merkle, seeds};
+
+#[account]
+#[cfg_attr(any(test, feature = "cli"), derive(Debug))]
+pub struct Bundle {
+    /// The wallet public key authority of the `clerk` and bundled documents.
+    pub authority: Pubkey,
+
+    /// The `Clerk` program account that holds every bundled document.
+    pub clerk: Pubkey,
+
+    /// The name of the bundle used to derive its address.
+    pub name: String,
+
+    /// The `Document` public keys that must all be finalized together.
+    pub documents: Vec<Pubkey>,
+
+    /// The unix timestamp of when the bundle and its documents were finalized.
+    pub finalization_timestamp: u64,
+
+    /// The program account bump nonce.
+    pub bump: [u8; 1],
+}
+
+impl Bundle {
+    /// Returns the byte size of the `Bundle` struct given the
+    /// length of its name and the number of bundled documents.
+    pub fn space(name_size: usize, doc_size: usize) -> usize {
+        8 + 32 + 32 + (4 + name_size) + (4 + 32 * doc_size) + 8 + 1
+    }
+
+    /// Convert a full bundle name string into a usable address seed.
+    pub fn name_seed(name: &str) -> &[u8] {
+        Document::title_seed(name)
+    }
+
+    /// Whether the bundle and its documents have been finalized.
+    pub fn is_finalized(&self) -> bool {
+        self.finalization_timestamp != 0
+    }
+
+    /// Try to set the timestamp of the bundle finalization in the account data.
+    pub fn try_finalize(&mut self) -> Result<()> {
+        self.finalization_timestamp = Clock::get()?.unix_timestamp as u64;
+        Ok(())
+    }
+}
+
+#[cfg(any(test, feature = "cli"))]
+impl Serialize for Bundle {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut s = serializer.serialize_struct("Bundle", 5)?;
+        s.serialize_field("authority", &self.authority.to_string())?;
+        s.serialize_field("clerk", &self.clerk.to_string())?;
+        s.serialize_field("name", &self.name)?;
+        s.serialize_field(
+            "documents",
+            &self
+                .documents
+                .iter()
+                .map(|d| d.to_string())
+                .collect::<Vec<String>>(),
+        )?;
+        s.serialize_field("finalizationTimestamp", &self.finalization_timestamp)?;
+        s.end()
+    }
+}
 
 #[account]
 #[cfg_attr(any(test, feature = "cli"), derive(Debug))]
 pub struct Clerk {
+    /// The layout version of the account data, which is `Clerk::VERSION`
+    /// unless the account was created before the layout was versioned.
+    pub version: u8,
+
     /// The wallet public key authority behind the program account.
     pub authority: Pubkey,
 
@@ -22,10 +96,13 @@ pub struct Clerk {
 }
 
 impl Clerk {
+    /// The current layout version of `Clerk` account data.
+    pub const VERSION: u8 = 1;
+
     /// Returns the byte size of the `Clerk` struct given the number
     /// of allowed `Document` public keys to be stored.
     pub fn space(size: usize) -> usize {
-        8 + 32 + (4 + 32 * size) + 1 + 1
+        8 + 1 + 32 + (4 + 32 * size) + 1 + 1
     }
 
     /// Whether the documents public key vector is storing the maximum
@@ -62,6 +139,14 @@ impl Clerk {
         self.documents[i] = document;
         Ok(())
     }
+
+    /// Replaces the argued `Document` public key in the documents
+    /// struct vector with `Pubkey::default()` to free its slot.
+    pub fn release(&mut self, document: &Pubkey) {
+        if let Some(d) = self.documents.iter_mut().find(|d| *d == document) {
+            *d = Pubkey::default();
+        }
+    }
 }
 
 #[cfg(any(test, feature = "cli"))]
@@ -70,7 +155,8 @@ impl Serialize for Clerk {
     where
         S: Serializer,
     {
-        let mut s = serializer.serialize_struct("Clerk", 3)?;
+        let mut s = serializer.serialize_struct("Clerk", 4)?;
+        s.serialize_field("version", &self.version)?;
         s.serialize_field("authority", &self.authority.to_string())?;
         s.serialize_field(
             "documents",
@@ -85,9 +171,357 @@ impl Serialize for Clerk {
     }
 }
 
+#[account]
+#[cfg_attr(any(test, feature = "cli"), derive(Debug))]
+pub struct Config {
+    /// The wallet public key permitted to update the program configuration.
+    pub admin: Pubkey,
+
+    /// The limits enforced when creating and upgrading program accounts.
+    pub limits: ConfigLimits,
+
+    /// Whether the admin has paused every mutating instruction of the program.
+    pub paused: bool,
+
+    /// The wallet public key that receives the document creation fees.
+    pub treasury: Pubkey,
+
+    /// The lamports charged to the payer of each new document,
+    /// unless its clerk has been granted a `FeeWaiver`.
+    pub document_fee: u64,
+
+    /// The program account bump nonce.
+    pub bump: [u8; 1],
+}
+
+impl Config {
+    /// The byte size of the `Config` struct.
+    pub const SPACE: usize = 8 + 32 + ConfigLimits::SPACE + 1 + 32 + 8 + 1;
+}
+
+#[cfg(any(test, feature = "cli"))]
+impl Serialize for Config {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut s = serializer.serialize_struct("Config", 5)?;
+        s.serialize_field("admin", &self.admin.to_string())?;
+        s.serialize_field("limits", &self.limits)?;
+        s.serialize_field("paused", &self.paused)?;
+        s.serialize_field("treasury", &self.treasury.to_string())?;
+        s.serialize_field("documentFee", &self.document_fee)?;
+        s.end()
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+#[cfg_attr(any(test, feature = "cli"), derive(Debug))]
+pub struct ConfigLimits {
+    /// The maximum byte length of a document title.
+    pub max_title_length: u16,
+
+    /// The maximum byte length of a document URI.
+    pub max_uri_length: u16,
+
+    /// The maximum number of participants and Ethereum participants
+    /// that can be stored on a single document.
+    pub max_participants: u16,
+
+    /// The maximum document limit that a clerk can be initialized or upgraded to.
+    pub max_clerk_limit: u8,
+}
+
+impl ConfigLimits {
+    /// The byte size of the `ConfigLimits` struct.
+    pub const SPACE: usize = 2 + 2 + 2 + 1;
+
+    /// Whether every limit permits at least one of what it restricts.
+    pub fn is_valid(&self) -> bool {
+        self.max_title_length > 0
+            && self.max_uri_length > 0
+            && self.max_participants > 0
+            && self.max_clerk_limit > 0
+    }
+}
+
+#[cfg(any(test, feature = "cli"))]
+impl Serialize for ConfigLimits {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut s = serializer.serialize_struct("ConfigLimits", 4)?;
+        s.serialize_field("maxTitleLength", &self.max_title_length)?;
+        s.serialize_field("maxUriLength", &self.max_uri_length)?;
+        s.serialize_field("maxParticipants", &self.max_participants)?;
+        s.serialize_field("maxClerkLimit", &self.max_clerk_limit)?;
+        s.end()
+    }
+}
+
+#[account]
+#[cfg_attr(any(test, feature = "cli"), derive(Debug))]
+pub struct Delegation {
+    /// The participant public key that granted the signing authority.
+    pub participant: Pubkey,
+
+    /// The public key that is authorized to sign on behalf of the `participant`.
+    pub delegate: Pubkey,
+
+    /// The optional `Document` public key that the delegation is restricted to.
+    pub document: Option<Pubkey>,
+
+    /// The optional unix timestamp after which the delegation is no longer valid.
+    pub expires_at: Option<u64>,
+
+    /// The program account bump nonce.
+    pub bump: [u8; 1],
+}
+
+impl Delegation {
+    /// The byte size of the `Delegation` struct.
+    pub const SPACE: usize = 8 + 32 + 32 + (1 + 32) + (1 + 8) + 1;
+
+    /// Whether the delegation has an expiration that is before the argued timestamp.
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.expires_at.map(|e| e <= now).unwrap_or(false)
+    }
+
+    /// Verify that the delegation authorizes the argued `delegate` to sign
+    /// the `document` on behalf of the `participant` at the current time.
+    pub fn try_authorize(
+        &self,
+        participant: &Pubkey,
+        delegate: &Pubkey,
+        document: &Pubkey,
+    ) -> Result<()> {
+        require!(
+            self.participant == *participant && self.delegate == *delegate,
+            ErrorCode::DelegationAuthorityMisMatch,
+        );
+
+        if let Some(d) = self.document {
+            require!(d == *document, ErrorCode::DelegationDocumentMisMatch);
+        }
+
+        require!(
+            !self.is_expired(Clock::get()?.unix_timestamp as u64),
+            ErrorCode::DelegationExpired,
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(any(test, feature = "cli"))]
+impl Serialize for Delegation {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut s = serializer.serialize_struct("Delegation", 4)?;
+        s.serialize_field("participant", &self.participant.to_string())?;
+        s.serialize_field("delegate", &self.delegate.to_string())?;
+        s.serialize_field("document", &self.document.map(|d| d.to_string()))?;
+        s.serialize_field("expiresAt", &self.expires_at)?;
+        s.end()
+    }
+}
+
+/// A supplementary file of a document, such as a storage mirror or an exhibit.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[cfg_attr(any(test, feature = "cli"), derive(Debug))]
+pub struct Attachment {
+    /// The unique name of the attachment within the document.
+    pub label: String,
+
+    /// The content address URI of the attachment.
+    pub uri: String,
+
+    /// The SHA-256 hash of the attachment content.
+    pub content_hash: [u8; 32],
+}
+
+impl Attachment {
+    /// The maximum byte length of an attachment label.
+    pub const MAX_LABEL_LENGTH: usize = 32;
+
+    /// Returns the byte size of an `Attachment` with the argued label and URI lengths.
+    pub fn space(label_size: usize, uri_size: usize) -> usize {
+        (4 + label_size) + (4 + uri_size) + 32
+    }
+}
+
+#[cfg(any(test, feature = "cli"))]
+impl Serialize for Attachment {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut s = serializer.serialize_struct("Attachment", 3)?;
+        s.serialize_field("label", &self.label)?;
+        s.serialize_field("uri", &self.uri)?;
+        s.serialize_field(
+            "contentHash",
+            &self
+                .content_hash
+                .iter()
+                .map(|b| format!("{:02x}", b))
+                .collect::<String>(),
+        )?;
+        s.end()
+    }
+}
+
+/// A named group of listed participants that is satisfied once
+/// a threshold of its members have signed every section.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[cfg_attr(any(test, feature = "cli"), derive(Debug))]
+pub struct SignerGroup {
+    /// The unique name of the group within the document.
+    pub name: String,
+
+    /// The number of members that must sign for the group quorum to be met.
+    pub threshold: u8,
+
+    /// The indices of the document `participants` that are members of the group.
+    pub members: Vec<u8>,
+}
+
+impl SignerGroup {
+    /// The maximum byte length of a signer group name.
+    pub const MAX_NAME_LENGTH: usize = 32;
+
+    /// Returns the byte size of a `SignerGroup` with the argued name length and member count.
+    pub fn space(name_size: usize, member_size: usize) -> usize {
+        (4 + name_size) + 1 + (4 + member_size)
+    }
+}
+
+#[cfg(any(test, feature = "cli"))]
+impl Serialize for SignerGroup {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut s = serializer.serialize_struct("SignerGroup", 3)?;
+        s.serialize_field("name", &self.name)?;
+        s.serialize_field("threshold", &self.threshold)?;
+        s.serialize_field("members", &self.members)?;
+        s.end()
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+#[cfg_attr(any(test, feature = "cli"), derive(Debug))]
+pub struct DocumentSettings {
+    /// Whether participants must accept their invitation before signing.
+    pub requires_acceptance: bool,
+
+    /// The optional Merkle root of a participant set that is too large to be
+    /// stored, where each member proves their inclusion when signing.
+    pub participant_root: Option<[u8; 32]>,
+
+    /// The number of participants committed to by the `participant_root`.
+    pub participant_count: u32,
+
+    /// Whether the document is still a draft, in which its URI, participants and
+    /// settings can be updated and signatures are rejected until it is opened.
+    pub draft: bool,
+
+    /// The summed weight of the participants that must sign every section for
+    /// the document to be finalized, where zero requires every participant.
+    pub required_weight: u64,
+
+    /// The optional effective date as a unix timestamp before which
+    /// the document cannot be finalized, even if it is fully signed.
+    pub not_before: Option<u64>,
+}
+
+impl DocumentSettings {
+    /// The byte size of the `DocumentSettings` struct.
+    pub const SPACE: usize = 1 + (1 + 32) + 4 + 1 + 8 + (1 + 8);
+}
+
+#[cfg(any(test, feature = "cli"))]
+impl Serialize for DocumentSettings {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut s = serializer.serialize_struct("DocumentSettings", 6)?;
+        s.serialize_field("requiresAcceptance", &self.requires_acceptance)?;
+        s.serialize_field(
+            "participantRoot",
+            &self
+                .participant_root
+                .map(|r| r.iter().map(|b| format!("{:02x}", b)).collect::<String>()),
+        )?;
+        s.serialize_field("participantCount", &self.participant_count)?;
+        s.serialize_field("draft", &self.draft)?;
+        s.serialize_field("requiredWeight", &self.required_weight)?;
+        s.serialize_field("notBefore", &self.not_before)?;
+        s.end()
+    }
+}
+
+/// The participants of a new document and the optional signing terms of each listed
+/// participant, where empty terms give every listed participant no signing deadline,
+/// a weight of one and no signer group.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+#[cfg_attr(any(test, feature = "cli"), derive(Debug))]
+pub struct ParticipantSet {
+    /// The participant public keys that are listed on the document.
+    pub participants: Vec<Pubkey>,
+
+    /// The Ethereum addresses of participants that sign with secp256k1 signatures.
+    pub eth_participants: Vec<[u8; 20]>,
+
+    /// The unix timestamp signing deadline of each listed participant, where zero is none.
+    pub deadlines: Vec<u64>,
+
+    /// The signature weight of each listed participant.
+    pub weights: Vec<u64>,
+
+    /// The named signer groups of listed participants with their own quorums.
+    pub groups: Vec<SignerGroup>,
+}
+
+/// The participant data of a `Document` that its account size depends on.
+pub struct ParticipantSpace<'a> {
+    /// The number of listed participants.
+    pub participants: usize,
+
+    /// The number of Ethereum participants.
+    pub eth_participants: usize,
+
+    /// The number of Merkle committed participants.
+    pub merkle_participants: usize,
+
+    /// The signer groups that listed participants are assigned to.
+    pub groups: &'a [SignerGroup],
+}
+
+/// The proof that a signer is a member of a document's Merkle committed
+/// participant set, given as their leaf index and the sibling hash path.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+#[cfg_attr(any(test, feature = "cli"), derive(Debug))]
+pub struct ParticipantProof {
+    /// The index of the participant in the committed set.
+    pub index: u32,
+
+    /// The sibling node hashes from the participant leaf up to the root.
+    pub path: Vec<[u8; 32]>,
+}
+
 #[account]
 #[cfg_attr(any(test, feature = "cli"), derive(Debug))]
 pub struct Document {
+    /// The layout version of the account data, which is `Document::VERSION`
+    /// unless the account was created before the layout was versioned.
+    pub version: u8,
+
     /// The public key of the wallet that created the document.
     pub authority: Pubkey,
 
@@ -97,15 +531,72 @@ pub struct Document {
     /// The content address URI of the document agnostic to storage platform.
     pub uri: String,
 
+    /// The supplementary files of the document, such as storage mirrors and
+    /// exhibits, which can only be changed before the first signature.
+    pub attachments: Vec<Attachment>,
+
     /// The unix timestamp of when the document was initialized.
     pub created_at: u64,
 
+    /// The configurable behavior of the document set at creation.
+    pub settings: DocumentSettings,
+
+    /// The names of the document sections that each require a separate
+    /// signature from every participant. An empty list represents a
+    /// single implicit section covering the entire document.
+    pub sections: Vec<String>,
+
+    /// The `Document` public keys that must be finalized before this document
+    /// is permitted to be finalized.
+    pub prerequisites: Vec<Pubkey>,
+
+    /// The `Bundle` that the document belongs to, which must finalize it
+    /// along with the rest of its documents.
+    pub bundle: Option<Pubkey>,
+
     /// The public keys that are required to sign and send approval transactions.
     pub participants: Vec<Pubkey>,
 
-    /// Vector of boolean flags to indicate which public key participants have signed.
+    /// The Ethereum addresses that are required to sign through an EIP-191 personal
+    /// signature, whose signature slots are ordered after those of `participants`.
+    pub eth_participants: Vec<[u8; 20]>,
+
+    /// The signature timestamps for each (participant, section) pair, ordered
+    /// by participant and then by section within each participant.
     pub signature_timestamps: Vec<u64>,
 
+    /// The unix timestamp after which each of the `participants` can no longer
+    /// sign the document, where zero indicates that they have no deadline.
+    pub deadlines: Vec<u64>,
+
+    /// The voting weight of each of the `participants` counted towards
+    /// the `settings.required_weight` of a weighted document.
+    pub weights: Vec<u64>,
+
+    /// The named groups of `participants` that must each meet their own quorum,
+    /// where participants outside of any group are individually required to sign.
+    pub groups: Vec<SignerGroup>,
+
+    /// The public keys that submitted each (participant, section) signature, which
+    /// is either the participant themselves or one of their delegates.
+    pub signers: Vec<Pubkey>,
+
+    /// The optional short memo or memo hash attached to each (participant, section)
+    /// signature, where a zeroed array indicates that no memo was provided.
+    pub memos: Vec<[u8; 32]>,
+
+    /// The bitmap of signed (participant, section) pairs for a Merkle committed
+    /// participant set, ordered the same way as `signature_timestamps`.
+    pub signed_bitmap: Vec<u8>,
+
+    /// The unix timestamps of when each participant accepted their invitation
+    /// to the document, only enforced when `settings.requires_acceptance` is set.
+    pub acceptance_timestamps: Vec<u64>,
+
+    /// The number of listed participants that have claimed their receipt,
+    /// all of which must be claimed before the document can be compressed.
+    pub receipts_claimed: u32,
+
     /// Whether all public key participants have signed the document.
     pub finalization_timestamp: u64,
 
@@ -114,19 +605,120 @@ pub struct Document {
 }
 
 impl Document {
+    /// The current layout version of `Document` account data.
+    pub const VERSION: u8 = 1;
+
+    /// The byte size of a signature memo stored for each participant.
+    pub const MEMO_SIZE: usize = 32;
+
+    /// The byte size of an Ethereum address participant.
+    pub const ETH_ADDRESS_SIZE: usize = 20;
+
+    /// The maximum number of attachments that can be stored on a document.
+    pub const MAX_ATTACHMENTS: usize = 8;
+
+    /// The maximum number of signer groups that can be set on a document.
+    pub const MAX_GROUPS: usize = 8;
+
     /// Returns the byte size of the `Document` struct given the number of
-    /// participants required to submit signed approval transactions.
-    pub fn space(title_size: usize, uri_size: usize, part_size: usize) -> usize {
-        8 + 32
+    /// participants, Ethereum participants and Merkle committed participants
+    /// required to submit signatures, the groups they are assigned to, the
+    /// named sections that they are each required to sign and the number
+    /// of prerequisite documents.
+    pub fn space(
+        title_size: usize,
+        uri_size: usize,
+        attachments: &[Attachment],
+        participants: &ParticipantSpace,
+        sections: &[String],
+        prereq_size: usize,
+    ) -> usize {
+        let part_size = participants.participants;
+        let slots = (part_size + participants.eth_participants) * sections.len().max(1);
+
+        8 + 1
+            + 32
             + (4 + title_size)
             + (4 + uri_size)
+            + (4 + attachments
+                .iter()
+                .map(|a| Attachment::space(a.label.len(), a.uri.len()))
+                .sum::<usize>())
             + 8
+            + DocumentSettings::SPACE
+            + (4 + sections.iter().map(|s| 4 + s.len()).sum::<usize>())
+            + (4 + 32 * prereq_size)
+            + (1 + 32)
             + (4 + 32 * part_size)
+            + (4 + Self::ETH_ADDRESS_SIZE * participants.eth_participants)
+            + (4 + 8 * slots)
+            + (4 + 8 * part_size)
             + (4 + 8 * part_size)
+            + (4 + participants
+                .groups
+                .iter()
+                .map(|g| SignerGroup::space(g.name.len(), g.members.len()))
+                .sum::<usize>())
+            + (4 + 32 * slots)
+            + (4 + Self::MEMO_SIZE * slots)
+            + (4 + Self::bitmap_size(participants.merkle_participants, sections.len().max(1)))
+            + (4 + 8 * part_size)
+            + 4
             + 8
             + 1
     }
 
+    /// Returns the byte size of the signed bitmap for the number of Merkle
+    /// committed participants and the sections they are each required to sign.
+    pub fn bitmap_size(merkle_size: usize, section_count: usize) -> usize {
+        (merkle_size * section_count + 7) / 8
+    }
+
+    /// Whether the document participants are committed to by a Merkle root.
+    pub fn is_merkle(&self) -> bool {
+        self.settings.participant_root.is_some()
+    }
+
+    /// Returns the Merkle root committing to the Solana participants of the document,
+    /// which is the `participant_root` of the settings if the participants were
+    /// committed at creation or otherwise the root of the listed participants.
+    pub fn participants_root(&self) -> [u8; 32] {
+        self.settings
+            .participant_root
+            .unwrap_or_else(|| merkle::root(&self.participants))
+    }
+
+    /// Returns the number of sections each participant is required to sign.
+    pub fn section_count(&self) -> usize {
+        self.sections.len().max(1)
+    }
+
+    /// Returns the SHA-256 hash of the document title and URI that
+    /// Ethereum participants commit to with their signatures.
+    pub fn content_hash(&self) -> [u8; 32] {
+        hashv(&[self.title.as_bytes(), self.uri.as_bytes()]).to_bytes()
+    }
+
+    /// Returns the payload that an Ethereum participant signs for the section
+    /// of the document at the argued address, which is the document public key,
+    /// the content hash and the section index.
+    pub fn eth_payload(&self, address: &Pubkey, section: u8) -> Vec<u8> {
+        let mut payload = Vec::with_capacity(65);
+        payload.extend_from_slice(address.as_ref());
+        payload.extend_from_slice(&self.content_hash());
+        payload.push(section);
+        payload
+    }
+
+    /// Returns the EIP-191 personal message of the signing payload, which is
+    /// the exact data that the secp256k1 program must verify.
+    pub fn eth_message(&self, address: &Pubkey, section: u8) -> Vec<u8> {
+        let payload = self.eth_payload(address, section);
+        let mut message = format!("\x19Ethereum Signed Message:\n{}", payload.len()).into_bytes();
+        message.extend(payload);
+        message
+    }
+
     /// Convert a full document title string into a usable address seed.
     pub fn title_seed(title: &str) -> &[u8] {
         let b = title.as_bytes();
@@ -137,18 +729,197 @@ impl Document {
         }
     }
 
-    /// Checks if all required participants have submitted signatures.
+    /// Checks if all required participants have submitted signatures, or for a
+    /// weighted document, if the signed weight meets the required weight. For a
+    /// grouped document, each group only requires its threshold of members.
     pub fn has_all_signatures(&self) -> bool {
+        if self.is_weighted() {
+            return self.signed_weight() >= self.settings.required_weight;
+        }
+
+        if !self.groups.is_empty() {
+            let count = self.section_count();
+            let eth_start = self.participants.len() * count;
+
+            return self
+                .groups
+                .iter()
+                .all(|g| self.group_signatures(g) >= g.threshold as usize)
+                && (0..self.participants.len())
+                    .filter(|&i| self.try_find_group(i).is_none())
+                    .all(|i| self.has_signed_all_sections(i))
+                && self.signature_timestamps[eth_start..]
+                    .iter()
+                    .all(|&t| t > 0);
+        }
+
+        let bits = self.settings.participant_count as usize * self.section_count();
+
         self.signature_timestamps.iter().all(|&t| t > 0)
+            && (0..bits).all(|b| self.signed_bitmap[b / 8] & (1 << (b % 8)) != 0)
     }
 
-    /// Whether the document has all signatures required and has been
-    /// finalized by the creator.
-    pub fn is_finalized(&self) -> bool {
-        self.finalization_timestamp != 0
+    /// Whether the document is finalized by a weighted quorum of participants.
+    pub fn is_weighted(&self) -> bool {
+        self.settings.required_weight > 0
     }
 
-    /// The program account signer seeds for programmatic authority.
+    /// Returns the summed weight of all listed participants.
+    pub fn total_weight(&self) -> u64 {
+        self.weights.iter().sum()
+    }
+
+    /// Returns the summed weight of the listed participants
+    /// that have signed every section of the document.
+    pub fn signed_weight(&self) -> u64 {
+        self.weights
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| self.has_signed_all_sections(*i))
+            .map(|(_, w)| w)
+            .sum()
+    }
+
+    /// Whether any listed participant that has signed every section
+    /// has not yet claimed their receipt for the document.
+    pub fn has_unclaimed_receipts(&self) -> bool {
+        let signed = (0..self.participants.len())
+            .filter(|&i| self.has_signed_all_sections(i))
+            .count();
+        signed > self.receipts_claimed as usize
+    }
+
+    /// Clears the participant and signature data of a compressed document,
+    /// leaving a finalized tombstone that keeps the title from being reused
+    /// at the same address, and returns the byte size of the remaining data.
+    pub fn compress(&mut self) -> usize {
+        self.attachments.clear();
+        self.sections.clear();
+        self.prerequisites.clear();
+        self.participants.clear();
+        self.eth_participants.clear();
+        self.signature_timestamps.clear();
+        self.deadlines.clear();
+        self.weights.clear();
+        self.groups.clear();
+        self.signers.clear();
+        self.memos.clear();
+        self.signed_bitmap.clear();
+        self.acceptance_timestamps.clear();
+
+        Self::space(
+            self.title.len(),
+            self.uri.len(),
+            &[],
+            &ParticipantSpace {
+                participants: 0,
+                eth_participants: 0,
+                merkle_participants: 0,
+                groups: &[],
+            },
+            &[],
+            0,
+        )
+    }
+
+    /// Whether the listed participant at the argued index has signed every section.
+    pub fn has_signed_all_sections(&self, index: usize) -> bool {
+        let count = self.section_count();
+        self.signature_timestamps[index * count..(index + 1) * count]
+            .iter()
+            .all(|&t| t != 0)
+    }
+
+    /// Returns the number of members of the argued group that have signed every section.
+    pub fn group_signatures(&self, group: &SignerGroup) -> usize {
+        group
+            .members
+            .iter()
+            .filter(|&&m| self.has_signed_all_sections(m as usize))
+            .count()
+    }
+
+    /// Returns the signer group that the listed participant at the argued index belongs to.
+    pub fn try_find_group(&self, index: usize) -> Option<&SignerGroup> {
+        self.groups
+            .iter()
+            .find(|g| g.members.contains(&(index as u8)))
+    }
+
+    /// Whether the document has all signatures required and has been
+    /// finalized by the creator.
+    pub fn is_finalized(&self) -> bool {
+        self.finalization_timestamp != 0
+    }
+
+    /// Whether the document is a draft that has not been opened for signing.
+    pub fn is_draft(&self) -> bool {
+        self.settings.draft
+    }
+
+    /// Replaces the participants and settings of the document, resetting the
+    /// signature and acceptance data of every slot for the new participant set.
+    pub fn reset_participants(&mut self, participants: Vec<Pubkey>, settings: DocumentSettings) {
+        let sections = self.section_count();
+        let slots = (participants.len() + self.eth_participants.len()) * sections;
+
+        self.signature_timestamps = vec![0; slots];
+        self.deadlines = vec![0; participants.len()];
+        self.weights = vec![1; participants.len()];
+        self.groups = vec![];
+        self.signers = vec![Pubkey::default(); slots];
+        self.memos = vec![[0; Self::MEMO_SIZE]; slots];
+        self.signed_bitmap =
+            vec![0; Self::bitmap_size(settings.participant_count as usize, sections)];
+        self.acceptance_timestamps = vec![0; participants.len()];
+        self.participants = participants;
+        self.settings = settings;
+    }
+
+    /// Replaces the settings of the document, resetting the signature data of every
+    /// slot while the kept participants keep their deadlines, weights, signer group
+    /// assignments and acceptances.
+    pub fn reset_settings(&mut self, settings: DocumentSettings) {
+        let deadlines = std::mem::take(&mut self.deadlines);
+        let weights = std::mem::take(&mut self.weights);
+        let groups = std::mem::take(&mut self.groups);
+        let acceptance_timestamps = std::mem::take(&mut self.acceptance_timestamps);
+
+        let participants = std::mem::take(&mut self.participants);
+        self.reset_participants(participants, settings);
+
+        self.deadlines = deadlines;
+        self.weights = weights;
+        self.groups = groups;
+        self.acceptance_timestamps = acceptance_timestamps;
+    }
+
+    /// Invalidates every signature on the document while
+    /// keeping the participants and their slots in place.
+    pub fn reset_signatures(&mut self) {
+        self.signature_timestamps.iter_mut().for_each(|t| *t = 0);
+        self.signers.iter_mut().for_each(|s| *s = Pubkey::default());
+        self.memos
+            .iter_mut()
+            .for_each(|m| *m = [0; Self::MEMO_SIZE]);
+        self.signed_bitmap.iter_mut().for_each(|b| *b = 0);
+    }
+
+    /// Whether any participant has signed any section of the document.
+    pub fn has_signatures(&self) -> bool {
+        self.signature_timestamps.iter().any(|&t| t != 0)
+            || self.signed_bitmap.iter().any(|&b| b != 0)
+    }
+
+    /// Attempt to find and return the index of the attachment with the argued label.
+    pub fn try_find_attachment(&self, label: &str) -> Result<usize> {
+        self.attachments
+            .iter()
+            .position(|a| a.label == label)
+            .ok_or_else(|| error!(ErrorCode::AttachmentNotFound))
+    }
+
+    /// The program account signer seeds for programmatic authority.
     pub fn signer_seeds(&self) -> [&[u8]; 4] {
         [
             seeds::DOCUMENT,
@@ -159,11 +930,73 @@ impl Document {
     }
 
     /// Try to set the timestamp of the document finalization in the account data.
+    /// The document cannot be finalized before its `not_before` timestamp.
     pub fn try_finalize(&mut self) -> Result<()> {
-        self.finalization_timestamp = Clock::get()?.unix_timestamp as u64;
+        let now = Clock::get()?.unix_timestamp as u64;
+        require!(self.can_finalize_at(now), ErrorCode::FinalizationTooEarly);
+
+        self.finalization_timestamp = now;
+        Ok(())
+    }
+
+    /// Whether the argued unix timestamp is at or after the
+    /// earliest time that the document can be finalized.
+    pub fn can_finalize_at(&self, now: u64) -> bool {
+        self.settings.not_before.map_or(true, |t| now >= t)
+    }
+
+    /// Verify that each prerequisite document is either one of the `finalizing`
+    /// documents or is provided in the argued accounts and has been finalized.
+    pub fn try_check_prerequisites<'info>(
+        &self,
+        accounts: &[AccountInfo<'info>],
+        finalizing: &[Pubkey],
+    ) -> Result<()> {
+        for prereq in self
+            .prerequisites
+            .iter()
+            .filter(|p| !finalizing.contains(p))
+        {
+            // A prerequisite that has been compressed is proven
+            // finalized by the existence of its `Receipt` instead.
+            if let Some(info) = accounts.iter().find(|a| a.key == prereq) {
+                require!(
+                    Account::<Document>::try_from(info)?.is_finalized(),
+                    ErrorCode::PrerequisiteNotFinalized,
+                );
+            } else {
+                let receipt = Receipt::address(prereq);
+                let info = accounts
+                    .iter()
+                    .find(|a| *a.key == receipt)
+                    .ok_or(ErrorCode::PrerequisiteNotProvided)?;
+
+                Account::<Receipt>::try_from(info)?;
+            }
+        }
+
         Ok(())
     }
 
+    /// Appends a new participant public key with empty signature data for
+    /// each of the document sections, inserted before the signature slots
+    /// of any Ethereum participants.
+    pub fn push_participant(&mut self, participant: Pubkey) {
+        let count = self.section_count();
+        let at = self.participants.len() * count;
+
+        self.participants.push(participant);
+        self.signature_timestamps
+            .splice(at..at, std::iter::repeat(0).take(count));
+        self.signers
+            .splice(at..at, std::iter::repeat(Pubkey::default()).take(count));
+        self.memos
+            .splice(at..at, std::iter::repeat([0; Self::MEMO_SIZE]).take(count));
+        self.deadlines.push(0);
+        self.weights.push(1);
+        self.acceptance_timestamps.push(0);
+    }
+
     /// Attempt to find and return the index of the argued participant public key.
     pub fn try_find_participant(&self, participant: &Pubkey) -> Result<usize> {
         self.participants
@@ -172,16 +1005,229 @@ impl Document {
             .ok_or_else(|| error!(ErrorCode::ParticipantNotAssociated))
     }
 
-    /// Check if the argued index has been marked as already signing the document.
-    pub fn try_has_signed<'a>(&self, participant: &Signer<'a>) -> Result<bool> {
-        let i = self.try_find_participant(&participant.key())?;
+    /// Returns the participant public keys that are still required to accept
+    /// their invitation before being able to sign the document.
+    pub fn pending_invitations(&self) -> Vec<Pubkey> {
+        if !self.settings.requires_acceptance {
+            return Vec::new();
+        }
+
+        self.participants
+            .iter()
+            .zip(self.acceptance_timestamps.iter())
+            .filter(|(_, &t)| t == 0)
+            .map(|(p, _)| *p)
+            .collect()
+    }
+
+    /// Check if the argued participant is permitted to sign based on whether
+    /// the document requires acceptance and they have accepted their invitation.
+    pub fn try_has_accepted(&self, participant: &Pubkey) -> Result<bool> {
+        if !self.settings.requires_acceptance {
+            return Ok(true);
+        }
+
+        let i = self.try_find_participant(participant)?;
+        Ok(self.acceptance_timestamps[i] != 0)
+    }
+
+    /// Attempt to mark the argued participant as having accepted their invitation.
+    pub fn try_accept(&mut self, participant: &Pubkey) -> Result<()> {
+        let i = self.try_find_participant(participant)?;
+        require!(
+            self.acceptance_timestamps[i] == 0,
+            ErrorCode::ParticipantAlreadyAccepted,
+        );
+
+        self.acceptance_timestamps[i] = Clock::get()?.unix_timestamp as u64;
+        Ok(())
+    }
+
+    /// Returns the participant public keys whose deadline has passed
+    /// at the argued unix timestamp without signing every section.
+    pub fn overdue_participants(&self, now: u64) -> Vec<Pubkey> {
+        let count = self.section_count();
+
+        self.participants
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| {
+                let deadline = self.deadlines[*i];
+                deadline != 0
+                    && now > deadline
+                    && self.signature_timestamps[i * count..(i + 1) * count]
+                        .iter()
+                        .any(|&t| t == 0)
+            })
+            .map(|(_, p)| *p)
+            .collect()
+    }
+
+    /// Attempt to move the deadline of the argued participant to the later
+    /// unix timestamp, which is only possible for participants with a deadline.
+    pub fn try_extend_deadline(&mut self, participant: &Pubkey, deadline: u64) -> Result<()> {
+        let i = self.try_find_participant(participant)?;
+        require!(
+            self.deadlines[i] != 0 && deadline > self.deadlines[i],
+            ErrorCode::DeadlineNotExtended,
+        );
+
+        self.deadlines[i] = deadline;
+        Ok(())
+    }
+
+    /// Attempt to find the index of the signature slot for the argued
+    /// participant public key and section index.
+    pub fn try_find_slot(&self, participant: &Pubkey, section: u8) -> Result<usize> {
+        let i = self.try_find_participant(participant)?;
+        require_gt!(
+            self.section_count(),
+            section as usize,
+            ErrorCode::InvalidDocumentSection,
+        );
+
+        Ok(i * self.section_count() + section as usize)
+    }
+
+    /// Attempt to find the index of the signature slot for the argued
+    /// Ethereum participant address and section index.
+    pub fn try_find_eth_slot(&self, eth_address: &[u8; 20], section: u8) -> Result<usize> {
+        let i = self
+            .eth_participants
+            .iter()
+            .position(|a| a == eth_address)
+            .ok_or(ErrorCode::ParticipantNotAssociated)?;
+
+        require_gt!(
+            self.section_count(),
+            section as usize,
+            ErrorCode::InvalidDocumentSection,
+        );
+
+        Ok((self.participants.len() + i) * self.section_count() + section as usize)
+    }
+
+    /// Attempt to verify the inclusion proof of the argued participant in the
+    /// Merkle committed participant set and find the index of the bit in the
+    /// signed bitmap for the section.
+    pub fn try_find_merkle_bit(
+        &self,
+        participant: &Pubkey,
+        section: u8,
+        proof: &ParticipantProof,
+    ) -> Result<usize> {
+        let root = self
+            .settings
+            .participant_root
+            .ok_or(ErrorCode::InvalidParticipantProof)?;
+
+        require!(
+            proof.index < self.settings.participant_count
+                && merkle::verify(&root, merkle::leaf(proof.index, participant), &proof.path),
+            ErrorCode::InvalidParticipantProof,
+        );
+
+        require_gt!(
+            self.section_count(),
+            section as usize,
+            ErrorCode::InvalidDocumentSection,
+        );
+
+        Ok(proof.index as usize * self.section_count() + section as usize)
+    }
+
+    /// Check if the argued participant has been marked as already signing the section,
+    /// where a `proof` is required for members of a Merkle committed participant set.
+    pub fn try_has_signed(
+        &self,
+        participant: &Pubkey,
+        section: u8,
+        proof: Option<&ParticipantProof>,
+    ) -> Result<bool> {
+        if let Some(proof) = proof {
+            let b = self.try_find_merkle_bit(participant, section, proof)?;
+            return Ok(self.signed_bitmap[b / 8] & (1 << (b % 8)) != 0);
+        }
+
+        let i = self.try_find_slot(participant, section)?;
         Ok(*self.signature_timestamps.get(i).unwrap() != 0)
     }
 
-    /// Attempt to mark the argued public key participant as having signed the document.
-    pub fn try_sign<'a>(&mut self, participant: &Signer<'a>) -> Result<()> {
-        let i = self.try_find_participant(&participant.key())?;
+    /// Attempt to replace the `old` participant public key with the `new` one,
+    /// optionally clearing any signatures that were already recorded for it.
+    /// The new participant is always required to accept their own invitation.
+    pub fn try_rotate(&mut self, old: &Pubkey, new: Pubkey, reset_signature: bool) -> Result<()> {
+        let i = self.try_find_participant(old)?;
+        self.participants[i] = new;
+        self.acceptance_timestamps[i] = 0;
+
+        if reset_signature {
+            let count = self.section_count();
+            for slot in i * count..(i + 1) * count {
+                self.signature_timestamps[slot] = 0;
+                self.signers[slot] = Pubkey::default();
+                self.memos[slot] = [0; Self::MEMO_SIZE];
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Attempt to mark the argued public key participant as having signed the
+    /// section and record the `signer` and optional `memo` submitted with it.
+    /// Members of a Merkle committed participant set only have their bit set
+    /// in the signed bitmap after their `proof` is verified, while listed
+    /// participants are rejected once their deadline has passed.
+    pub fn try_sign(
+        &mut self,
+        participant: &Pubkey,
+        section: u8,
+        signer: &Pubkey,
+        memo: Option<[u8; 32]>,
+        proof: Option<&ParticipantProof>,
+    ) -> Result<()> {
+        if let Some(proof) = proof {
+            // Committed participants only have a bit in the signed bitmap rather
+            // than the memo and deadline slots of listed participants, so a memo
+            // is rejected instead of being silently dropped.
+            require!(memo.is_none(), ErrorCode::MemoNotAllowedWithProof);
+
+            let b = self.try_find_merkle_bit(participant, section, proof)?;
+            self.signed_bitmap[b / 8] |= 1 << (b % 8);
+            return Ok(());
+        }
+
+        let i = self.try_find_slot(participant, section)?;
+        let now = Clock::get()?.unix_timestamp as u64;
+
+        let deadline = self.deadlines[i / self.section_count()];
+        require!(
+            deadline == 0 || now <= deadline,
+            ErrorCode::ParticipantDeadlinePassed,
+        );
+
+        self.signature_timestamps[i] = now;
+        self.signers[i] = *signer;
+        self.memos[i] = memo.unwrap_or_default();
+        Ok(())
+    }
+
+    /// Attempt to mark the argued Ethereum participant as having signed the
+    /// section and record the `submitter` that relayed their signature.
+    pub fn try_sign_eth(
+        &mut self,
+        eth_address: &[u8; 20],
+        section: u8,
+        submitter: &Pubkey,
+    ) -> Result<()> {
+        let i = self.try_find_eth_slot(eth_address, section)?;
+        require!(
+            self.signature_timestamps[i] == 0,
+            ErrorCode::ParticipantAlreadySigned,
+        );
+
         self.signature_timestamps[i] = Clock::get()?.unix_timestamp as u64;
+        self.signers[i] = *submitter;
         Ok(())
     }
 }
@@ -192,10 +1238,23 @@ impl Serialize for Document {
     where
         S: Serializer,
     {
-        let mut s = serializer.serialize_struct("Document", 6)?;
+        let mut s = serializer.serialize_struct("Document", 21)?;
+        s.serialize_field("version", &self.version)?;
         s.serialize_field("authority", &self.authority.to_string())?;
         s.serialize_field("title", &self.title)?;
+        s.serialize_field("attachments", &self.attachments)?;
         s.serialize_field("createdAt", &self.created_at)?;
+        s.serialize_field("settings", &self.settings)?;
+        s.serialize_field("sections", &self.sections)?;
+        s.serialize_field(
+            "prerequisites",
+            &self
+                .prerequisites
+                .iter()
+                .map(|p| p.to_string())
+                .collect::<Vec<String>>(),
+        )?;
+        s.serialize_field("bundle", &self.bundle.map(|b| b.to_string()))?;
         s.serialize_field(
             "participants",
             &self
@@ -204,79 +1263,1280 @@ impl Serialize for Document {
                 .map(|p| p.to_string())
                 .collect::<Vec<String>>(),
         )?;
+        s.serialize_field(
+            "ethParticipants",
+            &self
+                .eth_participants
+                .iter()
+                .map(display_eth_address)
+                .collect::<Vec<String>>(),
+        )?;
         s.serialize_field("signatureTimestamps", &self.signature_timestamps)?;
+        s.serialize_field("deadlines", &self.deadlines)?;
+        s.serialize_field("weights", &self.weights)?;
+        s.serialize_field("groups", &self.groups)?;
+        s.serialize_field(
+            "signers",
+            &self
+                .signers
+                .iter()
+                .map(|p| p.to_string())
+                .collect::<Vec<String>>(),
+        )?;
+        s.serialize_field(
+            "memos",
+            &self
+                .memos
+                .iter()
+                .map(display_memo)
+                .collect::<Vec<Option<String>>>(),
+        )?;
+        s.serialize_field(
+            "signedBitmap",
+            &self
+                .signed_bitmap
+                .iter()
+                .map(|b| format!("{:08b}", b.reverse_bits()))
+                .collect::<String>(),
+        )?;
+        s.serialize_field(
+            "pendingInvitations",
+            &self
+                .pending_invitations()
+                .iter()
+                .map(|p| p.to_string())
+                .collect::<Vec<String>>(),
+        )?;
+        s.serialize_field("receiptsClaimed", &self.receipts_claimed)?;
         s.serialize_field("finalizationTimestamp", &self.finalization_timestamp)?;
         s.end()
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use serde_test::{assert_ser_tokens, Token};
+/// Converts a stored Ethereum participant address into its `0x` prefixed hex string.
+#[cfg(any(test, feature = "cli"))]
+pub fn display_eth_address(address: &[u8; 20]) -> String {
+    format!(
+        "0x{}",
+        address
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect::<String>()
+    )
+}
 
-    use super::*;
+/// Converts a stored signature memo into a displayable string, which is the
+/// memo text if it is valid UTF-8 or otherwise the hex encoding of the hash.
+#[cfg(any(test, feature = "cli"))]
+fn display_memo(memo: &[u8; 32]) -> Option<String> {
+    if memo.iter().all(|&b| b == 0) {
+        return None;
+    }
 
-    #[test]
-    fn clerk_serialization() {
-        assert_ser_tokens(
-            &Clerk {
-                authority: Pubkey::default(),
-                documents: vec![Pubkey::default(); 3],
-                upgrades: 3,
-                bump: [0],
-            },
-            &[
-                Token::Struct {
-                    name: "Clerk",
-                    len: 3,
-                },
-                Token::Str("authority"),
-                Token::Str("11111111111111111111111111111111"),
-                Token::Str("documents"),
-                Token::Seq { len: Some(3) },
-                Token::Str("11111111111111111111111111111111"),
-                Token::Str("11111111111111111111111111111111"),
-                Token::Str("11111111111111111111111111111111"),
-                Token::SeqEnd,
-                Token::Str("upgrades"),
-                Token::U8(3),
-                Token::StructEnd,
-            ],
+    let end = memo.iter().rposition(|&b| b != 0).unwrap() + 1;
+    match std::str::from_utf8(&memo[..end]) {
+        Ok(text) => Some(text.to_string()),
+        Err(_) => Some(memo.iter().map(|b| format!("{:02x}", b)).collect()),
+    }
+}
+
+#[account]
+#[cfg_attr(any(test, feature = "cli"), derive(Debug))]
+pub struct FeeWaiver {
+    /// The `Clerk` public key whose documents are exempt from the creation fee.
+    pub clerk: Pubkey,
+
+    /// The program account bump nonce.
+    pub bump: [u8; 1],
+}
+
+impl FeeWaiver {
+    /// The byte size of the `FeeWaiver` struct.
+    pub const SPACE: usize = 8 + 32 + 1;
+}
+
+#[cfg(any(test, feature = "cli"))]
+impl Serialize for FeeWaiver {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut s = serializer.serialize_struct("FeeWaiver", 1)?;
+        s.serialize_field("clerk", &self.clerk.to_string())?;
+        s.end()
+    }
+}
+
+/// A single section document for large participant sets whose account data is
+/// accessed in place instead of being deserialized, so that the compute cost of
+/// signing and finalizing does not grow with the number of participants.
+#[account(zero_copy)]
+#[cfg_attr(any(test, feature = "cli"), derive(Debug))]
+pub struct LargeDocument {
+    /// The public key of the wallet that created the document.
+    pub authority: Pubkey,
+
+    /// The unix timestamp of when the document was initialized.
+    pub created_at: u64,
+
+    /// The unix timestamp of when the document was finalized, or zero.
+    pub finalization_timestamp: u64,
+
+    /// The number of occupied slots in `participants`.
+    pub participant_count: u16,
+
+    /// The number of `participants` that have signed the document.
+    pub signature_count: u16,
+
+    /// The byte length of the `title` within its fixed capacity.
+    pub title_length: u16,
+
+    /// The byte length of the `uri` within its fixed capacity.
+    pub uri_length: u16,
+
+    /// The title of the document padded with zeros.
+    pub title: [u8; 64],
+
+    /// The content address URI of the document padded with zeros.
+    pub uri: [u8; 256],
+
+    /// The public keys required to sign, kept in ascending order so that
+    /// a participant can be found by binary search.
+    pub participants: [Pubkey; 512],
+
+    /// The signature timestamp of each of the `participants`.
+    pub signature_timestamps: [u64; 512],
+}
+
+impl LargeDocument {
+    /// The maximum number of participants of a large document.
+    pub const CAPACITY: usize = 512;
+
+    /// The maximum byte length of a large document title.
+    pub const MAX_TITLE_LENGTH: usize = 64;
+
+    /// The maximum byte length of a large document URI.
+    pub const MAX_URI_LENGTH: usize = 256;
+
+    /// The byte size of the `LargeDocument` account, which must be created by
+    /// the client since it exceeds the size that can be allocated by the program.
+    pub const SPACE: usize = 8 + std::mem::size_of::<LargeDocument>();
+
+    /// Sets the title, URI and creation details of a newly created document.
+    pub fn init(&mut self, authority: Pubkey, title: &str, uri: &str, now: u64) {
+        self.authority = authority;
+        self.created_at = now;
+        self.title_length = title.len() as u16;
+        self.title[..title.len()].copy_from_slice(title.as_bytes());
+        self.uri_length = uri.len() as u16;
+        self.uri[..uri.len()].copy_from_slice(uri.as_bytes());
+    }
+
+    /// The title of the document.
+    pub fn title(&self) -> &str {
+        std::str::from_utf8(&self.title[..self.title_length as usize]).unwrap_or_default()
+    }
+
+    /// The content address URI of the document.
+    pub fn uri(&self) -> &str {
+        std::str::from_utf8(&self.uri[..self.uri_length as usize]).unwrap_or_default()
+    }
+
+    /// The public keys of the participants in ascending order.
+    pub fn participants(&self) -> &[Pubkey] {
+        &self.participants[..self.participant_count as usize]
+    }
+
+    /// Checks if every participant has signed the document.
+    pub fn has_all_signatures(&self) -> bool {
+        self.participant_count > 0 && self.signature_count == self.participant_count
+    }
+
+    /// Whether the document has been finalized.
+    pub fn is_finalized(&self) -> bool {
+        self.finalization_timestamp != 0
+    }
+
+    /// Attempt to append the argued participants, which must each be greater
+    /// than the last participant to keep the set sorted and free of duplicates.
+    pub fn try_push_participants(&mut self, participants: &[Pubkey]) -> Result<()> {
+        let count = self.participant_count as usize;
+        require_gte!(
+            Self::CAPACITY,
+            count + participants.len(),
+            ErrorCode::TooManyDocumentParticipants,
         );
+
+        let mut last = self.participants().last().copied();
+        for (i, p) in participants.iter().enumerate() {
+            require!(
+                last.map_or(true, |l| *p > l),
+                ErrorCode::ParticipantsAreNotSorted,
+            );
+            self.participants[count + i] = *p;
+            last = Some(*p);
+        }
+
+        self.participant_count += participants.len() as u16;
+        Ok(())
     }
 
-    #[test]
-    fn document_serialization() {
-        assert_ser_tokens(
-            &Document {
-                authority: Pubkey::default(),
-                title: "Test".into(),
-                uri: "https://arweave.net/abc1234567890".into(),
-                created_at: 0,
-                participants: vec![Pubkey::default()],
-                signature_timestamps: vec![0],
+    /// Attempt to mark the argued participant as having signed the document.
+    pub fn try_sign(&mut self, participant: &Pubkey) -> Result<()> {
+        let i = self
+            .participants()
+            .binary_search(participant)
+            .map_err(|_| error!(ErrorCode::ParticipantNotAssociated))?;
+
+        require!(
+            self.signature_timestamps[i] == 0,
+            ErrorCode::ParticipantAlreadySigned,
+        );
+
+        self.signature_timestamps[i] = Clock::get()?.unix_timestamp as u64;
+        self.signature_count += 1;
+        Ok(())
+    }
+
+    /// Try to set the timestamp of the document finalization.
+    pub fn try_finalize(&mut self) -> Result<()> {
+        self.finalization_timestamp = Clock::get()?.unix_timestamp as u64;
+        Ok(())
+    }
+}
+
+#[cfg(any(test, feature = "cli"))]
+impl Serialize for LargeDocument {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let count = self.participant_count as usize;
+
+        let mut s = serializer.serialize_struct("LargeDocument", 7)?;
+        s.serialize_field("authority", &self.authority.to_string())?;
+        s.serialize_field("title", self.title())?;
+        s.serialize_field("uri", self.uri())?;
+        s.serialize_field("createdAt", &{ self.created_at })?;
+        s.serialize_field(
+            "participants",
+            &self
+                .participants()
+                .iter()
+                .map(|p| p.to_string())
+                .collect::<Vec<String>>(),
+        )?;
+        s.serialize_field(
+            "signatureTimestamps",
+            &(0..count)
+                .map(|i| self.signature_timestamps[i])
+                .collect::<Vec<u64>>(),
+        )?;
+        s.serialize_field("finalizationTimestamp", &{ self.finalization_timestamp })?;
+        s.end()
+    }
+}
+
+#[account]
+#[cfg_attr(any(test, feature = "cli"), derive(Debug))]
+pub struct Receipt {
+    /// The public key of the finalized `Document` that was compressed into the receipt.
+    pub document: Pubkey,
+
+    /// The SHA-256 hash of the title and URI of the document.
+    pub content_hash: [u8; 32],
+
+    /// The Merkle root committing to the Solana participants of the document.
+    pub participants_root: [u8; 32],
+
+    /// The unix timestamp of when the document was created.
+    pub created_at: u64,
+
+    /// The unix timestamp of when the document was finalized.
+    pub finalization_timestamp: u64,
+
+    /// The program account bump nonce.
+    pub bump: [u8; 1],
+}
+
+impl Receipt {
+    /// The byte size of the `Receipt` struct.
+    pub const SPACE: usize = 8 + 32 + 32 + 32 + 8 + 8 + 1;
+
+    /// Returns the address of the receipt of the argued `Document` public key.
+    pub fn address(document: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[seeds::RECEIPT, document.as_ref()], &crate::ID).0
+    }
+
+    /// Verify that the argued participant is the leaf at the `index` of the
+    /// committed participant set using the sibling hash `path`.
+    pub fn verify_participant(&self, participant: &Pubkey, index: u32, path: &[[u8; 32]]) -> bool {
+        merkle::verify(
+            &self.participants_root,
+            merkle::leaf(index, participant),
+            path,
+        )
+    }
+}
+
+#[cfg(any(test, feature = "cli"))]
+impl Serialize for Receipt {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut s = serializer.serialize_struct("Receipt", 5)?;
+        s.serialize_field("document", &self.document.to_string())?;
+        s.serialize_field(
+            "contentHash",
+            &self
+                .content_hash
+                .iter()
+                .map(|b| format!("{:02x}", b))
+                .collect::<String>(),
+        )?;
+        s.serialize_field(
+            "participantsRoot",
+            &self
+                .participants_root
+                .iter()
+                .map(|b| format!("{:02x}", b))
+                .collect::<String>(),
+        )?;
+        s.serialize_field("createdAt", &self.created_at)?;
+        s.serialize_field("finalizationTimestamp", &self.finalization_timestamp)?;
+        s.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::solana_program::{entrypoint, program_stubs};
+    use serde_test::{assert_ser_tokens, Token};
+    use std::cell::Cell;
+
+    use super::*;
+    use crate::error::error_code_number;
+
+    /// Creates an unsigned `Document` for the argued participants and sections.
+    fn new_document(participants: &[Pubkey], sections: &[&str]) -> Document {
+        let mut doc = Document {
+            version: Document::VERSION,
+            authority: Pubkey::default(),
+            title: "Test".into(),
+            uri: "https://arweave.net/abc1234567890".into(),
+            attachments: vec![],
+            created_at: 0,
+            settings: DocumentSettings::default(),
+            sections: sections.iter().map(|s| s.to_string()).collect(),
+            prerequisites: vec![],
+            bundle: None,
+            participants: vec![],
+            eth_participants: vec![],
+            signature_timestamps: vec![],
+            deadlines: vec![],
+            weights: vec![],
+            groups: vec![],
+            signers: vec![],
+            memos: vec![],
+            signed_bitmap: vec![],
+            acceptance_timestamps: vec![],
+            receipts_claimed: 0,
+            finalization_timestamp: 0,
+            bump: [0],
+        };
+
+        participants.iter().for_each(|p| doc.push_participant(*p));
+        doc
+    }
+
+    thread_local! {
+        /// The unix timestamp returned by the stubbed `Clock::get` on the test thread.
+        static UNIX_TIMESTAMP: Cell<i64> = Cell::new(0);
+    }
+
+    /// Replaces the off-chain syscall stubs so that `Clock::get` returns the argued
+    /// unix timestamp instead of an unsupported sysvar error. The syscall stubs are
+    /// shared by every test thread, so each thread reads its own timestamp.
+    fn stub_clock(unix_timestamp: i64) {
+        struct ClockStub;
+
+        impl program_stubs::SyscallStubs for ClockStub {
+            fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+                unsafe {
+                    *(var_addr as *mut Clock) = Clock {
+                        unix_timestamp: UNIX_TIMESTAMP.with(Cell::get),
+                        ..Clock::default()
+                    };
+                }
+                entrypoint::SUCCESS
+            }
+        }
+
+        UNIX_TIMESTAMP.with(|t| t.set(unix_timestamp));
+        program_stubs::set_syscall_stubs(Box::new(ClockStub));
+    }
+
+    #[test]
+    fn bundle_serialization() {
+        assert_ser_tokens(
+            &Bundle {
+                authority: Pubkey::default(),
+                clerk: Pubkey::default(),
+                name: "Closing".into(),
+                documents: vec![Pubkey::default(); 2],
+                finalization_timestamp: 0,
+                bump: [0],
+            },
+            &[
+                Token::Struct {
+                    name: "Bundle",
+                    len: 5,
+                },
+                Token::Str("authority"),
+                Token::Str("11111111111111111111111111111111"),
+                Token::Str("clerk"),
+                Token::Str("11111111111111111111111111111111"),
+                Token::Str("name"),
+                Token::Str("Closing"),
+                Token::Str("documents"),
+                Token::Seq { len: Some(2) },
+                Token::Str("11111111111111111111111111111111"),
+                Token::Str("11111111111111111111111111111111"),
+                Token::SeqEnd,
+                Token::Str("finalizationTimestamp"),
+                Token::U64(0),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn clerk_serialization() {
+        assert_ser_tokens(
+            &Clerk {
+                version: Clerk::VERSION,
+                authority: Pubkey::default(),
+                documents: vec![Pubkey::default(); 3],
+                upgrades: 3,
+                bump: [0],
+            },
+            &[
+                Token::Struct {
+                    name: "Clerk",
+                    len: 4,
+                },
+                Token::Str("version"),
+                Token::U8(1),
+                Token::Str("authority"),
+                Token::Str("11111111111111111111111111111111"),
+                Token::Str("documents"),
+                Token::Seq { len: Some(3) },
+                Token::Str("11111111111111111111111111111111"),
+                Token::Str("11111111111111111111111111111111"),
+                Token::Str("11111111111111111111111111111111"),
+                Token::SeqEnd,
+                Token::Str("upgrades"),
+                Token::U8(3),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn config_serialization() {
+        assert_ser_tokens(
+            &Config {
+                admin: Pubkey::default(),
+                limits: ConfigLimits {
+                    max_title_length: 128,
+                    max_uri_length: 256,
+                    max_participants: 20,
+                    max_clerk_limit: 50,
+                },
+                paused: true,
+                treasury: Pubkey::default(),
+                document_fee: 5000,
+                bump: [0],
+            },
+            &[
+                Token::Struct {
+                    name: "Config",
+                    len: 5,
+                },
+                Token::Str("admin"),
+                Token::Str("11111111111111111111111111111111"),
+                Token::Str("limits"),
+                Token::Struct {
+                    name: "ConfigLimits",
+                    len: 4,
+                },
+                Token::Str("maxTitleLength"),
+                Token::U16(128),
+                Token::Str("maxUriLength"),
+                Token::U16(256),
+                Token::Str("maxParticipants"),
+                Token::U16(20),
+                Token::Str("maxClerkLimit"),
+                Token::U8(50),
+                Token::StructEnd,
+                Token::Str("paused"),
+                Token::Bool(true),
+                Token::Str("treasury"),
+                Token::Str("11111111111111111111111111111111"),
+                Token::Str("documentFee"),
+                Token::U64(5000),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn fee_waiver_serialization() {
+        assert_ser_tokens(
+            &FeeWaiver {
+                clerk: Pubkey::default(),
+                bump: [0],
+            },
+            &[
+                Token::Struct {
+                    name: "FeeWaiver",
+                    len: 1,
+                },
+                Token::Str("clerk"),
+                Token::Str("11111111111111111111111111111111"),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    /// Creates an empty `LargeDocument` with the argued title and URI.
+    fn new_large_document(title: &str, uri: &str) -> LargeDocument {
+        let mut doc = LargeDocument {
+            authority: Pubkey::default(),
+            created_at: 0,
+            finalization_timestamp: 0,
+            participant_count: 0,
+            signature_count: 0,
+            title_length: 0,
+            uri_length: 0,
+            title: [0; LargeDocument::MAX_TITLE_LENGTH],
+            uri: [0; LargeDocument::MAX_URI_LENGTH],
+            participants: [Pubkey::default(); LargeDocument::CAPACITY],
+            signature_timestamps: [0; LargeDocument::CAPACITY],
+        };
+
+        doc.init(Pubkey::default(), title, uri, 1);
+        doc
+    }
+
+    #[test]
+    fn large_document_serialization() {
+        let participant = Pubkey::default();
+        let mut doc = new_large_document("Test", "ar://abc");
+        doc.try_push_participants(&[participant]).unwrap();
+
+        assert_ser_tokens(
+            &doc,
+            &[
+                Token::Struct {
+                    name: "LargeDocument",
+                    len: 7,
+                },
+                Token::Str("authority"),
+                Token::Str("11111111111111111111111111111111"),
+                Token::Str("title"),
+                Token::Str("Test"),
+                Token::Str("uri"),
+                Token::Str("ar://abc"),
+                Token::Str("createdAt"),
+                Token::U64(1),
+                Token::Str("participants"),
+                Token::Seq { len: Some(1) },
+                Token::Str("11111111111111111111111111111111"),
+                Token::SeqEnd,
+                Token::Str("signatureTimestamps"),
+                Token::Seq { len: Some(1) },
+                Token::U64(0),
+                Token::SeqEnd,
+                Token::Str("finalizationTimestamp"),
+                Token::U64(0),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn large_document_participants_are_sorted() {
+        let mut keys: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        keys.sort();
+
+        let mut doc = new_large_document("Test", "ar://abc");
+        doc.try_push_participants(&keys[..2]).unwrap();
+
+        assert_eq!(
+            error_code_number(doc.try_push_participants(&[keys[1]]).unwrap_err()),
+            u32::from(ErrorCode::ParticipantsAreNotSorted),
+        );
+        assert_eq!(
+            error_code_number(doc.try_push_participants(&[keys[3], keys[2]]).unwrap_err()),
+            u32::from(ErrorCode::ParticipantsAreNotSorted),
+        );
+
+        doc.try_push_participants(&keys[2..]).unwrap();
+        assert_eq!(doc.participants(), &keys[..]);
+
+        doc.participant_count = LargeDocument::CAPACITY as u16;
+        assert_eq!(
+            error_code_number(
+                doc.try_push_participants(&[Pubkey::new_unique()])
+                    .unwrap_err()
+            ),
+            u32::from(ErrorCode::TooManyDocumentParticipants),
+        );
+    }
+
+    #[test]
+    fn large_document_signing() {
+        stub_clock(100);
+
+        let mut keys: Vec<Pubkey> = (0..LargeDocument::CAPACITY)
+            .map(|_| Pubkey::new_unique())
+            .collect();
+        keys.sort();
+
+        let mut doc = new_large_document("Test", "ar://abc");
+        for chunk in keys.chunks(30) {
+            doc.try_push_participants(chunk).unwrap();
+        }
+
+        assert!(!doc.has_all_signatures());
+        assert_eq!(
+            error_code_number(doc.try_sign(&Pubkey::new_unique()).unwrap_err()),
+            u32::from(ErrorCode::ParticipantNotAssociated),
+        );
+
+        for k in &keys {
+            doc.try_sign(k).unwrap();
+        }
+
+        assert_eq!(
+            error_code_number(doc.try_sign(&keys[7]).unwrap_err()),
+            u32::from(ErrorCode::ParticipantAlreadySigned),
+        );
+        assert_eq!({ doc.signature_timestamps[7] }, 100);
+        assert!(doc.has_all_signatures());
+
+        doc.try_finalize().unwrap();
+        assert!(doc.is_finalized());
+    }
+
+    #[test]
+    fn receipt_serialization() {
+        assert_ser_tokens(
+            &Receipt {
+                document: Pubkey::default(),
+                content_hash: [0xab; 32],
+                participants_root: [0; 32],
+                created_at: 1_650_000_000,
+                finalization_timestamp: 1_660_000_000,
+                bump: [0],
+            },
+            &[
+                Token::Struct {
+                    name: "Receipt",
+                    len: 5,
+                },
+                Token::Str("document"),
+                Token::Str("11111111111111111111111111111111"),
+                Token::Str("contentHash"),
+                Token::Str(Box::leak("ab".repeat(32).into_boxed_str())),
+                Token::Str("participantsRoot"),
+                Token::Str(Box::leak("00".repeat(32).into_boxed_str())),
+                Token::Str("createdAt"),
+                Token::U64(1_650_000_000),
+                Token::Str("finalizationTimestamp"),
+                Token::U64(1_660_000_000),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn receipt_participant_inclusion() {
+        let participants = (0..5).map(|_| Pubkey::new_unique()).collect::<Vec<_>>();
+        let tree = merkle::MerkleTree::new(&participants);
+
+        let listed = new_document(&participants, &[]);
+        let mut committed = new_document(&[], &[]);
+        committed.settings.participant_root = Some(tree.root());
+
+        for doc in [listed, committed] {
+            let receipt = Receipt {
+                document: Pubkey::new_unique(),
+                content_hash: doc.content_hash(),
+                participants_root: doc.participants_root(),
+                created_at: doc.created_at,
+                finalization_timestamp: doc.finalization_timestamp,
+                bump: [0],
+            };
+
+            assert!(receipt.verify_participant(&participants[3], 3, &tree.proof(3)));
+            assert!(!receipt.verify_participant(&participants[3], 2, &tree.proof(3)));
+            assert!(!receipt.verify_participant(&Pubkey::new_unique(), 3, &tree.proof(3)));
+        }
+    }
+
+    #[test]
+    fn clerk_release() {
+        let documents = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let mut clerk = Clerk {
+            version: Clerk::VERSION,
+            authority: Pubkey::default(),
+            documents: documents.to_vec(),
+            upgrades: 0,
+            bump: [0],
+        };
+        assert!(clerk.is_full());
+
+        clerk.release(&documents[0]);
+        assert!(!clerk.is_full());
+        assert!(!clerk.holds(&documents[0]) && clerk.holds(&documents[1]));
+    }
+
+    #[test]
+    fn document_compression() {
+        let participants = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let mut doc = new_document(&participants, &["Terms", "Schedule A"]);
+
+        stub_clock(1_650_000_000);
+
+        assert!(!doc.has_unclaimed_receipts());
+        doc.try_sign(&participants[0], 0, &participants[0], None, None)
+            .unwrap();
+        assert!(!doc.has_unclaimed_receipts());
+        doc.try_sign(&participants[0], 1, &participants[0], None, None)
+            .unwrap();
+        assert!(doc.has_unclaimed_receipts());
+
+        doc.receipts_claimed = 1;
+        assert!(!doc.has_unclaimed_receipts());
+
+        let len = doc.try_to_vec().unwrap().len();
+        let size = doc.compress();
+        assert!(doc.participants.is_empty() && doc.signature_timestamps.is_empty());
+        assert!(size >= 8 + doc.try_to_vec().unwrap().len());
+        assert!(size < 8 + len);
+        assert_eq!(doc.title, "Test");
+    }
+
+    #[test]
+    fn delegation_serialization() {
+        assert_ser_tokens(
+            &Delegation {
+                participant: Pubkey::default(),
+                delegate: Pubkey::default(),
+                document: None,
+                expires_at: Some(100),
+                bump: [0],
+            },
+            &[
+                Token::Struct {
+                    name: "Delegation",
+                    len: 4,
+                },
+                Token::Str("participant"),
+                Token::Str("11111111111111111111111111111111"),
+                Token::Str("delegate"),
+                Token::Str("11111111111111111111111111111111"),
+                Token::Str("document"),
+                Token::None,
+                Token::Str("expiresAt"),
+                Token::Some,
+                Token::U64(100),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn document_section_slots() {
+        let participants = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+        let mut doc = new_document(&participants, &["Schedule A", "Schedule B", "Schedule C"]);
+        assert_eq!(doc.signature_timestamps.len(), 6);
+
+        assert_eq!(doc.try_find_slot(&participants[0], 2).unwrap(), 2);
+        assert_eq!(doc.try_find_slot(&participants[1], 1).unwrap(), 4);
+        assert!(doc.try_find_slot(&participants[1], 3).is_err());
+        assert!(doc.try_find_slot(&Pubkey::new_unique(), 0).is_err());
+
+        let added = Pubkey::new_unique();
+        doc.push_participant(added);
+        assert_eq!(doc.signature_timestamps.len(), 9);
+        assert_eq!(doc.try_find_slot(&added, 0).unwrap(), 6);
+
+        doc.signature_timestamps = vec![1; 9];
+        assert!(doc.has_all_signatures());
+
+        doc.try_rotate(&participants[1], Pubkey::new_unique(), true)
+            .unwrap();
+        assert_eq!(doc.signature_timestamps, vec![1, 1, 1, 0, 0, 0, 1, 1, 1]);
+        assert!(!doc.has_all_signatures());
+    }
+
+    #[test]
+    fn pda_participant_signing() {
+        let (governance, _) = Pubkey::find_program_address(
+            &[b"governance", Pubkey::new_unique().as_ref()],
+            &crate::ID,
+        );
+
+        let wallet = Pubkey::new_unique();
+        let mut doc = new_document(&[governance, wallet], &[]);
+
+        stub_clock(1_650_000_000);
+
+        assert!(!doc.try_has_signed(&governance, 0, None).unwrap());
+        doc.try_sign(&governance, 0, &governance, None, None)
+            .unwrap();
+        assert!(doc.try_has_signed(&governance, 0, None).unwrap());
+        assert!(!doc.try_has_signed(&wallet, 0, None).unwrap());
+
+        assert_eq!(doc.signature_timestamps, vec![1_650_000_000, 0]);
+        assert_eq!(doc.signers, vec![governance, Pubkey::default()]);
+        assert!(!doc.has_all_signatures());
+    }
+
+    #[test]
+    fn attachments_and_signature_detection() {
+        let participant = Pubkey::new_unique();
+        let mut doc = new_document(&[participant], &[]);
+
+        doc.attachments.push(Attachment {
+            label: "Mirror".into(),
+            uri: "ar://abc123".into(),
+            content_hash: [0; 32],
+        });
+
+        assert_eq!(doc.try_find_attachment("Mirror").unwrap(), 0);
+        assert!(doc.try_find_attachment("Exhibit").is_err());
+
+        stub_clock(1_650_000_000);
+
+        assert!(!doc.has_signatures());
+        doc.try_sign(&participant, 0, &participant, None, None)
+            .unwrap();
+        assert!(doc.has_signatures());
+    }
+
+    #[test]
+    fn draft_participant_reset() {
+        let (a, b, c) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let mut doc = new_document(&[a, b], &["Terms", "Privacy"]);
+
+        stub_clock(1_650_000_000);
+
+        doc.try_sign(&a, 1, &a, Some([1; 32]), None).unwrap();
+        doc.reset_signatures();
+
+        assert!(!doc.has_signatures());
+        assert_eq!(doc.signature_timestamps.len(), 4);
+        assert!(doc.memos.iter().all(|m| *m == [0; 32]));
+
+        doc.reset_participants(
+            vec![c],
+            DocumentSettings {
+                draft: true,
+                ..DocumentSettings::default()
+            },
+        );
+
+        assert!(doc.is_draft());
+        assert_eq!(doc.participants, vec![c]);
+        assert_eq!(doc.signature_timestamps, vec![0, 0]);
+        assert_eq!(doc.signers.len(), 2);
+        assert_eq!(doc.acceptance_timestamps, vec![0]);
+    }
+
+    #[test]
+    fn draft_settings_reset() {
+        let (a, b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut doc = new_document(&[a, b], &[]);
+        doc.deadlines = vec![1_700_000_000, 0];
+        doc.weights = vec![2, 1];
+        doc.groups = vec![SignerGroup {
+            name: "Board".into(),
+            threshold: 1,
+            members: vec![0],
+        }];
+        doc.acceptance_timestamps = vec![1_650_000_000, 0];
+
+        doc.reset_settings(DocumentSettings {
+            requires_acceptance: true,
+            draft: true,
+            ..DocumentSettings::default()
+        });
+
+        assert!(doc.settings.requires_acceptance);
+        assert_eq!(doc.participants, vec![a, b]);
+        assert_eq!(doc.signature_timestamps, vec![0, 0]);
+        assert_eq!(doc.deadlines, vec![1_700_000_000, 0]);
+        assert_eq!(doc.weights, vec![2, 1]);
+        assert_eq!(doc.groups.len(), 1);
+        assert_eq!(doc.acceptance_timestamps, vec![1_650_000_000, 0]);
+    }
+
+    #[test]
+    fn participant_deadlines() {
+        let (a, b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut doc = new_document(&[a, b], &[]);
+        doc.deadlines = vec![1_650_000_000, 0];
+
+        assert!(doc.try_extend_deadline(&a, 1_649_000_000).is_err());
+        assert!(doc.try_extend_deadline(&b, 1_700_000_000).is_err());
+
+        stub_clock(1_650_000_001);
+
+        assert!(doc.try_sign(&a, 0, &a, None, None).is_err());
+        assert_eq!(doc.overdue_participants(1_650_000_001), vec![a]);
+
+        doc.try_extend_deadline(&a, 1_660_000_000).unwrap();
+        assert!(doc.overdue_participants(1_650_000_001).is_empty());
+
+        doc.try_sign(&a, 0, &a, None, None).unwrap();
+        doc.try_sign(&b, 0, &b, None, None).unwrap();
+        assert!(doc.overdue_participants(1_670_000_000).is_empty());
+    }
+
+    #[test]
+    fn weighted_quorum() {
+        let (a, b, c) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let mut doc = new_document(&[a, b, c], &["Terms", "Annex"]);
+        doc.weights = vec![60, 30, 10];
+        doc.settings.required_weight = 70;
+
+        assert!(doc.is_weighted());
+        assert_eq!(doc.total_weight(), 100);
+
+        stub_clock(1_650_000_000);
+
+        doc.try_sign(&a, 0, &a, None, None).unwrap();
+        doc.try_sign(&a, 1, &a, None, None).unwrap();
+        doc.try_sign(&b, 0, &b, None, None).unwrap();
+        assert_eq!(doc.signed_weight(), 60);
+        assert!(!doc.has_all_signatures());
+
+        doc.try_sign(&c, 0, &c, None, None).unwrap();
+        doc.try_sign(&c, 1, &c, None, None).unwrap();
+        assert_eq!(doc.signed_weight(), 70);
+        assert!(doc.has_all_signatures());
+    }
+
+    #[test]
+    fn finalization_time_lock() {
+        let a = Pubkey::new_unique();
+        let mut doc = new_document(&[a], &[]);
+        doc.settings.not_before = Some(1_650_000_000);
+
+        assert!(!doc.can_finalize_at(1_649_999_999));
+        assert!(doc.can_finalize_at(1_650_000_000));
+
+        stub_clock(1_649_000_000);
+        assert!(doc.try_finalize().is_err());
+        assert!(!doc.is_finalized());
+
+        stub_clock(1_650_000_001);
+        doc.try_finalize().unwrap();
+        assert_eq!(doc.finalization_timestamp, 1_650_000_001);
+    }
+
+    #[test]
+    fn signer_group_quorums() {
+        let partners = (0..6).map(|_| Pubkey::new_unique()).collect::<Vec<_>>();
+        let mut doc = new_document(&partners, &[]);
+        doc.groups = vec![
+            SignerGroup {
+                name: "Firm A".into(),
+                threshold: 2,
+                members: vec![0, 1, 2],
+            },
+            SignerGroup {
+                name: "Firm B".into(),
+                threshold: 1,
+                members: vec![3, 4],
+            },
+        ];
+
+        assert_eq!(doc.try_find_group(1).unwrap().name, "Firm A");
+        assert!(doc.try_find_group(5).is_none());
+
+        stub_clock(1_650_000_000);
+
+        for p in [partners[0], partners[2], partners[4]] {
+            doc.try_sign(&p, 0, &p, None, None).unwrap();
+        }
+        assert_eq!(doc.group_signatures(&doc.groups[0]), 2);
+        assert!(!doc.has_all_signatures());
+
+        let ungrouped = partners[5];
+        doc.try_sign(&ungrouped, 0, &ungrouped, None, None).unwrap();
+        assert!(doc.has_all_signatures());
+    }
+
+    #[test]
+    fn eth_participant_slots() {
+        let wallet = Pubkey::new_unique();
+        let eth = [0x11; 20];
+
+        let mut doc = new_document(&[wallet], &["Terms", "Exhibit"]);
+        doc.eth_participants.push(eth);
+        doc.signature_timestamps.resize(4, 0);
+        doc.signers.resize(4, Pubkey::default());
+        doc.memos.resize(4, [0; 32]);
+
+        assert_eq!(doc.try_find_eth_slot(&eth, 1).unwrap(), 3);
+        assert!(doc.try_find_eth_slot(&[0x22; 20], 0).is_err());
+
+        let added = Pubkey::new_unique();
+        doc.push_participant(added);
+        assert_eq!(doc.try_find_slot(&added, 1).unwrap(), 3);
+        assert_eq!(doc.try_find_eth_slot(&eth, 0).unwrap(), 4);
+
+        stub_clock(1_650_000_000);
+
+        let submitter = Pubkey::new_unique();
+        doc.try_sign_eth(&eth, 0, &submitter).unwrap();
+        assert!(doc.try_sign_eth(&eth, 0, &submitter).is_err());
+        assert_eq!(doc.signature_timestamps, vec![0, 0, 0, 0, 1_650_000_000, 0]);
+        assert_eq!(doc.signers[4], submitter);
+    }
+
+    #[test]
+    fn eth_message_encoding() {
+        let doc = new_document(&[], &[]);
+        let address = Pubkey::new_unique();
+
+        let message = doc.eth_message(&address, 2);
+        assert_eq!(&message[..28], b"\x19Ethereum Signed Message:\n65");
+        assert_eq!(&message[28..60], address.as_ref());
+        assert_eq!(&message[60..92], &doc.content_hash());
+        assert_eq!(message[92], 2);
+    }
+
+    #[test]
+    fn merkle_participant_signing() {
+        let members = (0..5).map(|_| Pubkey::new_unique()).collect::<Vec<_>>();
+        let tree = merkle::MerkleTree::new(&members);
+
+        let mut doc = new_document(&[], &["Resolution", "Minutes"]);
+        doc.settings.participant_root = Some(tree.root());
+        doc.settings.participant_count = members.len() as u32;
+        doc.signed_bitmap = vec![0; Document::bitmap_size(members.len(), 2)];
+        assert_eq!(doc.signed_bitmap.len(), 2);
+
+        let proof = |i: usize| ParticipantProof {
+            index: i as u32,
+            path: tree.proof(i),
+        };
+
+        assert!(!doc.try_has_signed(&members[3], 1, Some(&proof(3))).unwrap());
+        assert!(doc.try_has_signed(&members[3], 1, Some(&proof(2))).is_err());
+        assert!(doc.try_has_signed(&members[3], 2, Some(&proof(3))).is_err());
+        assert!(doc.try_has_signed(&members[3], 1, None).is_err());
+
+        assert_eq!(
+            error_code_number(
+                doc.try_sign(&members[3], 1, &members[3], Some([1; 32]), Some(&proof(3)))
+                    .unwrap_err()
+            ),
+            u32::from(ErrorCode::MemoNotAllowedWithProof),
+        );
+        assert_eq!(doc.signed_bitmap, vec![0, 0]);
+
+        doc.try_sign(&members[3], 1, &members[3], None, Some(&proof(3)))
+            .unwrap();
+        assert!(doc.try_has_signed(&members[3], 1, Some(&proof(3))).unwrap());
+        assert_eq!(doc.signed_bitmap, vec![0b1000_0000, 0]);
+        assert!(!doc.has_all_signatures());
+
+        for (i, m) in members.iter().enumerate() {
+            for section in 0..2 {
+                doc.try_sign(m, section, m, None, Some(&proof(i))).unwrap();
+            }
+        }
+        assert!(doc.has_all_signatures());
+    }
+
+    #[test]
+    fn memo_display() {
+        let mut memo = [0; 32];
+        assert_eq!(display_memo(&memo), None);
+
+        memo[..7].copy_from_slice(b"clause4");
+        assert_eq!(display_memo(&memo), Some("clause4".into()));
+
+        let hashed = [0xff; 32];
+        assert_eq!(display_memo(&hashed), Some("ff".repeat(32)));
+    }
+
+    #[test]
+    fn document_serialization() {
+        assert_ser_tokens(
+            &Document {
+                version: Document::VERSION,
+                authority: Pubkey::default(),
+                title: "Test".into(),
+                uri: "https://arweave.net/abc1234567890".into(),
+                attachments: vec![Attachment {
+                    label: "Exhibit A".into(),
+                    uri: "ipfs://bafybeigdyr".into(),
+                    content_hash: [0xab; 32],
+                }],
+                created_at: 0,
+                settings: DocumentSettings {
+                    requires_acceptance: true,
+                    not_before: Some(1_700_000_000),
+                    ..DocumentSettings::default()
+                },
+                sections: vec![],
+                prerequisites: vec![],
+                bundle: Some(Pubkey::default()),
+                participants: vec![Pubkey::default()],
+                eth_participants: vec![[0xab; 20]],
+                signature_timestamps: vec![0, 0],
+                deadlines: vec![1_650_000_000],
+                weights: vec![1],
+                groups: vec![SignerGroup {
+                    name: "Firm A".into(),
+                    threshold: 1,
+                    members: vec![0],
+                }],
+                signers: vec![Pubkey::default(); 2],
+                memos: vec![[0; 32]; 2],
+                signed_bitmap: vec![],
+                acceptance_timestamps: vec![0],
+                receipts_claimed: 0,
                 finalization_timestamp: 0,
                 bump: [0],
             },
             &[
                 Token::Struct {
                     name: "Document",
-                    len: 6,
+                    len: 21,
                 },
+                Token::Str("version"),
+                Token::U8(1),
                 Token::Str("authority"),
                 Token::Str("11111111111111111111111111111111"),
                 Token::Str("title"),
                 Token::Str("Test"),
+                Token::Str("attachments"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "Attachment",
+                    len: 3,
+                },
+                Token::Str("label"),
+                Token::Str("Exhibit A"),
+                Token::Str("uri"),
+                Token::Str("ipfs://bafybeigdyr"),
+                Token::Str("contentHash"),
+                Token::Str(Box::leak("ab".repeat(32).into_boxed_str())),
+                Token::StructEnd,
+                Token::SeqEnd,
                 Token::Str("createdAt"),
                 Token::U64(0),
+                Token::Str("settings"),
+                Token::Struct {
+                    name: "DocumentSettings",
+                    len: 6,
+                },
+                Token::Str("requiresAcceptance"),
+                Token::Bool(true),
+                Token::Str("participantRoot"),
+                Token::None,
+                Token::Str("participantCount"),
+                Token::U32(0),
+                Token::Str("draft"),
+                Token::Bool(false),
+                Token::Str("requiredWeight"),
+                Token::U64(0),
+                Token::Str("notBefore"),
+                Token::Some,
+                Token::U64(1_700_000_000),
+                Token::StructEnd,
+                Token::Str("sections"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::Str("prerequisites"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::Str("bundle"),
+                Token::Some,
+                Token::Str("11111111111111111111111111111111"),
                 Token::Str("participants"),
                 Token::Seq { len: Some(1) },
                 Token::Str("11111111111111111111111111111111"),
                 Token::SeqEnd,
-                Token::Str("signatureTimestamps"),
+                Token::Str("ethParticipants"),
                 Token::Seq { len: Some(1) },
+                Token::Str("0xabababababababababababababababababababab"),
+                Token::SeqEnd,
+                Token::Str("signatureTimestamps"),
+                Token::Seq { len: Some(2) },
+                Token::U64(0),
                 Token::U64(0),
                 Token::SeqEnd,
+                Token::Str("deadlines"),
+                Token::Seq { len: Some(1) },
+                Token::U64(1_650_000_000),
+                Token::SeqEnd,
+                Token::Str("weights"),
+                Token::Seq { len: Some(1) },
+                Token::U64(1),
+                Token::SeqEnd,
+                Token::Str("groups"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "SignerGroup",
+                    len: 3,
+                },
+                Token::Str("name"),
+                Token::Str("Firm A"),
+                Token::Str("threshold"),
+                Token::U8(1),
+                Token::Str("members"),
+                Token::Seq { len: Some(1) },
+                Token::U8(0),
+                Token::SeqEnd,
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::Str("signers"),
+                Token::Seq { len: Some(2) },
+                Token::Str("11111111111111111111111111111111"),
+                Token::Str("11111111111111111111111111111111"),
+                Token::SeqEnd,
+                Token::Str("memos"),
+                Token::Seq { len: Some(2) },
+                Token::None,
+                Token::None,
+                Token::SeqEnd,
+                Token::Str("signedBitmap"),
+                Token::Str(""),
+                Token::Str("pendingInvitations"),
+                Token::Seq { len: Some(1) },
+                Token::Str("11111111111111111111111111111111"),
+                Token::SeqEnd,
+                Token::Str("receiptsClaimed"),
+                Token::U32(0),
                 Token::Str("finalizationTimestamp"),
                 Token::U64(0),
                 Token::StructEnd,
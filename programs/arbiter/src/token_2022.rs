@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+
+declare_id!("TokenzQdBNbLqP5VEhdkAS6EhFL5wPcHRsNPcx1jDUz");
+
+/// The associated token account program that derives and creates
+/// the token accounts of wallets for Token-2022 mints.
+pub mod associated_token {
+    use anchor_lang::prelude::*;
+
+    declare_id!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+}
+
+/// The byte size of a Token-2022 mint with only the non-transferable extension,
+/// which is the base mint padded to the size of a token account followed by the
+/// account type byte and the type-length header of the empty extension.
+pub const NON_TRANSFERABLE_MINT_SPACE: usize = 165 + 1 + 4;
+
+/// The Token-2022 instruction discriminators used by the program.
+const INITIALIZE_MINT_2: u8 = 20;
+const INITIALIZE_NON_TRANSFERABLE_MINT: u8 = 32;
+const MINT_TO: u8 = 7;
+
+/// Returns the address of the associated token account of the
+/// argued wallet for a mint owned by the Token-2022 program.
+pub fn get_associated_token_address(wallet: &Pubkey, mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[wallet.as_ref(), ID.as_ref(), mint.as_ref()],
+        &associated_token::ID,
+    )
+    .0
+}
+
+/// Creates an instruction to enable the non-transferable extension on an uninitialized mint.
+pub fn initialize_non_transferable_mint(mint: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: ID,
+        accounts: vec![AccountMeta::new(*mint, false)],
+        data: vec![INITIALIZE_NON_TRANSFERABLE_MINT],
+    }
+}
+
+/// Creates an instruction to initialize a zero decimal mint with
+/// the argued mint authority and no freeze authority.
+pub fn initialize_mint2(mint: &Pubkey, mint_authority: &Pubkey) -> Instruction {
+    let mut data = vec![INITIALIZE_MINT_2, 0];
+    data.extend_from_slice(mint_authority.as_ref());
+    data.push(0);
+
+    Instruction {
+        program_id: ID,
+        accounts: vec![AccountMeta::new(*mint, false)],
+        data,
+    }
+}
+
+/// Creates an instruction to mint the amount of tokens to the destination token account.
+pub fn mint_to(
+    mint: &Pubkey,
+    destination: &Pubkey,
+    authority: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let mut data = vec![MINT_TO];
+    data.extend_from_slice(&amount.to_le_bytes());
+
+    Instruction {
+        program_id: ID,
+        accounts: vec![
+            AccountMeta::new(*mint, false),
+            AccountMeta::new(*destination, false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+        data,
+    }
+}
+
+/// Creates an instruction to create the associated token account of the
+/// wallet for the Token-2022 mint, which inherits the mint's extensions.
+pub fn create_associated_token_account(
+    payer: &Pubkey,
+    wallet: &Pubkey,
+    mint: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: associated_token::ID,
+        accounts: vec![
+            AccountMeta::new(*payer, true),
+            AccountMeta::new(get_associated_token_address(wallet, mint), false),
+            AccountMeta::new_readonly(*wallet, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new_readonly(System::id(), false),
+            AccountMeta::new_readonly(ID, false),
+        ],
+        data: vec![],
+    }
+}
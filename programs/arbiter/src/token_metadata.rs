@@ -0,0 +1,205 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+
+declare_id!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+/// The static seed of the metadata account PDAs of the token metadata program.
+const METADATA: &[u8] = b"metadata";
+
+/// The maximum byte length of a metadata name.
+pub const MAX_NAME_LENGTH: usize = 32;
+
+/// The maximum byte length of a metadata URI.
+pub const MAX_URI_LENGTH: usize = 200;
+
+/// The maximum number of creators that can be listed in metadata.
+pub const MAX_CREATOR_LIMIT: usize = 5;
+
+/// The symbol given to the metadata of document receipts.
+pub const RECEIPT_SYMBOL: &str = "ARBITER";
+
+/// The token metadata instruction discriminators used by the program.
+const SIGN_METADATA: u8 = 7;
+const CREATE_METADATA_ACCOUNT_V3: u8 = 33;
+
+/// A creator listed in the metadata of a mint.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "cli"), derive(Debug))]
+pub struct Creator {
+    pub address: Pubkey,
+    pub verified: bool,
+    pub share: u8,
+}
+
+/// The borsh layout of the `DataV2` metadata argument without
+/// the collection and uses, which are always serialized as `None`.
+#[derive(AnchorSerialize)]
+struct DataV2<'a> {
+    name: &'a str,
+    symbol: &'a str,
+    uri: &'a str,
+    seller_fee_basis_points: u16,
+    creators: Option<&'a [Creator]>,
+    collection: Option<()>,
+    uses: Option<()>,
+}
+
+/// Returns the address of the metadata account of the argued mint.
+pub fn find_metadata_address(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[METADATA, ID.as_ref(), mint.as_ref()], &ID).0
+}
+
+/// Returns the longest prefix of the argued name that fits
+/// within the metadata name length on a character boundary.
+pub fn truncate_name(name: &str) -> &str {
+    let mut end = name.len().min(MAX_NAME_LENGTH);
+    while !name.is_char_boundary(end) {
+        end -= 1;
+    }
+    &name[..end]
+}
+
+/// Returns the creators of the metadata of a document receipt, which are the verified
+/// `authority` holding the entire share followed by as many of the unverified `signers`
+/// as fit within the creator limit, without any duplicates.
+pub fn receipt_creators(
+    authority: Pubkey,
+    signers: impl IntoIterator<Item = Pubkey>,
+) -> Vec<Creator> {
+    let mut creators = vec![Creator {
+        address: authority,
+        verified: true,
+        share: 100,
+    }];
+
+    for address in signers {
+        if creators.len() == MAX_CREATOR_LIMIT {
+            break;
+        }
+
+        if creators.iter().all(|c| c.address != address) {
+            creators.push(Creator {
+                address,
+                verified: false,
+                share: 0,
+            });
+        }
+    }
+
+    creators
+}
+
+/// Creates an instruction to create the immutable metadata account of the mint
+/// with the argued name, symbol, URI and creators and no seller fee.
+#[allow(clippy::too_many_arguments)]
+pub fn create_metadata_account_v3(
+    metadata: &Pubkey,
+    mint: &Pubkey,
+    mint_authority: &Pubkey,
+    payer: &Pubkey,
+    update_authority: &Pubkey,
+    name: &str,
+    symbol: &str,
+    uri: &str,
+    creators: &[Creator],
+) -> Instruction {
+    let mut data = vec![CREATE_METADATA_ACCOUNT_V3];
+    DataV2 {
+        name,
+        symbol,
+        uri,
+        seller_fee_basis_points: 0,
+        creators: Some(creators),
+        collection: None,
+        uses: None,
+    }
+    .serialize(&mut data)
+    .unwrap();
+
+    // The metadata is immutable and has no collection details.
+    data.extend_from_slice(&[0, 0]);
+
+    Instruction {
+        program_id: ID,
+        accounts: vec![
+            AccountMeta::new(*metadata, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new_readonly(*mint_authority, true),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(*update_authority, true),
+            AccountMeta::new_readonly(System::id(), false),
+        ],
+        data,
+    }
+}
+
+/// Creates an instruction for a listed creator to verify themselves in the metadata.
+pub fn sign_metadata(metadata: &Pubkey, creator: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: ID,
+        accounts: vec![
+            AccountMeta::new(*metadata, false),
+            AccountMeta::new_readonly(*creator, true),
+        ],
+        data: vec![SIGN_METADATA],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_are_truncated_on_char_boundaries() {
+        assert_eq!(truncate_name("Short Title"), "Short Title");
+        assert_eq!(truncate_name(&"a".repeat(40)), "a".repeat(32));
+
+        let name = format!("{}é", "a".repeat(31));
+        assert_eq!(truncate_name(&name), "a".repeat(31));
+    }
+
+    #[test]
+    fn receipt_creators_are_capped_and_unique() {
+        let authority = Pubkey::new_unique();
+        let signers: Vec<Pubkey> = (0..6).map(|_| Pubkey::new_unique()).collect();
+
+        let creators = receipt_creators(authority, [signers[0], signers[0], signers[1]]);
+        assert_eq!(creators.len(), 3);
+        assert!(creators[0].verified && creators[0].share == 100);
+        assert!(creators[1..].iter().all(|c| !c.verified && c.share == 0));
+
+        let creators = receipt_creators(authority, signers.clone());
+        assert_eq!(creators.len(), MAX_CREATOR_LIMIT);
+        assert_eq!(creators[4].address, signers[3]);
+    }
+
+    #[test]
+    fn create_metadata_account_v3_data_layout() {
+        let creators = receipt_creators(Pubkey::new_unique(), []);
+        let ix = create_metadata_account_v3(
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            "Doc",
+            RECEIPT_SYMBOL,
+            "ar://x",
+            &creators,
+        );
+
+        let mut expected = vec![CREATE_METADATA_ACCOUNT_V3];
+        expected.extend_from_slice(&[3, 0, 0, 0]);
+        expected.extend_from_slice(b"Doc");
+        expected.extend_from_slice(&[7, 0, 0, 0]);
+        expected.extend_from_slice(b"ARBITER");
+        expected.extend_from_slice(&[6, 0, 0, 0]);
+        expected.extend_from_slice(b"ar://x");
+        expected.extend_from_slice(&[0, 0, 1, 1, 0, 0, 0]);
+        expected.extend_from_slice(creators[0].address.as_ref());
+        expected.extend_from_slice(&[1, 100, 0, 0, 0, 0]);
+
+        assert_eq!(ix.data, expected);
+        assert_eq!(ix.accounts.len(), 6);
+    }
+}
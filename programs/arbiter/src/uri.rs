@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+
+/// The URI schemes that documents are permitted to be stored with.
+pub const ALLOWED_SCHEMES: &[&str] = &["ar://", "ipfs://", "https://"];
+
+/// Validates that the argued URI is non-empty, within the `max_length`, free
+/// of whitespace and control characters and begins with an allowed scheme
+/// that is followed by a non-empty location.
+pub fn validate(uri: &str, max_length: usize) -> Result<()> {
+    require!(!uri.is_empty(), ErrorCode::EmptyDocumentUri);
+    require_gte!(max_length, uri.len(), ErrorCode::DocumentUriTooLong);
+
+    require!(
+        !uri.chars().any(|c| c.is_control() || c.is_whitespace()),
+        ErrorCode::DocumentUriHasInvalidCharacters,
+    );
+
+    let scheme = ALLOWED_SCHEMES
+        .iter()
+        .find(|s| starts_with_ignore_case(uri, s))
+        .ok_or(ErrorCode::InvalidDocumentUriScheme)?;
+
+    require!(uri.len() > scheme.len(), ErrorCode::InvalidDocumentUri);
+
+    Ok(())
+}
+
+/// Returns the argued URI with its scheme lowercased so that equivalent
+/// locations are always stored identically. The URI is expected to have
+/// already been checked by `validate`.
+pub fn normalize(uri: &str) -> String {
+    match ALLOWED_SCHEMES
+        .iter()
+        .find(|s| starts_with_ignore_case(uri, s))
+    {
+        Some(scheme) => format!("{}{}", scheme, &uri[scheme.len()..]),
+        None => uri.to_string(),
+    }
+}
+
+/// Whether the argued string begins with the `prefix` regardless of ASCII case.
+fn starts_with_ignore_case(s: &str, prefix: &str) -> bool {
+    s.len() >= prefix.len() && s.as_bytes()[..prefix.len()].eq_ignore_ascii_case(prefix.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::error_code_number;
+
+    #[test]
+    fn allowed_schemes_are_valid() {
+        assert!(validate("ar://abc123", 64).is_ok());
+        assert!(validate("ipfs://bafybeigdyr", 64).is_ok());
+        assert!(validate("https://arweave.net/abc123", 64).is_ok());
+        assert!(validate("HTTPS://arweave.net/abc123", 64).is_ok());
+    }
+
+    #[test]
+    fn invalid_uris_are_rejected() {
+        let rejects = |uri: &str, max_len: usize, code: ErrorCode| {
+            let err = validate(uri, max_len).unwrap_err();
+            assert_eq!(error_code_number(err), u32::from(code), "{:?}", uri);
+        };
+
+        rejects("", 64, ErrorCode::EmptyDocumentUri);
+        rejects(
+            "https://arweave.net/abc123",
+            10,
+            ErrorCode::DocumentUriTooLong,
+        );
+        rejects(
+            "htps://arweave.net/abc123",
+            64,
+            ErrorCode::InvalidDocumentUriScheme,
+        );
+        rejects(
+            "http://arweave.net/abc123",
+            64,
+            ErrorCode::InvalidDocumentUriScheme,
+        );
+        rejects(
+            " ar://abc123",
+            64,
+            ErrorCode::DocumentUriHasInvalidCharacters,
+        );
+        rejects(
+            "ar://abc\u{7}123",
+            64,
+            ErrorCode::DocumentUriHasInvalidCharacters,
+        );
+        rejects("ipfs://", 64, ErrorCode::InvalidDocumentUri);
+    }
+
+    #[test]
+    fn schemes_are_normalized() {
+        assert_eq!(normalize("IPFS://bafyBEIG"), "ipfs://bafyBEIG");
+        assert_eq!(normalize("Https://Arweave.net/X"), "https://Arweave.net/X");
+        assert_eq!(normalize("ar://abc123"), "ar://abc123");
+    }
+}
@@ -0,0 +1,323 @@
+//! Tests of the receipt token metadata against local copies of the token metadata,
+//! Token-2022 and associated token account programs, which are loaded from the
+//! `tests/fixtures` shared objects dumped by `npm run fixtures`.
+
+use std::path::Path;
+
+use anchor_lang::{AccountDeserialize, AccountSerialize, InstructionData, ToAccountMetas};
+use arbiter::state::{Config, ConfigLimits, Document, DocumentSettings};
+use arbiter::{seeds, token_2022, token_metadata};
+use solana_program_test::{processor, ProgramTest, ProgramTestContext};
+use solana_sdk::account::Account;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::rent::Rent;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::system_program;
+use solana_sdk::transaction::Transaction;
+
+/// The names of the program fixtures loaded by `ProgramTest::add_program`.
+const FIXTURES: [&str; 3] = [
+    "mpl_token_metadata",
+    "spl_token_2022",
+    "spl_associated_token_account",
+];
+
+const TITLE: &str = "A Receipt Agreement With A Title Beyond The Name Limit";
+const URI: &str = "https://arweave.net/receipt";
+
+/// The name, URI and creators decoded from a token metadata account.
+struct Metadata {
+    update_authority: Pubkey,
+    mint: Pubkey,
+    name: String,
+    uri: String,
+    creators: Vec<(Pubkey, bool)>,
+}
+
+impl Metadata {
+    /// Decodes the borsh encoded metadata account data, whose
+    /// strings are padded with null bytes to their maximum length.
+    fn decode(data: &[u8]) -> Self {
+        let mut cursor = &data[1..];
+
+        let update_authority = read_pubkey(&mut cursor);
+        let mint = read_pubkey(&mut cursor);
+        let name = read_string(&mut cursor);
+        let _symbol = read_string(&mut cursor);
+        let uri = read_string(&mut cursor);
+        let _seller_fee_basis_points = take(&mut cursor, 2);
+
+        let mut creators = vec![];
+        if take(&mut cursor, 1)[0] == 1 {
+            for _ in 0..read_u32(&mut cursor) {
+                let address = read_pubkey(&mut cursor);
+                let verified = take(&mut cursor, 2)[0] == 1;
+                creators.push((address, verified));
+            }
+        }
+
+        Self {
+            update_authority,
+            mint,
+            name,
+            uri,
+            creators,
+        }
+    }
+}
+
+/// Splits the next `n` bytes off of the front of the cursor.
+fn take<'a>(cursor: &mut &'a [u8], n: usize) -> &'a [u8] {
+    let (head, tail) = cursor.split_at(n);
+    *cursor = tail;
+    head
+}
+
+fn read_u32(cursor: &mut &[u8]) -> u32 {
+    u32::from_le_bytes(take(cursor, 4).try_into().unwrap())
+}
+
+fn read_pubkey(cursor: &mut &[u8]) -> Pubkey {
+    Pubkey::new(take(cursor, 32))
+}
+
+fn read_string(cursor: &mut &[u8]) -> String {
+    let len = read_u32(cursor) as usize;
+    String::from_utf8(take(cursor, len).to_vec())
+        .unwrap()
+        .trim_end_matches('\0')
+        .to_string()
+}
+
+/// Returns the serialized account data of a program account with its discriminator.
+fn program_account<T: AccountSerialize>(account: &T) -> Account {
+    let mut data = vec![];
+    account.try_serialize(&mut data).unwrap();
+
+    Account {
+        lamports: Rent::default().minimum_balance(data.len()),
+        data,
+        owner: arbiter::ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+/// Starts a test validator with the fixture programs loaded, an unpaused config and a
+/// finalized document that every argued participant has signed, except for `unsigned`.
+async fn setup(participants: &[Pubkey], unsigned: &[Pubkey]) -> (ProgramTestContext, Pubkey) {
+    for name in FIXTURES {
+        assert!(
+            Path::new("tests/fixtures")
+                .join(format!("{}.so", name))
+                .exists(),
+            "the {} fixture is missing, dump it with `npm run fixtures`",
+            name,
+        );
+    }
+
+    let mut program_test = ProgramTest::new("arbiter", arbiter::ID, processor!(arbiter::entry));
+    program_test.add_program("mpl_token_metadata", token_metadata::ID, None);
+    program_test.add_program("spl_token_2022", token_2022::ID, None);
+    program_test.add_program(
+        "spl_associated_token_account",
+        token_2022::associated_token::ID,
+        None,
+    );
+
+    let (config, config_bump) = Pubkey::find_program_address(&[seeds::CONFIG], &arbiter::ID);
+    program_test.add_account(
+        config,
+        program_account(&Config {
+            admin: Pubkey::new_unique(),
+            limits: ConfigLimits {
+                max_title_length: 64,
+                max_uri_length: 128,
+                max_participants: 10,
+                max_clerk_limit: 10,
+            },
+            paused: false,
+            treasury: Pubkey::new_unique(),
+            document_fee: 0,
+            bump: [config_bump],
+        }),
+    );
+
+    let authority = Pubkey::new_unique();
+    let (document, document_bump) = Pubkey::find_program_address(
+        &[
+            seeds::DOCUMENT,
+            authority.as_ref(),
+            Document::title_seed(TITLE),
+        ],
+        &arbiter::ID,
+    );
+
+    let mut doc = Document {
+        version: Document::VERSION,
+        authority,
+        title: TITLE.into(),
+        uri: URI.into(),
+        attachments: vec![],
+        created_at: 1,
+        settings: DocumentSettings::default(),
+        sections: vec![],
+        prerequisites: vec![],
+        bundle: None,
+        participants: vec![],
+        eth_participants: vec![],
+        signature_timestamps: vec![],
+        deadlines: vec![],
+        weights: vec![],
+        groups: vec![],
+        signers: vec![],
+        memos: vec![],
+        signed_bitmap: vec![],
+        acceptance_timestamps: vec![],
+        receipts_claimed: 0,
+        finalization_timestamp: 2,
+        bump: [document_bump],
+    };
+
+    for (i, p) in participants.iter().enumerate() {
+        doc.push_participant(*p);
+        if !unsigned.contains(p) {
+            doc.signature_timestamps[i] = 1;
+            doc.signers[i] = *p;
+        }
+    }
+
+    program_test.add_account(document, program_account(&doc));
+
+    (program_test.start_with_context().await, document)
+}
+
+/// Returns the `claim_receipt` instruction of the participant and its metadata
+/// address, with the participant as a signer when they `sign` for the claim.
+fn claim_receipt(
+    payer: &Pubkey,
+    participant: &Pubkey,
+    document: &Pubkey,
+    sign: bool,
+) -> (Instruction, Pubkey) {
+    let mint = Pubkey::find_program_address(
+        &[seeds::MINT, document.as_ref(), participant.as_ref()],
+        &arbiter::ID,
+    )
+    .0;
+    let metadata = token_metadata::find_metadata_address(&mint);
+
+    let mut ix = Instruction {
+        program_id: arbiter::ID,
+        accounts: arbiter::accounts::ClaimReceipt {
+            payer: *payer,
+            participant: *participant,
+            config: Pubkey::find_program_address(&[seeds::CONFIG], &arbiter::ID).0,
+            document: *document,
+            mint,
+            receipt: token_2022::get_associated_token_address(participant, &mint),
+            metadata,
+            token_program: token_2022::ID,
+            associated_token_program: token_2022::associated_token::ID,
+            token_metadata_program: token_metadata::ID,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: arbiter::instruction::ClaimReceipt {}.data(),
+    };
+
+    ix.accounts[1].is_signer = sign;
+    (ix, metadata)
+}
+
+/// Processes the instruction signed by the context payer and the other signers.
+async fn process(ctx: &mut ProgramTestContext, ix: Instruction, signers: &[&Keypair]) {
+    let mut all_signers = vec![&ctx.payer];
+    all_signers.extend_from_slice(signers);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &all_signers,
+        ctx.last_blockhash,
+    );
+
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+}
+
+#[tokio::test]
+async fn receipt_metadata_describes_the_document() {
+    let participant = Keypair::new();
+    let others: Vec<Pubkey> = (0..5).map(|_| Pubkey::new_unique()).collect();
+    let unsigned = others[4];
+
+    let mut participants = others.clone();
+    participants.insert(1, participant.pubkey());
+
+    let (mut ctx, document) = setup(&participants, &[unsigned]).await;
+    let (ix, metadata) = claim_receipt(&ctx.payer.pubkey(), &participant.pubkey(), &document, true);
+    process(&mut ctx, ix, &[&participant]).await;
+
+    let account = ctx
+        .banks_client
+        .get_account(metadata)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(account.owner, token_metadata::ID);
+
+    let data = Metadata::decode(&account.data);
+    assert_eq!(data.update_authority, document);
+    assert_eq!(data.name, token_metadata::truncate_name(TITLE));
+    assert_eq!(data.uri, URI);
+
+    // The claiming participant follows the document and verifies themselves, while the
+    // remaining signers fill the creator limit unverified and the non-signer is left out.
+    assert_eq!(
+        data.creators,
+        vec![
+            (document, true),
+            (participant.pubkey(), true),
+            (others[0], false),
+            (others[1], false),
+            (others[2], false),
+        ],
+    );
+
+    let account = ctx
+        .banks_client
+        .get_account(document)
+        .await
+        .unwrap()
+        .unwrap();
+    let doc = Document::try_deserialize(&mut &account.data[..]).unwrap();
+    assert_eq!(doc.receipts_claimed, 1);
+}
+
+#[tokio::test]
+async fn participant_is_unverified_when_another_wallet_claims() {
+    let participant = Pubkey::new_unique();
+
+    let (mut ctx, document) = setup(&[participant], &[]).await;
+    let (ix, metadata) = claim_receipt(&ctx.payer.pubkey(), &participant, &document, false);
+    process(&mut ctx, ix, &[]).await;
+
+    let account = ctx
+        .banks_client
+        .get_account(metadata)
+        .await
+        .unwrap()
+        .unwrap();
+
+    let data = Metadata::decode(&account.data);
+    assert_eq!(
+        data.mint,
+        Pubkey::find_program_address(
+            &[seeds::MINT, document.as_ref(), participant.as_ref()],
+            &arbiter::ID,
+        )
+        .0,
+    );
+    assert_eq!(data.creators, vec![(document, true), (participant, false)]);
+}
@@ -0,0 +1,83 @@
+//! A minimal stand-in for a governance or treasury program that holds a
+//! program derived address as an Arbiter document participant and signs
+//! on its behalf through a cross-program invocation of `add_signature`.
+//!
+//! This program is only deployed to the local validator for the tests.
+
+use anchor_lang::prelude::*;
+use arbiter::cpi::accounts::AddSignature;
+use arbiter::program::Arbiter;
+
+declare_id!("Eqh8btKFhp6Ka7MUk1zYScPBrvxSCeyw4pq6tct3bxQt");
+
+/// The static seed for the governance authority PDA of a realm.
+pub const GOVERNANCE: &[u8] = b"governance";
+
+#[program]
+pub mod mock_governance {
+    use super::*;
+
+    pub fn sign_document(
+        ctx: Context<SignDocument>,
+        section: u8,
+        memo: Option<[u8; 32]>,
+    ) -> Result<()> {
+        let SignDocument {
+            realm,
+            governance,
+            config,
+            document,
+            arbiter_program,
+        } = ctx.accounts;
+
+        let realm_key = realm.key();
+        let bump = [*ctx.bumps.get("governance").unwrap()];
+        let signer_seeds: &[&[u8]] = &[GOVERNANCE, realm_key.as_ref(), &bump];
+
+        arbiter::cpi::add_signature(
+            CpiContext::new_with_signer(
+                arbiter_program.to_account_info(),
+                AddSignature {
+                    signer: governance.to_account_info(),
+                    participant: governance.to_account_info(),
+                    config: config.to_account_info(),
+                    document: document.to_account_info(),
+                },
+                &[signer_seeds],
+            ),
+            section,
+            memo,
+            None,
+        )
+    }
+}
+
+#[derive(Accounts)]
+pub struct SignDocument<'info> {
+    /// CHECK: The arbitrary realm public key that the `governance`
+    /// authority is derived from. It is never read or written.
+    pub realm: UncheckedAccount<'info>,
+
+    /// CHECK: The program derived governance authority of the `realm`
+    /// that is listed as a participant on the `document`.
+    #[account(
+        seeds = [
+            GOVERNANCE,
+            realm.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub governance: UncheckedAccount<'info>,
+
+    /// CHECK: The global Arbiter `Config` program account,
+    /// which is fully validated by the Arbiter program itself.
+    pub config: UncheckedAccount<'info>,
+
+    /// CHECK: The Arbiter `Document` program account being signed,
+    /// which is fully validated by the Arbiter program itself.
+    #[account(mut)]
+    pub document: UncheckedAccount<'info>,
+
+    /// The Arbiter program being invoked.
+    pub arbiter_program: Program<'info, Arbiter>,
+}
@@ -10,6 +10,7 @@ import {
 import { assert, use as chaiUse } from 'chai'
 import chaiAsPromised from 'chai-as-promised'
 import { Arbiter } from '../target/types/arbiter'
+import { ensureConfig, limits, participantSet } from './utils'
 
 chaiUse(chaiAsPromised)
 
@@ -24,10 +25,93 @@ describe('arbiter', async () => {
 
   const title = 'My Test Document'
   const uri = 'https://arweave.net/abc123'
+  const settings = {
+    requiresAcceptance: false,
+    participantRoot: null,
+    participantCount: 0,
+    draft: false,
+    requiredWeight: new BN(0),
+    notBefore: null
+  }
 
   let clerk: web3.PublicKey
+  let config: web3.PublicKey
+  let treasury: web3.PublicKey
   let document: web3.PublicKey
 
+  before(async () => {
+    config = await ensureConfig(program)
+    treasury = (await program.account.config.fetch(config)).treasury
+  })
+
+  describe('the program admin should be able to', () => {
+    it('update the configured limits', async () => {
+      await program.methods
+        .updateConfig({ ...limits, maxParticipants: limits.maxParticipants + 1 }, null)
+        .accounts({ admin: authority.publicKey, config })
+        .rpc()
+
+      let c = await program.account.config.fetch(config)
+      assert.strictEqual(c.limits.maxParticipants, limits.maxParticipants + 1)
+
+      await program.methods
+        .updateConfig(limits, null)
+        .accounts({ admin: authority.publicKey, config })
+        .rpc()
+
+      c = await program.account.config.fetch(config)
+      assert.strictEqual(c.limits.maxParticipants, limits.maxParticipants)
+    })
+
+    it('but no one else can update the configuration', () => {
+      const random = web3.Keypair.generate()
+      assert.isRejected(
+        program.methods
+          .updateConfig(limits, random.publicKey)
+          .accounts({ admin: random.publicKey, config })
+          .signers([random])
+          .simulate()
+      )
+    })
+
+    it('pause and unpause every mutating instruction', async () => {
+      const [pausedClerk] = await web3.PublicKey.findProgramAddress(
+        [Buffer.from('clerk'), authority.publicKey.toBytes()],
+        program.programId
+      )
+
+      await program.methods.setPaused(true).accounts({ admin: authority.publicKey, config }).rpc()
+      assert.isTrue((await program.account.config.fetch(config)).paused)
+
+      await assert.isRejected(
+        program.methods
+          .initClerk(1)
+          .accounts({
+            authority: authority.publicKey,
+            payer: authority.publicKey,
+            config,
+            clerk: pausedClerk
+          })
+          .signers([authority])
+          .simulate()
+      )
+
+      await program.methods.setPaused(false).accounts({ admin: authority.publicKey, config }).rpc()
+      assert.isFalse((await program.account.config.fetch(config)).paused)
+    })
+
+    it('but no one else can pause the program', () => {
+      const random = web3.Keypair.generate()
+      assert.isRejected(
+        program.methods
+          .setPaused(true)
+          .accounts({ admin: random.publicKey, config })
+          .signers([random])
+          .simulate()
+      )
+    })
+  })
+
   describe('users of the program should be able to', () => {
     describe('invoke `init_clerk` to create a Clerk program account for themselves', () => {
       before(async () => {
@@ -45,6 +129,22 @@ describe('arbiter', async () => {
               .accounts({
                 authority: authority.publicKey,
                 payer: authority.publicKey,
+                config,
+                clerk
+              })
+              .signers([authority])
+              .simulate()
+          )
+        })
+
+        it('the document limit provided exceeds the configured maximum', () => {
+          assert.isRejected(
+            program.methods
+              .initClerk(limits.maxClerkLimit + 1)
+              .accounts({
+                authority: authority.publicKey,
+                payer: authority.publicKey,
+                config,
                 clerk
               })
               .signers([authority])
@@ -62,6 +162,7 @@ describe('arbiter', async () => {
             .accounts({
               authority: authority.publicKey,
               payer: authority.publicKey,
+              config,
               clerk
             })
             .signers([authority])
@@ -79,6 +180,21 @@ describe('arbiter', async () => {
         it('the document list is set to the proper size limit', () => {
           assert.lengthOf(clerkData.account.documents, 1)
         })
+
+        it('the clerk is stored in the current layout version', () => {
+          assert.strictEqual(clerkData.account.version, 1)
+        })
+
+        it('but a clerk in the current layout cannot be migrated', async () => {
+          await assert.isRejected(
+            program.methods
+              .migrateClerk()
+              .accounts({ payer: authority.publicKey, config, clerk })
+              .signers([authority])
+              .rpc(),
+            /AccountAlreadyMigrated/
+          )
+        })
       })
     })
 
@@ -106,11 +222,50 @@ describe('arbiter', async () => {
               .initDocument(
                 '',
                 uri,
-                participants.map(p => p.publicKey)
+                participantSet({ participants: participants.map(p => p.publicKey) }),
+                [],
+                [],
+                settings
+              )
+              .accounts({
+                authority: authority.publicKey,
+                payer: authority.publicKey,
+                config,
+                treasury,
+                clerk,
+                document: badDoc
+              })
+              .signers([authority])
+              .simulate()
+          )
+        })
+
+        it('the document title exceeds the configured maximum length', async () => {
+          const longTitle = 'T'.repeat(limits.maxTitleLength + 1)
+          const [badDoc] = await web3.PublicKey.findProgramAddress(
+            [
+              Buffer.from('document'),
+              authority.publicKey.toBytes(),
+              Buffer.from(longTitle.substring(0, 32))
+            ],
+            program.programId
+          )
+
+          assert.isRejected(
+            program.methods
+              .initDocument(
+                longTitle,
+                uri,
+                participantSet({ participants: participants.map(p => p.publicKey) }),
+                [],
+                [],
+                settings
               )
               .accounts({
                 authority: authority.publicKey,
                 payer: authority.publicKey,
+                config,
+                treasury,
                 clerk,
                 document: badDoc
               })
@@ -125,11 +280,40 @@ describe('arbiter', async () => {
               .initDocument(
                 title,
                 '',
-                participants.map(p => p.publicKey)
+                participantSet({ participants: participants.map(p => p.publicKey) }),
+                [],
+                [],
+                settings
               )
               .accounts({
                 authority: authority.publicKey,
                 payer: authority.publicKey,
+                config,
+                treasury,
+                clerk,
+                document
+              })
+              .signers([authority])
+              .simulate()
+          )
+        })
+
+        it('the document uri does not use an allowed scheme', async () => {
+          assert.isRejected(
+            program.methods
+              .initDocument(
+                title,
+                'htps://arweave.net/abc123',
+                participantSet({ participants: participants.map(p => p.publicKey) }),
+                [],
+                [],
+                settings
+              )
+              .accounts({
+                authority: authority.publicKey,
+                payer: authority.publicKey,
+                config,
+                treasury,
                 clerk,
                 document
               })
@@ -141,10 +325,12 @@ describe('arbiter', async () => {
         it('the participants public key array is empty', () => {
           assert.isRejected(
             program.methods
-              .initDocument(title, uri, [])
+              .initDocument(title, uri, participantSet({}), [], [], settings)
               .accounts({
                 authority: authority.publicKey,
                 payer: authority.publicKey,
+                config,
+                treasury,
                 clerk,
                 document
               })
@@ -156,13 +342,45 @@ describe('arbiter', async () => {
         it('there are duplicate participant public keys', () => {
           assert.isRejected(
             program.methods
-              .initDocument(title, uri, [
-                ...participants.map(p => p.publicKey),
-                participants[0].publicKey
-              ])
+              .initDocument(
+                title,
+                uri,
+                participantSet({
+                  participants: [...participants.map(p => p.publicKey), participants[0].publicKey]
+                }),
+                [],
+                [],
+                settings
+              )
+              .accounts({
+                authority: authority.publicKey,
+                payer: authority.publicKey,
+                config,
+                treasury,
+                clerk,
+                document
+              })
+              .signers([authority])
+              .simulate()
+          )
+        })
+
+        it('the document is listed as its own prerequisite', () => {
+          assert.isRejected(
+            program.methods
+              .initDocument(
+                title,
+                uri,
+                participantSet({ participants: participants.map(p => p.publicKey) }),
+                [],
+                [document],
+                settings
+              )
               .accounts({
                 authority: authority.publicKey,
                 payer: authority.publicKey,
+                config,
+                treasury,
                 clerk,
                 document
               })
@@ -180,11 +398,16 @@ describe('arbiter', async () => {
             .initDocument(
               title,
               uri,
-              participants.map(p => p.publicKey)
+              participantSet({ participants: participants.map(p => p.publicKey) }),
+              [],
+              [],
+              settings
             )
             .accounts({
               authority: authority.publicKey,
               payer: authority.publicKey,
+              config,
+              treasury,
               clerk,
               document
             })
@@ -229,9 +452,24 @@ describe('arbiter', async () => {
 
           it('additional state data fields', () => {
             assert.equal(docData.account.finalizationTimestamp.toNumber(), 0)
+            assert.strictEqual(docData.account.version, 1)
           })
         })
 
+        it('participants cannot accept an invitation the document does not require', () => {
+          assert.isRejected(
+            program.methods
+              .acceptInvitation()
+              .accounts({
+                participant: participants[0].publicKey,
+                config,
+                document
+              })
+              .signers([participants[0]])
+              .simulate()
+          )
+        })
+
         it('and it will fail to create more documents after the clerk limit is met', async () => {
           const newTitle = 'My Next Document'
           const [newDocKey] = await web3.PublicKey.findProgramAddress(
@@ -248,11 +486,16 @@ describe('arbiter', async () => {
               .initDocument(
                 newTitle,
                 uri,
-                participants.map(p => p.publicKey)
+                participantSet({ participants: participants.map(p => p.publicKey) }),
+                [],
+                [],
+                settings
               )
               .accounts({
                 authority: authority.publicKey,
                 payer: authority.publicKey,
+                config,
+                treasury,
                 clerk,
                 document: newDocKey
               })
@@ -263,14 +506,68 @@ describe('arbiter', async () => {
       })
     })
 
+    describe('the creator can manage attachments before the document is signed', () => {
+      const contentHash = [...Array(32)].map((_, i) => i)
+
+      it('by adding them with `add_attachment`', async () => {
+        for (const label of ['Arweave Mirror', 'Exhibit A']) {
+          await program.methods
+            .addAttachment(label, 'IPFS://bafybeigdyr', contentHash)
+            .accounts({
+              authority: authority.publicKey,
+              payer: authority.publicKey,
+              config,
+              document
+            })
+            .signers([authority])
+            .rpc()
+        }
+
+        const doc = await program.account.document.fetch(document)
+        assert.lengthOf(doc.attachments, 2)
+        assert.strictEqual(doc.attachments[0].label, 'Arweave Mirror')
+        assert.strictEqual(doc.attachments[0].uri, 'ipfs://bafybeigdyr')
+        assert.deepEqual(doc.attachments[0].contentHash, contentHash)
+      })
+
+      it('unless the label is already used', () => {
+        assert.isRejected(
+          program.methods
+            .addAttachment('Exhibit A', 'ar://abc123', contentHash)
+            .accounts({
+              authority: authority.publicKey,
+              payer: authority.publicKey,
+              config,
+              document
+            })
+            .signers([authority])
+            .simulate()
+        )
+      })
+
+      it('and removing them with `remove_attachment`', async () => {
+        await program.methods
+          .removeAttachment('Exhibit A')
+          .accounts({ authority: authority.publicKey, config, document })
+          .signers([authority])
+          .rpc()
+
+        const doc = await program.account.document.fetch(document)
+        assert.lengthOf(doc.attachments, 1)
+        assert.strictEqual(doc.attachments[0].label, 'Arweave Mirror')
+      })
+    })
+
     describe('invoke `add_signature` for a participant to willingly sign a document', () => {
       describe('but the instruction will fail when', () => {
         it('the participant is not associated with the document', () => {
           const random = web3.Keypair.generate()
           assert.isRejected(
             program.methods
-              .addSignature()
+              .addSignature(0, null, null)
               .accounts({
+                signer: random.publicKey,
+                config,
                 participant: random.publicKey,
                 document
               })
@@ -278,15 +575,33 @@ describe('arbiter', async () => {
               .simulate()
           )
         })
+
+        it('the section index does not exist on the document', () => {
+          assert.isRejected(
+            program.methods
+              .addSignature(1, null, null)
+              .accounts({
+                signer: participants[0].publicKey,
+                config,
+                participant: participants[0].publicKey,
+                document
+              })
+              .signers([participants[0]])
+              .simulate()
+          )
+        })
       })
 
       describe('and when the instruction succeeds for a single participant', () => {
+        const memo = [...Buffer.from('subject to clause 4'.padEnd(32, '\0'))]
         let docData: any
 
         before(async () => {
           await program.methods
-            .addSignature()
+            .addSignature(0, memo, null)
             .accounts({
+              signer: participants[2].publicKey,
+              config,
               participant: participants[2].publicKey,
               document
             })
@@ -300,11 +615,18 @@ describe('arbiter', async () => {
           assert.notEqual(docData.signatureTimestamps[2].toNumber(), 0)
         })
 
+        it('their signature memo is stored in the document account data', () => {
+          assert.deepEqual(docData.memos[2], memo)
+          assert.deepEqual(docData.memos[0], Array(32).fill(0))
+        })
+
         it('the same participant can not submit subsequent signatures on the same document', () => {
           assert.isRejected(
             program.methods
-              .addSignature()
+              .addSignature(0, null, null)
               .accounts({
+                signer: participants[2].publicKey,
+                config,
                 participant: participants[2].publicKey,
                 document
               })
@@ -313,6 +635,86 @@ describe('arbiter', async () => {
           )
         })
       })
+
+      describe('and a delegate can sign on behalf of a participant after `grant_delegation`', () => {
+        const delegate = web3.Keypair.generate()
+        let delegation: web3.PublicKey
+        let docData: any
+
+        before(async () => {
+          ;[delegation] = await web3.PublicKey.findProgramAddress(
+            [
+              Buffer.from('delegation'),
+              participants[1].publicKey.toBytes(),
+              delegate.publicKey.toBytes()
+            ],
+            program.programId
+          )
+        })
+
+        it('unless no delegation account is provided', () => {
+          assert.isRejected(
+            program.methods
+              .addSignature(0, null, null)
+              .accounts({
+                signer: delegate.publicKey,
+                config,
+                participant: participants[1].publicKey,
+                document
+              })
+              .signers([delegate])
+              .simulate()
+          )
+        })
+
+        describe('once the delegation is granted', () => {
+          before(async () => {
+            await program.methods
+              .grantDelegation(document, null)
+              .accounts({
+                participant: participants[1].publicKey,
+                payer: authority.publicKey,
+                config,
+                delegate: delegate.publicKey,
+                delegation
+              })
+              .signers([participants[1], authority])
+              .rpc()
+
+            await program.methods
+              .addSignature(0, null, null)
+              .accounts({
+                signer: delegate.publicKey,
+                config,
+                participant: participants[1].publicKey,
+                document
+              })
+              .remainingAccounts([{ pubkey: delegation, isSigner: false, isWritable: false }])
+              .signers([delegate])
+              .rpc()
+
+            docData = await program.account.document.fetch(document)
+          })
+
+          it('the participant signature timestamp is set', () => {
+            assert.notEqual(docData.signatureTimestamps[1].toNumber(), 0)
+          })
+
+          it('the delegate is recorded as the signer', () => {
+            assert.isTrue(docData.signers[1].equals(delegate.publicKey))
+          })
+        })
+      })
+    })
+
+    it('the creator cannot change attachments once the document is signed', () => {
+      assert.isRejected(
+        program.methods
+          .removeAttachment('Arweave Mirror')
+          .accounts({ authority: authority.publicKey, config, document })
+          .signers([authority])
+          .simulate()
+      )
     })
 
     describe('the creator can add a new participant with `add_participant`', () => {
@@ -324,6 +726,7 @@ describe('arbiter', async () => {
               .accounts({
                 authority: authority.publicKey,
                 payer: authority.publicKey,
+                config,
                 document
               })
               .signers([authority])
@@ -341,6 +744,7 @@ describe('arbiter', async () => {
             .accounts({
               authority: authority.publicKey,
               payer: authority.publicKey,
+              config,
               document
             })
             .signers([authority])
@@ -361,6 +765,24 @@ describe('arbiter', async () => {
       })
     })
 
+    describe('the creator can invoke `rotate_participant` to replace a participant key', () => {
+      it('but it will fail when neither the old or new participant approves', () => {
+        assert.isRejected(
+          program.methods
+            .rotateParticipant(false)
+            .accounts({
+              authority: authority.publicKey,
+              config,
+              oldParticipant: participants[0].publicKey,
+              newParticipant: web3.Keypair.generate().publicKey,
+              document
+            })
+            .signers([authority])
+            .simulate()
+        )
+      })
+    })
+
     describe('the creator can invoke `finalize` to complete a document', () => {
       describe('it will fail when', () => {
         it('not all participants have signature timestamps on the document', () => {
@@ -370,6 +792,7 @@ describe('arbiter', async () => {
               .accounts({
                 authority: authority.publicKey,
                 payer: authority.publicKey,
+                config,
                 clerk,
                 document
               })
@@ -380,10 +803,12 @@ describe('arbiter', async () => {
 
         after(async () => {
           await Promise.all(
-            [participants[0], participants[1], participants[3], additionalParticipant].map(p =>
+            [participants[0], participants[3], additionalParticipant].map(p =>
               program.methods
-                .addSignature()
+                .addSignature(0, null, null)
                 .accounts({
+                  signer: p.publicKey,
+                  config,
                   participant: p.publicKey,
                   document
                 })
@@ -403,6 +828,7 @@ describe('arbiter', async () => {
             .accounts({
               authority: authority.publicKey,
               payer: authority.publicKey,
+              config,
               clerk,
               document
             })
@@ -421,7 +847,12 @@ describe('arbiter', async () => {
           assert.isRejected(
             program.methods
               .addParticipant(newPart.publicKey)
-              .accounts({ authority: authority.publicKey, payer: authority.publicKey, document })
+              .accounts({
+                authority: authority.publicKey,
+                payer: authority.publicKey,
+                config,
+                document
+              })
               .signers([authority])
               .simulate()
           )
@@ -435,7 +866,12 @@ describe('arbiter', async () => {
           assert.isRejected(
             program.methods
               .upgrade(0)
-              .accounts({ authority: authority.publicKey, clerk })
+              .accounts({
+                authority: authority.publicKey,
+                payer: authority.publicKey,
+                config,
+                clerk
+              })
               .signers([authority])
               .simulate()
           )
@@ -454,6 +890,7 @@ describe('arbiter', async () => {
             .upgrade(2)
             .accounts({
               authority: authority.publicKey,
+              config,
               clerk
             })
             .signers([authority])
@@ -481,5 +918,57 @@ describe('arbiter', async () => {
         })
       })
     })
+
+    describe('invoke `init_bundle` to link documents for atomic finalization', () => {
+      const bundleName = 'Deal Closing'
+      let bundle: web3.PublicKey
+
+      before(async () => {
+        ;[bundle] = await web3.PublicKey.findProgramAddress(
+          [Buffer.from('bundle'), clerk.toBytes(), Buffer.from(bundleName)],
+          program.programId
+        )
+      })
+
+      describe('unless it fails because', () => {
+        it('a document is not held by the clerk', () => {
+          const other = web3.Keypair.generate().publicKey
+          assert.isRejected(
+            program.methods
+              .initBundle(bundleName, [document, other])
+              .accounts({
+                authority: authority.publicKey,
+                payer: authority.publicKey,
+                config,
+                clerk,
+                bundle
+              })
+              .remainingAccounts([
+                { pubkey: document, isSigner: false, isWritable: true },
+                { pubkey: other, isSigner: false, isWritable: true }
+              ])
+              .signers([authority])
+              .simulate()
+          )
+        })
+
+        it('a document has already been finalized', () => {
+          assert.isRejected(
+            program.methods
+              .initBundle(bundleName, [document])
+              .accounts({
+                authority: authority.publicKey,
+                payer: authority.publicKey,
+                config,
+                clerk,
+                bundle
+              })
+              .remainingAccounts([{ pubkey: document, isSigner: false, isWritable: true }])
+              .signers([authority])
+              .simulate()
+          )
+        })
+      })
+    })
   })
 })
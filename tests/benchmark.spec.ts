@@ -0,0 +1,270 @@
+import { BN, Program, AnchorProvider as Provider, setProvider, web3, workspace } from '@project-serum/anchor'
+import { assert } from 'chai'
+import { Arbiter } from '../target/types/arbiter'
+import { ensureConfig, limits, participantSet } from './utils'
+
+/**
+ * The participant counts that each document layout is benchmarked with.
+ */
+const SIZES = [8, 32, 64]
+
+/**
+ * The number of participants added to a document in a single transaction.
+ */
+const BATCH = 8
+
+type Result = { participants: number; layout: string; addSignature: number; finalize: number }
+
+describe('compute unit benchmarks', async () => {
+  setProvider(Provider.env())
+
+  const program = workspace.Arbiter as Program<Arbiter>
+  const provider = program.provider as Provider
+
+  const authority = web3.Keypair.generate()
+  const uri = 'https://arweave.net/benchmark'
+  const settings = {
+    requiresAcceptance: false,
+    participantRoot: null,
+    participantCount: 0,
+    draft: false,
+    requiredWeight: new BN(0),
+    notBefore: null
+  }
+
+  const results: Result[] = []
+
+  let clerk: web3.PublicKey
+  let config: web3.PublicKey
+  let treasury: web3.PublicKey
+
+  /**
+   * Returns the compute units consumed by the program in the confirmed transaction,
+   * which it reports in its logs as `Program <id> consumed <units> of <limit> compute units`.
+   */
+  const unitsConsumed = async (sig: string) => {
+    const tx = await provider.connection.getTransaction(sig, { commitment: 'confirmed' })
+    const log = tx.meta.logMessages.find(l =>
+      l.startsWith(`Program ${program.programId} consumed`)
+    )
+    return parseInt(log.split(' ')[3])
+  }
+
+  /**
+   * Generates the participant keypairs in the ascending public key order that
+   * is required by large documents and has no effect on the current layout.
+   */
+  const generateParticipants = (n: number) =>
+    [...Array(n)]
+      .map(() => web3.Keypair.generate())
+      .sort((a, b) => Buffer.compare(a.publicKey.toBuffer(), b.publicKey.toBuffer()))
+
+  /**
+   * Sends the instructions in transactions of `BATCH` instructions each.
+   */
+  const sendBatched = async (ixs: web3.TransactionInstruction[], signers: web3.Keypair[]) => {
+    for (let i = 0; i < ixs.length; i += BATCH) {
+      await provider.sendAndConfirm(new web3.Transaction().add(...ixs.slice(i, i + BATCH)), signers)
+    }
+  }
+
+  /**
+   * Signs the document with each of the participants and returns
+   * the compute units consumed by the last signature.
+   */
+  const signAll = async (
+    participants: web3.Keypair[],
+    sign: (p: web3.Keypair) => Promise<string>
+  ) => {
+    const last = participants.length - 1
+    for (let i = 0; i < last; i += BATCH) {
+      await Promise.all(participants.slice(i, Math.min(i + BATCH, last)).map(sign))
+    }
+    return unitsConsumed(await sign(participants[last]))
+  }
+
+  const benchmarkDocument = async (n: number): Promise<Result> => {
+    const participants = generateParticipants(n)
+    const title = `Benchmark ${n}`
+
+    const [document] = await web3.PublicKey.findProgramAddress(
+      [Buffer.from('document'), authority.publicKey.toBytes(), Buffer.from(title)],
+      program.programId
+    )
+
+    await program.methods
+      .initDocument(
+        title,
+        uri,
+        participantSet({ participants: [participants[0].publicKey] }),
+        [],
+        [],
+        settings
+      )
+      .accounts({
+        authority: authority.publicKey,
+        payer: authority.publicKey,
+        config,
+        treasury,
+        clerk,
+        document
+      })
+      .signers([authority])
+      .rpc()
+
+    await sendBatched(
+      await Promise.all(
+        participants.slice(1).map(p =>
+          program.methods
+            .addParticipant(p.publicKey)
+            .accounts({
+              authority: authority.publicKey,
+              payer: authority.publicKey,
+              config,
+              document
+            })
+            .instruction()
+        )
+      ),
+      [authority]
+    )
+
+    const addSignature = await signAll(participants, p =>
+      program.methods
+        .addSignature(0, null, null)
+        .accounts({ signer: p.publicKey, config, participant: p.publicKey, document })
+        .signers([p])
+        .rpc({ commitment: 'confirmed' })
+    )
+
+    const finalize = await unitsConsumed(
+      await program.methods
+        .finalize()
+        .accounts({
+          authority: authority.publicKey,
+          payer: authority.publicKey,
+          config,
+          clerk,
+          document
+        })
+        .signers([authority])
+        .rpc({ commitment: 'confirmed' })
+    )
+
+    return { participants: n, layout: 'Document', addSignature, finalize }
+  }
+
+  const benchmarkLargeDocument = async (n: number): Promise<Result> => {
+    const participants = generateParticipants(n)
+    const document = web3.Keypair.generate()
+
+    await program.methods
+      .initLargeDocument(`Large Benchmark ${n}`, uri)
+      .accounts({
+        authority: authority.publicKey,
+        payer: authority.publicKey,
+        config,
+        treasury,
+        clerk,
+        document: document.publicKey
+      })
+      .preInstructions([await program.account.largeDocument.createInstruction(document)])
+      .signers([authority, document])
+      .rpc()
+
+    const batches = []
+    for (let i = 0; i < n; i += BATCH) {
+      batches.push(participants.slice(i, i + BATCH).map(p => p.publicKey))
+    }
+
+    await sendBatched(
+      await Promise.all(
+        batches.map(b =>
+          program.methods
+            .addLargeParticipants(b)
+            .accounts({ authority: authority.publicKey, config, document: document.publicKey })
+            .instruction()
+        )
+      ),
+      [authority]
+    )
+
+    const addSignature = await signAll(participants, p =>
+      program.methods
+        .signLargeDocument()
+        .accounts({ signer: p.publicKey, config, document: document.publicKey })
+        .signers([p])
+        .rpc({ commitment: 'confirmed' })
+    )
+
+    const finalize = await unitsConsumed(
+      await program.methods
+        .finalizeLargeDocument()
+        .accounts({ authority: authority.publicKey, config, clerk, document: document.publicKey })
+        .signers([authority])
+        .rpc({ commitment: 'confirmed' })
+    )
+
+    return { participants: n, layout: 'LargeDocument', addSignature, finalize }
+  }
+
+  before(async () => {
+    config = await ensureConfig(program)
+    treasury = (await program.account.config.fetch(config)).treasury
+
+    await program.methods
+      .updateConfig({ ...limits, maxParticipants: Math.max(...SIZES) }, null)
+      .accounts({ admin: provider.wallet.publicKey, config })
+      .rpc()
+
+    const sig = await provider.connection.requestAirdrop(
+      authority.publicKey,
+      10 * web3.LAMPORTS_PER_SOL
+    )
+    await provider.connection.confirmTransaction(sig)
+
+    ;[clerk] = await web3.PublicKey.findProgramAddress(
+      [Buffer.from('clerk'), authority.publicKey.toBytes()],
+      program.programId
+    )
+
+    await program.methods
+      .initClerk(SIZES.length * 2)
+      .accounts({ authority: authority.publicKey, payer: authority.publicKey, config, clerk })
+      .signers([authority])
+      .rpc()
+
+    for (const n of SIZES) {
+      results.push(await benchmarkDocument(n), await benchmarkLargeDocument(n))
+    }
+
+    console.table(results)
+  })
+
+  after(async () => {
+    await program.methods
+      .updateConfig(limits, null)
+      .accounts({ admin: provider.wallet.publicKey, config })
+      .rpc()
+  })
+
+  const byLayout = (layout: string) => results.filter(r => r.layout === layout)
+
+  it('signing a large document costs less than the current layout', () => {
+    const large = byLayout('LargeDocument')
+    byLayout('Document').forEach((r, i) => assert.isBelow(large[i].addSignature, r.addSignature))
+  })
+
+  it('finalizing a large document costs less than the current layout', () => {
+    const large = byLayout('LargeDocument')
+    byLayout('Document').forEach((r, i) => assert.isBelow(large[i].finalize, r.finalize))
+  })
+
+  it('the cost of a large document does not grow with its participants', () => {
+    const [first, ...rest] = byLayout('LargeDocument')
+    for (const r of rest) {
+      assert.isBelow(r.addSignature, first.addSignature * 1.1)
+      assert.isBelow(r.finalize, first.finalize * 1.1)
+    }
+  })
+})
@@ -0,0 +1,171 @@
+import { BN, Program, AnchorProvider as Provider, setProvider, web3, workspace } from '@project-serum/anchor'
+import { assert, use as chaiUse } from 'chai'
+import chaiAsPromised from 'chai-as-promised'
+import { Arbiter } from '../target/types/arbiter'
+import { ensureConfig, participantSet } from './utils'
+
+chaiUse(chaiAsPromised)
+
+describe('document bundles', async () => {
+  setProvider(Provider.env())
+
+  const program = workspace.Arbiter as Program<Arbiter>
+
+  const authority = web3.Keypair.generate()
+  const participant = web3.Keypair.generate()
+
+  const titles = ['Asset Purchase Agreement', 'Escrow Agreement']
+  const uri = 'https://arweave.net/closing'
+  const settings = {
+    requiresAcceptance: false,
+    participantRoot: null,
+    participantCount: 0,
+    draft: false,
+    requiredWeight: new BN(0),
+    notBefore: null
+  }
+
+  let clerk: web3.PublicKey
+  let config: web3.PublicKey
+  let treasury: web3.PublicKey
+  let bundle: web3.PublicKey
+  let documents: web3.PublicKey[]
+
+  const members = () => documents.map(pubkey => ({ pubkey, isSigner: false, isWritable: true }))
+
+  const initBundle = (name: string, address: web3.PublicKey) =>
+    program.methods
+      .initBundle(name, documents)
+      .accounts({
+        authority: authority.publicKey,
+        payer: authority.publicKey,
+        config,
+        clerk,
+        bundle: address
+      })
+      .remainingAccounts(members())
+      .signers([authority])
+
+  const findBundle = async (name: string) => {
+    const [address] = await web3.PublicKey.findProgramAddress(
+      [Buffer.from('bundle'), clerk.toBytes(), Buffer.from(name)],
+      program.programId
+    )
+    return address
+  }
+
+  before(async () => {
+    config = await ensureConfig(program)
+    treasury = (await program.account.config.fetch(config)).treasury
+
+    for (const k of [authority, participant]) {
+      const sig = await program.provider.connection.requestAirdrop(
+        k.publicKey,
+        web3.LAMPORTS_PER_SOL
+      )
+      await program.provider.connection.confirmTransaction(sig)
+    }
+
+    ;[clerk] = await web3.PublicKey.findProgramAddress(
+      [Buffer.from('clerk'), authority.publicKey.toBytes()],
+      program.programId
+    )
+
+    await program.methods
+      .initClerk(titles.length)
+      .accounts({ authority: authority.publicKey, payer: authority.publicKey, config, clerk })
+      .signers([authority])
+      .rpc()
+
+    documents = []
+    for (const title of titles) {
+      const [document] = await web3.PublicKey.findProgramAddress(
+        [Buffer.from('document'), authority.publicKey.toBytes(), Buffer.from(title)],
+        program.programId
+      )
+
+      await program.methods
+        .initDocument(
+          title,
+          uri,
+          participantSet({ participants: [participant.publicKey] }),
+          [],
+          [],
+          settings
+        )
+        .accounts({
+          authority: authority.publicKey,
+          payer: authority.publicKey,
+          config,
+          treasury,
+          clerk,
+          document
+        })
+        .signers([authority])
+        .rpc()
+
+      documents.push(document)
+    }
+
+    bundle = await findBundle('Closing')
+    await initBundle('Closing', bundle).rpc()
+
+    for (const document of documents) {
+      await program.methods
+        .addSignature(0, null, null)
+        .accounts({
+          signer: participant.publicKey,
+          config,
+          participant: participant.publicKey,
+          document
+        })
+        .signers([participant])
+        .rpc()
+    }
+  })
+
+  it('each bundled document stores the bundle', async () => {
+    for (const document of documents) {
+      const doc = await program.account.document.fetch(document)
+      assert.isTrue(doc.bundle.equals(bundle))
+    }
+  })
+
+  it('a bundled document cannot be added to another bundle', async () => {
+    assert.isRejected(initBundle('Second Closing', await findBundle('Second Closing')).simulate())
+  })
+
+  it('a bundled document cannot be finalized on its own', () => {
+    assert.isRejected(
+      program.methods
+        .finalize()
+        .accounts({
+          authority: authority.publicKey,
+          payer: authority.publicKey,
+          config,
+          clerk,
+          document: documents[0]
+        })
+        .signers([authority])
+        .simulate()
+    )
+  })
+
+  describe('once the bundle is finalized', () => {
+    before(async () => {
+      await program.methods
+        .finalizeBundle()
+        .accounts({ authority: authority.publicKey, config, clerk, bundle })
+        .remainingAccounts(members())
+        .signers([authority])
+        .rpc()
+    })
+
+    it('every bundled document is finalized', async () => {
+      for (const document of documents) {
+        const doc = await program.account.document.fetch(document)
+        assert.notEqual(doc.finalizationTimestamp.toNumber(), 0)
+      }
+    })
+  })
+})
@@ -0,0 +1,248 @@
+import { BN, Program, AnchorProvider as Provider, setProvider, web3, workspace } from '@project-serum/anchor'
+import { ASSOCIATED_TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { assert, use as chaiUse } from 'chai'
+import chaiAsPromised from 'chai-as-promised'
+import { createHash } from 'crypto'
+import { Arbiter } from '../target/types/arbiter'
+import {
+  ensureConfig,
+  findReceiptAccounts,
+  participantSet,
+  TOKEN_2022_PROGRAM_ID,
+  TOKEN_METADATA_PROGRAM_ID
+} from './utils'
+
+chaiUse(chaiAsPromised)
+
+const sha256 = (...data: Buffer[]) => createHash('sha256').update(Buffer.concat(data)).digest()
+
+/**
+ * Mirrors the on-chain Merkle leaf of the participant at the index of the set.
+ */
+function leaf(index: number, participant: web3.PublicKey) {
+  const i = Buffer.alloc(4)
+  i.writeUInt32LE(index)
+  return sha256(Buffer.from([0]), i, participant.toBuffer())
+}
+
+describe('document compression', async () => {
+  setProvider(Provider.env())
+
+  const program = workspace.Arbiter as Program<Arbiter>
+
+  const authority = web3.Keypair.generate()
+  const participants = [...Array(2)].map(() => web3.Keypair.generate())
+
+  const title = 'Compressed Agreement'
+  const uri = 'https://arweave.net/compressed'
+  const settings = {
+    requiresAcceptance: false,
+    participantRoot: null,
+    participantCount: 0,
+    draft: false,
+    requiredWeight: new BN(0),
+    notBefore: null
+  }
+
+  let clerk: web3.PublicKey
+  let config: web3.PublicKey
+  let treasury: web3.PublicKey
+  let document: web3.PublicKey
+  let receipt: web3.PublicKey
+
+  const compress = () =>
+    program.methods
+      .compressDocument()
+      .accounts({
+        authority: authority.publicKey,
+        payer: authority.publicKey,
+        config,
+        clerk,
+        document,
+        receipt
+      })
+      .signers([authority])
+
+  const claim = async (participant: web3.PublicKey) =>
+    program.methods
+      .claimReceipt()
+      .accounts({
+        payer: authority.publicKey,
+        participant,
+        config,
+        document,
+        ...(await findReceiptAccounts(program, document, participant)),
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID
+      })
+      .signers([authority])
+
+  const initDocument = () =>
+    program.methods
+      .initDocument(
+        title,
+        uri,
+        participantSet({ participants: participants.map(p => p.publicKey) }),
+        [],
+        [],
+        settings
+      )
+      .accounts({
+        authority: authority.publicKey,
+        payer: authority.publicKey,
+        config,
+        treasury,
+        clerk,
+        document
+      })
+      .signers([authority])
+
+  before(async () => {
+    config = await ensureConfig(program)
+    treasury = (await program.account.config.fetch(config)).treasury
+
+    for (const k of [authority, ...participants]) {
+      const sig = await program.provider.connection.requestAirdrop(
+        k.publicKey,
+        web3.LAMPORTS_PER_SOL
+      )
+      await program.provider.connection.confirmTransaction(sig)
+    }
+
+    ;[clerk] = await web3.PublicKey.findProgramAddress(
+      [Buffer.from('clerk'), authority.publicKey.toBytes()],
+      program.programId
+    )
+
+    ;[document] = await web3.PublicKey.findProgramAddress(
+      [Buffer.from('document'), authority.publicKey.toBytes(), Buffer.from(title)],
+      program.programId
+    )
+
+    ;[receipt] = await web3.PublicKey.findProgramAddress(
+      [Buffer.from('receipt'), document.toBytes()],
+      program.programId
+    )
+
+    await program.methods
+      .initClerk(1)
+      .accounts({ authority: authority.publicKey, payer: authority.publicKey, config, clerk })
+      .signers([authority])
+      .rpc()
+
+    await initDocument().rpc()
+
+    for (const p of participants) {
+      await program.methods
+        .addSignature(0, null, null)
+        .accounts({ signer: p.publicKey, config, participant: p.publicKey, document })
+        .signers([p])
+        .rpc()
+    }
+  })
+
+  it('a document cannot be compressed before it is finalized', async () => {
+    await assert.isRejected(compress().rpc())
+  })
+
+  describe('once the document is finalized', () => {
+    before(async () => {
+      await program.methods
+        .finalize()
+        .accounts({
+          authority: authority.publicKey,
+          payer: authority.publicKey,
+          config,
+          clerk,
+          document
+        })
+        .signers([authority])
+        .rpc()
+    })
+
+    it('the document cannot be compressed while receipts are unclaimed', async () => {
+      await (await claim(participants[0].publicKey)).rpc()
+      await assert.isRejected(compress().rpc())
+    })
+
+    describe('and its receipts are claimed before it is compressed', () => {
+      let documentRent: number
+      let balanceBefore: number
+
+      before(async () => {
+        await (await claim(participants[1].publicKey)).rpc()
+
+        documentRent = (await program.provider.connection.getAccountInfo(document)).lamports
+        balanceBefore = await program.provider.connection.getBalance(authority.publicKey)
+
+        await compress().rpc()
+      })
+
+      it('the document account is kept as a finalized tombstone', async () => {
+        const doc = await program.account.document.fetch(document)
+        assert.strictEqual(doc.title, title)
+        assert.isTrue(doc.finalizationTimestamp.gtn(0))
+        assert.isEmpty(doc.participants)
+        assert.isEmpty(doc.signatureTimestamps)
+        assert.strictEqual(doc.receiptsClaimed, participants.length)
+      })
+
+      it('the document title cannot be initialized again', async () => {
+        await assert.isRejected(initDocument().simulate())
+      })
+
+      it('the attachments of the document can no longer be changed', async () => {
+        await assert.isRejected(
+          program.methods
+            .addAttachment('Exhibit A', 'ar://abc123', [...Array(32)].map((_, i) => i))
+            .accounts({
+              authority: authority.publicKey,
+              payer: authority.publicKey,
+              config,
+              document
+            })
+            .signers([authority])
+            .rpc(),
+          /DocumentIsAlreadyFinalized/
+        )
+        await assert.isRejected(
+          program.methods
+            .removeAttachment('Exhibit A')
+            .accounts({ authority: authority.publicKey, config, document })
+            .signers([authority])
+            .rpc(),
+          /DocumentIsAlreadyFinalized/
+        )
+      })
+
+      it('the excess document rent is refunded to the authority', async () => {
+        const { connection } = program.provider
+        const tombstoneRent = (await connection.getAccountInfo(document)).lamports
+        const receiptRent = (await connection.getAccountInfo(receipt)).lamports
+        const balance = await connection.getBalance(authority.publicKey)
+        assert.isBelow(tombstoneRent, documentRent)
+        assert.isAbove(balance, balanceBefore + documentRent - tombstoneRent - receiptRent - 10_000)
+      })
+
+      it('the receipt records the document', async () => {
+        const r = await program.account.receipt.fetch(receipt)
+        assert.isTrue(r.document.equals(document))
+        assert.deepEqual(r.contentHash, [...sha256(Buffer.from(title), Buffer.from(uri))])
+        assert.isTrue(r.finalizationTimestamp.gtn(0))
+        assert.isTrue(r.createdAt.gtn(0))
+      })
+
+      it('the receipt commits to the participants', async () => {
+        const r = await program.account.receipt.fetch(receipt)
+        const [a, b] = participants.map((p, i) => leaf(i, p.publicKey)).sort(Buffer.compare)
+        assert.deepEqual(r.participantsRoot, [...sha256(Buffer.from([1]), a, b)])
+      })
+
+      it('the clerk slot of the document is freed', async () => {
+        const c = await program.account.clerk.fetch(clerk)
+        assert.isFalse(c.documents.some(d => d.equals(document)))
+      })
+    })
+  })
+})
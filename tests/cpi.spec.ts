@@ -0,0 +1,144 @@
+import { BN, Program, AnchorProvider as Provider, setProvider, web3, workspace } from '@project-serum/anchor'
+import { assert, use as chaiUse } from 'chai'
+import chaiAsPromised from 'chai-as-promised'
+import { Arbiter } from '../target/types/arbiter'
+import { MockGovernance } from '../target/types/mock_governance'
+import { ensureConfig, participantSet } from './utils'
+
+chaiUse(chaiAsPromised)
+
+describe('cross-program participants', async () => {
+  setProvider(Provider.env())
+
+  const program = workspace.Arbiter as Program<Arbiter>
+  const governanceProgram = workspace.MockGovernance as Program<MockGovernance>
+
+  const authority = web3.Keypair.generate()
+  const realm = web3.Keypair.generate().publicKey
+  const wallet = web3.Keypair.generate()
+
+  const title = 'Treasury Resolution'
+  const uri = 'https://arweave.net/xyz789'
+  const settings = {
+    requiresAcceptance: false,
+    participantRoot: null,
+    participantCount: 0,
+    draft: false,
+    requiredWeight: new BN(0),
+    notBefore: null
+  }
+
+  let clerk: web3.PublicKey
+  let config: web3.PublicKey
+  let treasury: web3.PublicKey
+  let document: web3.PublicKey
+  let governance: web3.PublicKey
+
+  before(async () => {
+    config = await ensureConfig(program)
+    treasury = (await program.account.config.fetch(config)).treasury
+
+    const sig = await program.provider.connection.requestAirdrop(
+      authority.publicKey,
+      web3.LAMPORTS_PER_SOL
+    )
+    await program.provider.connection.confirmTransaction(sig)
+
+    ;[clerk] = await web3.PublicKey.findProgramAddress(
+      [Buffer.from('clerk'), authority.publicKey.toBytes()],
+      program.programId
+    )
+
+    ;[document] = await web3.PublicKey.findProgramAddress(
+      [Buffer.from('document'), authority.publicKey.toBytes(), Buffer.from(title)],
+      program.programId
+    )
+
+    ;[governance] = await web3.PublicKey.findProgramAddress(
+      [Buffer.from('governance'), realm.toBytes()],
+      governanceProgram.programId
+    )
+
+    await program.methods
+      .initClerk(1)
+      .accounts({ authority: authority.publicKey, payer: authority.publicKey, config, clerk })
+      .signers([authority])
+      .rpc()
+
+    await program.methods
+      .initDocument(
+        title,
+        uri,
+        participantSet({ participants: [governance, wallet.publicKey] }),
+        [],
+        [],
+        settings
+      )
+      .accounts({
+        authority: authority.publicKey,
+        payer: authority.publicKey,
+        config,
+        treasury,
+        clerk,
+        document
+      })
+      .signers([authority])
+      .rpc()
+  })
+
+  describe('a program derived participant', () => {
+    it('cannot be signed for without the owning program', () => {
+      const random = web3.Keypair.generate()
+      assert.isRejected(
+        program.methods
+          .addSignature(0, null, null)
+          .accounts({ signer: random.publicKey, config, participant: governance, document })
+          .signers([random])
+          .simulate()
+      )
+    })
+
+    describe('when its program invokes `add_signature` with the derived signer', () => {
+      let docData: any
+
+      before(async () => {
+        await governanceProgram.methods
+          .signDocument(0, null)
+          .accounts({
+            realm,
+            config,
+            governance,
+            document,
+            arbiterProgram: program.programId
+          })
+          .rpc()
+
+        docData = await program.account.document.fetch(document)
+      })
+
+      it('the participant signature timestamp is set', () => {
+        assert.notEqual(docData.signatureTimestamps[0].toNumber(), 0)
+        assert.equal(docData.signatureTimestamps[1].toNumber(), 0)
+      })
+
+      it('the program derived address is recorded as the signer', () => {
+        assert.isTrue(docData.signers[0].equals(governance))
+      })
+
+      it('the same program derived participant cannot sign again', () => {
+        assert.isRejected(
+          governanceProgram.methods
+            .signDocument(0, null)
+            .accounts({
+              realm,
+              config,
+              governance,
+              document,
+              arbiterProgram: program.programId
+            })
+            .simulate()
+        )
+      })
+    })
+  })
+})
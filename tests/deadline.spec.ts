@@ -0,0 +1,126 @@
+import { BN, Program, AnchorProvider as Provider, setProvider, web3, workspace } from '@project-serum/anchor'
+import { assert, use as chaiUse } from 'chai'
+import chaiAsPromised from 'chai-as-promised'
+import { Arbiter } from '../target/types/arbiter'
+import { ensureConfig, participantSet } from './utils'
+
+chaiUse(chaiAsPromised)
+
+describe('participant deadlines', async () => {
+  setProvider(Provider.env())
+
+  const program = workspace.Arbiter as Program<Arbiter>
+
+  const authority = web3.Keypair.generate()
+  const participants = [...Array(2)].map(() => web3.Keypair.generate())
+
+  const title = 'Deadline Agreement'
+  const uri = 'https://arweave.net/deadline'
+  const settings = {
+    requiresAcceptance: false,
+    participantRoot: null,
+    participantCount: 0,
+    draft: false,
+    requiredWeight: new BN(0),
+    notBefore: null
+  }
+
+  const now = Math.floor(Date.now() / 1000)
+  const deadline = new BN(now + 60 * 60)
+
+  let clerk: web3.PublicKey
+  let config: web3.PublicKey
+  let treasury: web3.PublicKey
+  let document: web3.PublicKey
+
+  const initDocument = (deadlines: BN[]) =>
+    program.methods
+      .initDocument(
+        title,
+        uri,
+        participantSet({ participants: participants.map(p => p.publicKey), deadlines }),
+        [],
+        [],
+        settings
+      )
+      .accounts({
+        authority: authority.publicKey,
+        payer: authority.publicKey,
+        config,
+        treasury,
+        clerk,
+        document
+      })
+      .signers([authority])
+
+  const extend = (participant: web3.PublicKey, newDeadline: BN) =>
+    program.methods
+      .extendDeadline(participant, newDeadline)
+      .accounts({ authority: authority.publicKey, config, document })
+      .signers([authority])
+
+  before(async () => {
+    config = await ensureConfig(program)
+    treasury = (await program.account.config.fetch(config)).treasury
+
+    const sig = await program.provider.connection.requestAirdrop(
+      authority.publicKey,
+      web3.LAMPORTS_PER_SOL
+    )
+    await program.provider.connection.confirmTransaction(sig)
+
+    ;[clerk] = await web3.PublicKey.findProgramAddress(
+      [Buffer.from('clerk'), authority.publicKey.toBytes()],
+      program.programId
+    )
+
+    ;[document] = await web3.PublicKey.findProgramAddress(
+      [Buffer.from('document'), authority.publicKey.toBytes(), Buffer.from(title)],
+      program.programId
+    )
+
+    await program.methods
+      .initClerk(1)
+      .accounts({ authority: authority.publicKey, payer: authority.publicKey, config, clerk })
+      .signers([authority])
+      .rpc()
+  })
+
+  describe('a document cannot be created when', () => {
+    it('the number of deadlines does not match the participants', () => {
+      assert.isRejected(initDocument([deadline]).simulate())
+    })
+
+    it('a deadline has already passed', () => {
+      assert.isRejected(initDocument([new BN(now - 60), new BN(0)]).simulate())
+    })
+  })
+
+  describe('when a document is created with deadlines', () => {
+    before(async () => {
+      await initDocument([deadline, new BN(0)]).rpc()
+    })
+
+    it('they are stored alongside the participants', async () => {
+      const doc = await program.account.document.fetch(document)
+      assert.isTrue(doc.deadlines[0].eq(deadline))
+      assert.isTrue(doc.deadlines[1].isZero())
+    })
+
+    it('the authority can extend a deadline with `extend_deadline`', async () => {
+      const later = deadline.addn(60 * 60)
+      await extend(participants[0].publicKey, later).rpc()
+
+      const doc = await program.account.document.fetch(document)
+      assert.isTrue(doc.deadlines[0].eq(later))
+    })
+
+    it('a deadline cannot be moved earlier', () => {
+      assert.isRejected(extend(participants[0].publicKey, deadline).simulate())
+    })
+
+    it('a participant without a deadline cannot be given one', () => {
+      assert.isRejected(extend(participants[1].publicKey, deadline).simulate())
+    })
+  })
+})
@@ -0,0 +1,143 @@
+import { BN, Program, AnchorProvider as Provider, setProvider, web3, workspace } from '@project-serum/anchor'
+import { assert, use as chaiUse } from 'chai'
+import chaiAsPromised from 'chai-as-promised'
+import { Arbiter } from '../target/types/arbiter'
+import { ensureConfig, participantSet } from './utils'
+
+chaiUse(chaiAsPromised)
+
+describe('draft documents', async () => {
+  setProvider(Provider.env())
+
+  const program = workspace.Arbiter as Program<Arbiter>
+
+  const authority = web3.Keypair.generate()
+  const participants = [...Array(3)].map(() => web3.Keypair.generate())
+
+  const title = 'Draft Services Agreement'
+  const uri = 'https://arweave.net/draft-v1'
+  const settings = {
+    requiresAcceptance: false,
+    participantRoot: null,
+    participantCount: 0,
+    draft: true,
+    requiredWeight: new BN(0),
+    notBefore: null
+  }
+
+  let clerk: web3.PublicKey
+  let config: web3.PublicKey
+  let treasury: web3.PublicKey
+  let document: web3.PublicKey
+
+  const sign = (signer: web3.Keypair) =>
+    program.methods
+      .addSignature(0, null, null)
+      .accounts({ signer: signer.publicKey, config, participant: signer.publicKey, document })
+      .signers([signer])
+
+  const update = (newUri: string | null, newParticipants: web3.PublicKey[] | null) =>
+    program.methods
+      .updateDocument(newUri, newParticipants, null)
+      .accounts({ authority: authority.publicKey, payer: authority.publicKey, config, document })
+      .signers([authority])
+
+  before(async () => {
+    config = await ensureConfig(program)
+    treasury = (await program.account.config.fetch(config)).treasury
+
+    for (const k of [authority, ...participants]) {
+      const sig = await program.provider.connection.requestAirdrop(
+        k.publicKey,
+        web3.LAMPORTS_PER_SOL
+      )
+      await program.provider.connection.confirmTransaction(sig)
+    }
+
+    ;[clerk] = await web3.PublicKey.findProgramAddress(
+      [Buffer.from('clerk'), authority.publicKey.toBytes()],
+      program.programId
+    )
+
+    ;[document] = await web3.PublicKey.findProgramAddress(
+      [Buffer.from('document'), authority.publicKey.toBytes(), Buffer.from(title)],
+      program.programId
+    )
+
+    await program.methods
+      .initClerk(1)
+      .accounts({ authority: authority.publicKey, payer: authority.publicKey, config, clerk })
+      .signers([authority])
+      .rpc()
+
+    await program.methods
+      .initDocument(
+        title,
+        uri,
+        participantSet({ participants: [participants[0].publicKey] }),
+        [],
+        [],
+        settings
+      )
+      .accounts({
+        authority: authority.publicKey,
+        payer: authority.publicKey,
+        config,
+        treasury,
+        clerk,
+        document
+      })
+      .signers([authority])
+      .rpc()
+  })
+
+  describe('while the document is a draft', () => {
+    it('participants cannot sign it', () => {
+      assert.isRejected(sign(participants[0]).simulate())
+    })
+
+    it('the authority can change the uri and replace the participants', async () => {
+      await update(
+        'https://arweave.net/draft-v2',
+        participants.slice(1).map(p => p.publicKey)
+      ).rpc()
+
+      const doc = await program.account.document.fetch(document)
+      assert.strictEqual(doc.uri, 'https://arweave.net/draft-v2')
+      assert.lengthOf(doc.participants, 2)
+      assert.lengthOf(doc.signatureTimestamps, 2)
+      assert.isTrue(doc.settings.draft)
+    })
+  })
+
+  describe('once the authority invokes `open_for_signing`', () => {
+    before(async () => {
+      await program.methods
+        .openForSigning()
+        .accounts({ authority: authority.publicKey, config, document })
+        .signers([authority])
+        .rpc()
+
+      await sign(participants[1]).rpc()
+    })
+
+    it('participants can sign the document', async () => {
+      const doc = await program.account.document.fetch(document)
+      assert.isFalse(doc.settings.draft)
+      assert.notEqual(doc.signatureTimestamps[0].toNumber(), 0)
+    })
+
+    it('the participants are locked', () => {
+      assert.isRejected(update(null, [participants[0].publicKey]).simulate())
+    })
+
+    it('changing the uri invalidates every signature', async () => {
+      await update('https://arweave.net/draft-v3', null).rpc()
+
+      const doc = await program.account.document.fetch(document)
+      assert.strictEqual(doc.uri, 'https://arweave.net/draft-v3')
+      assert.isFalse(doc.settings.draft)
+      assert.isTrue(doc.signatureTimestamps.every((t: any) => t.toNumber() === 0))
+    })
+  })
+})
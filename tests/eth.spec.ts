@@ -0,0 +1,199 @@
+import { BN, Program, AnchorProvider as Provider, setProvider, web3, workspace } from '@project-serum/anchor'
+import { assert, use as chaiUse } from 'chai'
+import chaiAsPromised from 'chai-as-promised'
+import { createHash, randomBytes } from 'crypto'
+import { privateKeyVerify, publicKeyCreate } from 'secp256k1'
+import { Arbiter } from '../target/types/arbiter'
+import { ensureConfig, participantSet } from './utils'
+
+chaiUse(chaiAsPromised)
+
+/**
+ * Builds the EIP-191 personal message an Ethereum participant signs for a document section.
+ */
+function ethMessage(document: web3.PublicKey, title: string, uri: string, section: number) {
+  const contentHash = createHash('sha256').update(title).update(uri).digest()
+  const payload = Buffer.concat([document.toBuffer(), contentHash, Buffer.from([section])])
+  return Buffer.concat([
+    Buffer.from(`\x19Ethereum Signed Message:\n${payload.length}`),
+    payload
+  ])
+}
+
+describe('ethereum participants', async () => {
+  setProvider(Provider.env())
+
+  const program = workspace.Arbiter as Program<Arbiter>
+
+  const authority = web3.Keypair.generate()
+  const submitter = web3.Keypair.generate()
+
+  const title = 'Cross-Chain Services Agreement'
+  const uri = 'https://arweave.net/eth123'
+  const settings = {
+    requiresAcceptance: false,
+    participantRoot: null,
+    participantCount: 0,
+    draft: false,
+    requiredWeight: new BN(0),
+    notBefore: null
+  }
+
+  let privateKey: Buffer
+  let ethAddress: Buffer
+  let clerk: web3.PublicKey
+  let config: web3.PublicKey
+  let treasury: web3.PublicKey
+  let document: web3.PublicKey
+
+  before(async () => {
+    config = await ensureConfig(program)
+    treasury = (await program.account.config.fetch(config)).treasury
+
+    do {
+      privateKey = randomBytes(32)
+    } while (!privateKeyVerify(privateKey))
+
+    ethAddress = web3.Secp256k1Program.publicKeyToEthAddress(
+      Buffer.from(publicKeyCreate(privateKey, false).slice(1))
+    )
+
+    for (const k of [authority, submitter]) {
+      const sig = await program.provider.connection.requestAirdrop(
+        k.publicKey,
+        web3.LAMPORTS_PER_SOL
+      )
+      await program.provider.connection.confirmTransaction(sig)
+    }
+
+    ;[clerk] = await web3.PublicKey.findProgramAddress(
+      [Buffer.from('clerk'), authority.publicKey.toBytes()],
+      program.programId
+    )
+
+    ;[document] = await web3.PublicKey.findProgramAddress(
+      [Buffer.from('document'), authority.publicKey.toBytes(), Buffer.from(title)],
+      program.programId
+    )
+
+    await program.methods
+      .initClerk(1)
+      .accounts({ authority: authority.publicKey, payer: authority.publicKey, config, clerk })
+      .signers([authority])
+      .rpc()
+
+    await program.methods
+      .initDocument(
+        title,
+        uri,
+        participantSet({ ethParticipants: [[...ethAddress]] }),
+        [],
+        [],
+        settings
+      )
+      .accounts({
+        authority: authority.publicKey,
+        payer: authority.publicKey,
+        config,
+        treasury,
+        clerk,
+        document
+      })
+      .signers([authority])
+      .rpc()
+  })
+
+  describe('invoke `submit_eth_signature` with a preceding secp256k1 instruction', () => {
+    describe('but the instruction will fail when', () => {
+      it('the signed message is for a different section', () => {
+        assert.isRejected(
+          program.methods
+            .submitEthSignature([...ethAddress], 0)
+            .accounts({
+              submitter: submitter.publicKey,
+              config,
+              document,
+              instructionsSysvar: web3.SYSVAR_INSTRUCTIONS_PUBKEY
+            })
+            .preInstructions([
+              web3.Secp256k1Program.createInstructionWithPrivateKey({
+                privateKey,
+                message: ethMessage(document, title, uri, 1),
+                instructionIndex: 0
+              })
+            ])
+            .signers([submitter])
+            .simulate()
+        )
+      })
+
+      it('there is no secp256k1 instruction in the transaction', () => {
+        assert.isRejected(
+          program.methods
+            .submitEthSignature([...ethAddress], 0)
+            .accounts({
+              submitter: submitter.publicKey,
+              config,
+              document,
+              instructionsSysvar: web3.SYSVAR_INSTRUCTIONS_PUBKEY
+            })
+            .signers([submitter])
+            .simulate()
+        )
+      })
+    })
+
+    describe('and when the signature is verified', () => {
+      let docData: any
+
+      before(async () => {
+        await program.methods
+          .submitEthSignature([...ethAddress], 0)
+          .accounts({
+            submitter: submitter.publicKey,
+            config,
+            document,
+            instructionsSysvar: web3.SYSVAR_INSTRUCTIONS_PUBKEY
+          })
+          .preInstructions([
+            web3.Secp256k1Program.createInstructionWithPrivateKey({
+              privateKey,
+              message: ethMessage(document, title, uri, 0),
+              instructionIndex: 0
+            })
+          ])
+          .signers([submitter])
+          .rpc()
+
+        docData = await program.account.document.fetch(document)
+      })
+
+      it('the ethereum participant signature timestamp is set', () => {
+        assert.lengthOf(docData.ethParticipants, 1)
+        assert.deepEqual(Buffer.from(docData.ethParticipants[0]), ethAddress)
+        assert.notEqual(docData.signatureTimestamps[0].toNumber(), 0)
+      })
+
+      it('the relaying submitter is recorded as the signer', () => {
+        assert.isTrue(docData.signers[0].equals(submitter.publicKey))
+      })
+
+      it('the document can be finalized by its authority', async () => {
+        await program.methods
+          .finalize()
+          .accounts({
+            authority: authority.publicKey,
+            payer: authority.publicKey,
+            config,
+            clerk,
+            document
+          })
+          .signers([authority])
+          .rpc()
+
+        const doc = await program.account.document.fetch(document)
+        assert.notEqual(doc.finalizationTimestamp.toNumber(), 0)
+      })
+    })
+  })
+})
@@ -0,0 +1,150 @@
+import { BN, Program, AnchorProvider as Provider, setProvider, web3, workspace } from '@project-serum/anchor'
+import { assert, use as chaiUse } from 'chai'
+import chaiAsPromised from 'chai-as-promised'
+import { Arbiter } from '../target/types/arbiter'
+import { ensureConfig, participantSet } from './utils'
+
+chaiUse(chaiAsPromised)
+
+describe('document creation fees', async () => {
+  setProvider(Provider.env())
+
+  const program = workspace.Arbiter as Program<Arbiter>
+
+  const admin = (program.provider as Provider).wallet.publicKey
+  const authority = web3.Keypair.generate()
+  const participant = web3.Keypair.generate()
+  const treasury = web3.Keypair.generate()
+
+  const documentFee = new BN(web3.LAMPORTS_PER_SOL / 100)
+  const settings = {
+    requiresAcceptance: false,
+    participantRoot: null,
+    participantCount: 0,
+    draft: false,
+    requiredWeight: new BN(0),
+    notBefore: null
+  }
+
+  let clerk: web3.PublicKey
+  let config: web3.PublicKey
+  let waiver: web3.PublicKey
+
+  const findDocument = async (title: string) =>
+    (
+      await web3.PublicKey.findProgramAddress(
+        [Buffer.from('document'), authority.publicKey.toBytes(), Buffer.from(title)],
+        program.programId
+      )
+    )[0]
+
+  const initDocument = async (title: string, treasuryKey: web3.PublicKey, waived: boolean) =>
+    program.methods
+      .initDocument(
+        title,
+        'https://arweave.net/fee',
+        participantSet({ participants: [participant.publicKey] }),
+        [],
+        [],
+        settings
+      )
+      .accounts({
+        authority: authority.publicKey,
+        payer: authority.publicKey,
+        config,
+        treasury: treasuryKey,
+        clerk,
+        document: await findDocument(title)
+      })
+      .remainingAccounts(waived ? [{ pubkey: waiver, isSigner: false, isWritable: false }] : [])
+      .signers([authority])
+
+  before(async () => {
+    config = await ensureConfig(program)
+
+    const sig = await program.provider.connection.requestAirdrop(
+      authority.publicKey,
+      web3.LAMPORTS_PER_SOL
+    )
+    await program.provider.connection.confirmTransaction(sig)
+
+    ;[clerk] = await web3.PublicKey.findProgramAddress(
+      [Buffer.from('clerk'), authority.publicKey.toBytes()],
+      program.programId
+    )
+
+    ;[waiver] = await web3.PublicKey.findProgramAddress(
+      [Buffer.from('waiver'), clerk.toBytes()],
+      program.programId
+    )
+
+    await program.methods
+      .initClerk(3)
+      .accounts({ authority: authority.publicKey, payer: authority.publicKey, config, clerk })
+      .signers([authority])
+      .rpc()
+
+    await program.methods
+      .setFee(documentFee, treasury.publicKey)
+      .accounts({ admin, config })
+      .rpc()
+  })
+
+  after(async () => {
+    await program.methods.setFee(new BN(0), admin).accounts({ admin, config }).rpc()
+  })
+
+  it('the admin set fee and treasury are stored in the config', async () => {
+    const c = await program.account.config.fetch(config)
+    assert.isTrue(c.documentFee.eq(documentFee))
+    assert.isTrue(c.treasury.equals(treasury.publicKey))
+  })
+
+  it('no one else can set the fee', () => {
+    const random = web3.Keypair.generate()
+    assert.isRejected(
+      program.methods
+        .setFee(new BN(0), random.publicKey)
+        .accounts({ admin: random.publicKey, config })
+        .signers([random])
+        .simulate()
+    )
+  })
+
+  it('a document cannot be created with a different treasury', async () => {
+    await assert.isRejected((await initDocument('Wrong Treasury', admin, false)).simulate())
+  })
+
+  it('the fee is transferred to the treasury when a document is created', async () => {
+    await (await initDocument('Charged Document', treasury.publicKey, false)).rpc()
+
+    const balance = await program.provider.connection.getBalance(treasury.publicKey)
+    assert.strictEqual(balance, documentFee.toNumber())
+  })
+
+  describe('a clerk granted a fee waiver by the admin', () => {
+    before(async () => {
+      await program.methods
+        .grantFeeWaiver()
+        .accounts({ admin, payer: admin, config, clerk, waiver })
+        .rpc()
+    })
+
+    it('is not charged when the waiver is provided', async () => {
+      await (await initDocument('Waived Document', treasury.publicKey, true)).rpc()
+
+      const balance = await program.provider.connection.getBalance(treasury.publicKey)
+      assert.strictEqual(balance, documentFee.toNumber())
+    })
+
+    it('is charged again once the waiver is revoked', async () => {
+      await program.methods.revokeFeeWaiver().accounts({ admin, config, waiver }).rpc()
+      assert.isNull(await program.account.feeWaiver.fetchNullable(waiver))
+
+      await (await initDocument('Charged Again', treasury.publicKey, false)).rpc()
+
+      const balance = await program.provider.connection.getBalance(treasury.publicKey)
+      assert.strictEqual(balance, documentFee.toNumber() * 2)
+    })
+  })
+})
@@ -0,0 +1,143 @@
+import { BN, Program, AnchorProvider as Provider, setProvider, web3, workspace } from '@project-serum/anchor'
+import { assert, use as chaiUse } from 'chai'
+import chaiAsPromised from 'chai-as-promised'
+import { Arbiter } from '../target/types/arbiter'
+import { ensureConfig, participantSet } from './utils'
+
+chaiUse(chaiAsPromised)
+
+describe('signer groups', async () => {
+  setProvider(Provider.env())
+
+  const program = workspace.Arbiter as Program<Arbiter>
+
+  const authority = web3.Keypair.generate()
+  const partners = [...Array(5)].map(() => web3.Keypair.generate())
+
+  const title = 'Partnership Agreement'
+  const uri = 'https://arweave.net/partnership'
+  const settings = {
+    requiresAcceptance: false,
+    participantRoot: null,
+    participantCount: 0,
+    draft: false,
+    requiredWeight: new BN(0),
+    notBefore: null
+  }
+
+  const firmA = { name: 'Firm A', threshold: 2, members: Buffer.from([0, 1, 2]) }
+  const firmB = { name: 'Firm B', threshold: 1, members: Buffer.from([3, 4]) }
+
+  let clerk: web3.PublicKey
+  let config: web3.PublicKey
+  let treasury: web3.PublicKey
+  let document: web3.PublicKey
+
+  const initDocument = (groups: typeof firmA[]) =>
+    program.methods
+      .initDocument(
+        title,
+        uri,
+        participantSet({ participants: partners.map(p => p.publicKey), groups }),
+        [],
+        [],
+        settings
+      )
+      .accounts({
+        authority: authority.publicKey,
+        payer: authority.publicKey,
+        config,
+        treasury,
+        clerk,
+        document
+      })
+      .signers([authority])
+
+  const sign = (signer: web3.Keypair) =>
+    program.methods
+      .addSignature(0, null, null)
+      .accounts({ signer: signer.publicKey, config, participant: signer.publicKey, document })
+      .signers([signer])
+      .rpc()
+
+  const finalize = () =>
+    program.methods
+      .finalize()
+      .accounts({
+        authority: authority.publicKey,
+        payer: authority.publicKey,
+        config,
+        clerk,
+        document
+      })
+      .signers([authority])
+
+  before(async () => {
+    config = await ensureConfig(program)
+    treasury = (await program.account.config.fetch(config)).treasury
+
+    for (const k of [authority, ...partners]) {
+      const sig = await program.provider.connection.requestAirdrop(
+        k.publicKey,
+        web3.LAMPORTS_PER_SOL
+      )
+      await program.provider.connection.confirmTransaction(sig)
+    }
+
+    ;[clerk] = await web3.PublicKey.findProgramAddress(
+      [Buffer.from('clerk'), authority.publicKey.toBytes()],
+      program.programId
+    )
+
+    ;[document] = await web3.PublicKey.findProgramAddress(
+      [Buffer.from('document'), authority.publicKey.toBytes(), Buffer.from(title)],
+      program.programId
+    )
+
+    await program.methods
+      .initClerk(1)
+      .accounts({ authority: authority.publicKey, payer: authority.publicKey, config, clerk })
+      .signers([authority])
+      .rpc()
+  })
+
+  describe('a document cannot be created when', () => {
+    it('a participant is a member of more than one group', () => {
+      assert.isRejected(
+        initDocument([firmA, { ...firmB, members: Buffer.from([2, 3]) }]).simulate()
+      )
+    })
+
+    it('a group threshold exceeds its members', () => {
+      assert.isRejected(initDocument([{ ...firmA, threshold: 4 }, firmB]).simulate())
+    })
+  })
+
+  describe('when each group has its own quorum', () => {
+    before(async () => {
+      await initDocument([firmA, firmB]).rpc()
+      await sign(partners[0])
+      await sign(partners[1])
+    })
+
+    it('the groups are stored on the document', async () => {
+      const doc = await program.account.document.fetch(document)
+      assert.deepEqual(
+        doc.groups.map((g: any) => g.name),
+        ['Firm A', 'Firm B']
+      )
+    })
+
+    it('it cannot be finalized until every group quorum is met', () => {
+      assert.isRejected(finalize().simulate())
+    })
+
+    it('it can be finalized once every group quorum is met', async () => {
+      await sign(partners[4])
+      await finalize().rpc()
+
+      const doc = await program.account.document.fetch(document)
+      assert.notEqual(doc.finalizationTimestamp.toNumber(), 0)
+    })
+  })
+})
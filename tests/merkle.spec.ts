@@ -0,0 +1,212 @@
+import { BN, Program, AnchorProvider as Provider, setProvider, web3, workspace } from '@project-serum/anchor'
+import { assert, use as chaiUse } from 'chai'
+import chaiAsPromised from 'chai-as-promised'
+import { createHash } from 'crypto'
+import { Arbiter } from '../target/types/arbiter'
+import { ensureConfig, participantSet } from './utils'
+
+chaiUse(chaiAsPromised)
+
+const sha256 = (...data: Buffer[]) => createHash('sha256').update(Buffer.concat(data)).digest()
+
+/**
+ * Mirrors the on-chain Merkle tree of a participant set with domain separated,
+ * index committed leaves and sorted sibling nodes.
+ */
+function buildTree(participants: web3.PublicKey[]): Buffer[][] {
+  const leaves = participants.map((p, i) => {
+    const index = Buffer.alloc(4)
+    index.writeUInt32LE(i)
+    return sha256(Buffer.from([0]), index, p.toBuffer())
+  })
+
+  const layers = [leaves]
+  while (layers[layers.length - 1].length > 1) {
+    const prev = layers[layers.length - 1]
+    const next: Buffer[] = []
+    for (let i = 0; i < prev.length; i += 2) {
+      if (i + 1 === prev.length) {
+        next.push(prev[i])
+      } else {
+        const [l, r] = [prev[i], prev[i + 1]].sort(Buffer.compare)
+        next.push(sha256(Buffer.from([1]), l, r))
+      }
+    }
+    layers.push(next)
+  }
+
+  return layers
+}
+
+function proofFor(layers: Buffer[][], index: number) {
+  const path: number[][] = []
+  let i = index
+  for (const layer of layers.slice(0, -1)) {
+    if ((i ^ 1) < layer.length) path.push([...layer[i ^ 1]])
+    i = Math.floor(i / 2)
+  }
+  return { index, path }
+}
+
+describe('merkle committed participant sets', async () => {
+  setProvider(Provider.env())
+
+  const program = workspace.Arbiter as Program<Arbiter>
+
+  const authority = web3.Keypair.generate()
+  const members = [...Array(9)].map(() => web3.Keypair.generate())
+  const tree = buildTree(members.map(m => m.publicKey))
+
+  const title = 'Board Resolution 2022-04'
+  const uri = 'https://arweave.net/petition'
+
+  let clerk: web3.PublicKey
+  let config: web3.PublicKey
+  let treasury: web3.PublicKey
+  let document: web3.PublicKey
+
+  before(async () => {
+    config = await ensureConfig(program)
+    treasury = (await program.account.config.fetch(config)).treasury
+
+    for (const k of [authority, ...members.slice(0, 2)]) {
+      const sig = await program.provider.connection.requestAirdrop(
+        k.publicKey,
+        web3.LAMPORTS_PER_SOL
+      )
+      await program.provider.connection.confirmTransaction(sig)
+    }
+
+    ;[clerk] = await web3.PublicKey.findProgramAddress(
+      [Buffer.from('clerk'), authority.publicKey.toBytes()],
+      program.programId
+    )
+
+    ;[document] = await web3.PublicKey.findProgramAddress(
+      [Buffer.from('document'), authority.publicKey.toBytes(), Buffer.from(title)],
+      program.programId
+    )
+
+    await program.methods
+      .initClerk(1)
+      .accounts({ authority: authority.publicKey, payer: authority.publicKey, config, clerk })
+      .signers([authority])
+      .rpc()
+
+    await program.methods
+      .initDocument(title, uri, participantSet({}), [], [], {
+        requiresAcceptance: false,
+        participantRoot: [...tree[tree.length - 1][0]],
+        participantCount: members.length,
+        draft: false,
+        requiredWeight: new BN(0),
+        notBefore: null
+      })
+      .accounts({
+        authority: authority.publicKey,
+        payer: authority.publicKey,
+        config,
+        treasury,
+        clerk,
+        document
+      })
+      .signers([authority])
+      .rpc()
+  })
+
+  it('the document stores only the root and an empty signed bitmap', async () => {
+    const doc = await program.account.document.fetch(document)
+    assert.lengthOf(doc.participants, 0)
+    assert.lengthOf(doc.signatureTimestamps, 0)
+    assert.deepEqual(doc.signedBitmap, Buffer.alloc(2))
+  })
+
+  it('listed participants cannot be added to the document', () => {
+    assert.isRejected(
+      program.methods
+        .addParticipant(web3.Keypair.generate().publicKey)
+        .accounts({ authority: authority.publicKey, payer: authority.publicKey, config, document })
+        .signers([authority])
+        .simulate()
+    )
+  })
+
+  describe('invoke `add_signature` with a participant proof', () => {
+    it('fails when the proof is for a different participant', () => {
+      assert.isRejected(
+        program.methods
+          .addSignature(0, null, proofFor(tree, 1))
+          .accounts({
+            signer: members[0].publicKey,
+            config,
+            participant: members[0].publicKey,
+            document
+          })
+          .signers([members[0]])
+          .simulate()
+      )
+    })
+
+    it('fails when no proof is provided', () => {
+      assert.isRejected(
+        program.methods
+          .addSignature(0, null, null)
+          .accounts({
+            signer: members[0].publicKey,
+            config,
+            participant: members[0].publicKey,
+            document
+          })
+          .signers([members[0]])
+          .simulate()
+      )
+    })
+
+    it('sets the bit of the participant in the signed bitmap', async () => {
+      await program.methods
+        .addSignature(0, null, proofFor(tree, 1))
+        .accounts({
+          signer: members[1].publicKey,
+          config,
+          participant: members[1].publicKey,
+          document
+        })
+        .signers([members[1]])
+        .rpc()
+
+      const doc = await program.account.document.fetch(document)
+      assert.deepEqual([...doc.signedBitmap], [0b10, 0])
+    })
+
+    it('rejects a second signature by the same participant', () => {
+      assert.isRejected(
+        program.methods
+          .addSignature(0, null, proofFor(tree, 1))
+          .accounts({
+            signer: members[1].publicKey,
+            config,
+            participant: members[1].publicKey,
+            document
+          })
+          .signers([members[1]])
+          .simulate()
+      )
+    })
+
+    it('the document cannot be finalized until every member has signed', () => {
+      assert.isRejected(
+        program.methods
+          .finalize()
+          .accounts({
+            authority: authority.publicKey,
+            payer: authority.publicKey,
+            config,
+            clerk,
+            document
+          })
+          .signers([authority])
+          .simulate()
+      )
+    })
+  })
+})
@@ -0,0 +1,219 @@
+import { BN, Program, AnchorProvider as Provider, setProvider, web3, workspace } from '@project-serum/anchor'
+import { ASSOCIATED_TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { assert, use as chaiUse } from 'chai'
+import chaiAsPromised from 'chai-as-promised'
+import { Arbiter } from '../target/types/arbiter'
+import {
+  ensureConfig,
+  findReceiptAccounts,
+  participantSet,
+  TOKEN_2022_PROGRAM_ID,
+  TOKEN_METADATA_PROGRAM_ID
+} from './utils'
+
+chaiUse(chaiAsPromised)
+
+/**
+ * Decodes the name, URI and creators from the borsh encoded data of a token metadata
+ * account, which follows its key byte, update authority and mint public keys.
+ */
+function decodeMetadata(data: Buffer) {
+  let offset = 1 + 32 + 32
+  const readString = () => {
+    const len = data.readUInt32LE(offset)
+    const s = data.subarray(offset + 4, offset + 4 + len).toString().replace(/\0/g, '')
+    offset += 4 + len
+    return s
+  }
+
+  const name = readString()
+  readString()
+  const uri = readString()
+  offset += 2
+
+  const creators: { address: web3.PublicKey; verified: boolean }[] = []
+  if (data[offset++] === 1) {
+    const count = data.readUInt32LE(offset)
+    offset += 4
+    for (let i = 0; i < count; i++, offset += 34) {
+      creators.push({
+        address: new web3.PublicKey(data.subarray(offset, offset + 32)),
+        verified: data[offset + 32] === 1
+      })
+    }
+  }
+
+  return { name, uri, creators }
+}
+
+describe('participation receipts', async () => {
+  setProvider(Provider.env())
+
+  const program = workspace.Arbiter as Program<Arbiter>
+
+  const authority = web3.Keypair.generate()
+  const participants = [...Array(2)].map(() => web3.Keypair.generate())
+
+  const title = 'Receipt Agreement'
+  const uri = 'https://arweave.net/receipt'
+  const settings = {
+    requiresAcceptance: false,
+    participantRoot: null,
+    participantCount: 0,
+    draft: false,
+    requiredWeight: new BN(1),
+    notBefore: null
+  }
+
+  let clerk: web3.PublicKey
+  let config: web3.PublicKey
+  let treasury: web3.PublicKey
+  let document: web3.PublicKey
+
+  const receiptAccounts = (participant: web3.PublicKey) =>
+    findReceiptAccounts(program, document, participant)
+
+  const claim = async (participant: web3.PublicKey) =>
+    program.methods.claimReceipt().accounts({
+      payer: authority.publicKey,
+      participant,
+      config,
+      document,
+      ...(await receiptAccounts(participant)),
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID
+    })
+
+  before(async () => {
+    config = await ensureConfig(program)
+    treasury = (await program.account.config.fetch(config)).treasury
+
+    for (const k of [authority, ...participants]) {
+      const sig = await program.provider.connection.requestAirdrop(
+        k.publicKey,
+        web3.LAMPORTS_PER_SOL
+      )
+      await program.provider.connection.confirmTransaction(sig)
+    }
+
+    ;[clerk] = await web3.PublicKey.findProgramAddress(
+      [Buffer.from('clerk'), authority.publicKey.toBytes()],
+      program.programId
+    )
+
+    ;[document] = await web3.PublicKey.findProgramAddress(
+      [Buffer.from('document'), authority.publicKey.toBytes(), Buffer.from(title)],
+      program.programId
+    )
+
+    await program.methods
+      .initClerk(1)
+      .accounts({ authority: authority.publicKey, payer: authority.publicKey, config, clerk })
+      .signers([authority])
+      .rpc()
+
+    await program.methods
+      .initDocument(
+        title,
+        uri,
+        participantSet({ participants: participants.map(p => p.publicKey) }),
+        [],
+        [],
+        settings
+      )
+      .accounts({
+        authority: authority.publicKey,
+        payer: authority.publicKey,
+        config,
+        treasury,
+        clerk,
+        document
+      })
+      .signers([authority])
+      .rpc()
+
+    await program.methods
+      .addSignature(0, null, null)
+      .accounts({
+        signer: participants[0].publicKey,
+        config,
+        participant: participants[0].publicKey,
+        document
+      })
+      .signers([participants[0]])
+      .rpc()
+  })
+
+  it('a receipt cannot be claimed before the document is finalized', async () => {
+    await assert.isRejected((await claim(participants[0].publicKey)).signers([authority]).simulate())
+  })
+
+  describe('once the document is finalized', () => {
+    before(async () => {
+      await program.methods
+        .finalize()
+        .accounts({
+          authority: authority.publicKey,
+          payer: authority.publicKey,
+          config,
+          clerk,
+          document
+        })
+        .signers([authority])
+        .rpc()
+
+      // Funding the mint address ahead of the claim must not prevent its creation.
+      const { mint } = await receiptAccounts(participants[0].publicKey)
+      await (program.provider as Provider).sendAndConfirm(
+        new web3.Transaction().add(
+          web3.SystemProgram.transfer({
+            fromPubkey: authority.publicKey,
+            toPubkey: mint,
+            lamports: web3.LAMPORTS_PER_SOL / 100
+          })
+        ),
+        [authority]
+      )
+
+      await (await claim(participants[0].publicKey)).signers([authority]).rpc()
+    })
+
+    it('a signing participant holds a single receipt token', async () => {
+      const { receipt } = await receiptAccounts(participants[0].publicKey)
+      const balance = await program.provider.connection.getTokenAccountBalance(receipt)
+      assert.strictEqual(balance.value.amount, '1')
+    })
+
+    it('the receipt mint is owned by the Token-2022 program', async () => {
+      const { mint } = await receiptAccounts(participants[0].publicKey)
+      const info = await program.provider.connection.getAccountInfo(mint)
+      assert.isTrue(info.owner.equals(TOKEN_2022_PROGRAM_ID))
+    })
+
+    it('the receipt metadata describes the document', async () => {
+      const { metadata } = await receiptAccounts(participants[0].publicKey)
+      const info = await program.provider.connection.getAccountInfo(metadata)
+      const { name, uri: metadataUri, creators } = decodeMetadata(info.data)
+
+      assert.strictEqual(name, title)
+      assert.strictEqual(metadataUri, uri)
+      assert.isTrue(creators[0].address.equals(document))
+      assert.isTrue(creators[0].verified)
+      assert.isTrue(creators[1].address.equals(participants[0].publicKey))
+      assert.isFalse(creators[1].verified)
+    })
+
+    it('a receipt cannot be claimed twice', async () => {
+      await assert.isRejected(
+        (await claim(participants[0].publicKey)).signers([authority]).simulate()
+      )
+    })
+
+    it('a participant that did not sign cannot claim a receipt', async () => {
+      await assert.isRejected(
+        (await claim(participants[1].publicKey)).signers([authority]).simulate()
+      )
+    })
+  })
+})
@@ -0,0 +1,109 @@
+import { BN, Program, AnchorProvider as Provider, setProvider, web3, workspace } from '@project-serum/anchor'
+import { assert, use as chaiUse } from 'chai'
+import chaiAsPromised from 'chai-as-promised'
+import { Arbiter } from '../target/types/arbiter'
+import { ensureConfig, participantSet } from './utils'
+
+chaiUse(chaiAsPromised)
+
+describe('time locked finalization', async () => {
+  setProvider(Provider.env())
+
+  const program = workspace.Arbiter as Program<Arbiter>
+
+  const authority = web3.Keypair.generate()
+  const participant = web3.Keypair.generate()
+
+  const title = 'Lease Effective Next Year'
+  const uri = 'https://arweave.net/lease'
+  const notBefore = new BN(Math.floor(Date.now() / 1000) + 365 * 24 * 60 * 60)
+  const settings = {
+    requiresAcceptance: false,
+    participantRoot: null,
+    participantCount: 0,
+    draft: false,
+    requiredWeight: new BN(0),
+    notBefore
+  }
+
+  let clerk: web3.PublicKey
+  let config: web3.PublicKey
+  let treasury: web3.PublicKey
+  let document: web3.PublicKey
+
+  before(async () => {
+    config = await ensureConfig(program)
+    treasury = (await program.account.config.fetch(config)).treasury
+
+    for (const k of [authority, participant]) {
+      const sig = await program.provider.connection.requestAirdrop(
+        k.publicKey,
+        web3.LAMPORTS_PER_SOL
+      )
+      await program.provider.connection.confirmTransaction(sig)
+    }
+
+    ;[clerk] = await web3.PublicKey.findProgramAddress(
+      [Buffer.from('clerk'), authority.publicKey.toBytes()],
+      program.programId
+    )
+
+    ;[document] = await web3.PublicKey.findProgramAddress(
+      [Buffer.from('document'), authority.publicKey.toBytes(), Buffer.from(title)],
+      program.programId
+    )
+
+    await program.methods
+      .initClerk(1)
+      .accounts({ authority: authority.publicKey, payer: authority.publicKey, config, clerk })
+      .signers([authority])
+      .rpc()
+
+    await program.methods
+      .initDocument(
+        title,
+        uri,
+        participantSet({ participants: [participant.publicKey] }),
+        [],
+        [],
+        settings
+      )
+      .accounts({
+        authority: authority.publicKey,
+        payer: authority.publicKey,
+        config,
+        treasury,
+        clerk,
+        document
+      })
+      .signers([authority])
+      .rpc()
+
+    await program.methods
+      .addSignature(0, null, null)
+      .accounts({ signer: participant.publicKey, config, participant: participant.publicKey, document })
+      .signers([participant])
+      .rpc()
+  })
+
+  it('the not before timestamp is stored in the document settings', async () => {
+    const doc = await program.account.document.fetch(document)
+    assert.isTrue(doc.settings.notBefore.eq(notBefore))
+  })
+
+  it('a fully signed document cannot be finalized before its effective date', () => {
+    assert.isRejected(
+      program.methods
+        .finalize()
+        .accounts({
+          authority: authority.publicKey,
+          payer: authority.publicKey,
+          config,
+          clerk,
+          document
+        })
+        .signers([authority])
+        .simulate()
+    )
+  })
+})
@@ -0,0 +1,90 @@
+import { BN, AnchorProvider as Provider, Program, web3 } from '@project-serum/anchor'
+import { ASSOCIATED_TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { Arbiter } from '../target/types/arbiter'
+
+const BPF_LOADER_UPGRADEABLE = new web3.PublicKey('BPFLoaderUpgradeab1e11111111111111111111111')
+
+export const TOKEN_2022_PROGRAM_ID = new web3.PublicKey(
+  'TokenzQdBNbLqP5VEhdkAS6EhFL5wPcHRsNPcx1jDUz'
+)
+export const TOKEN_METADATA_PROGRAM_ID = new web3.PublicKey(
+  'metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s'
+)
+
+export const limits = {
+  maxTitleLength: 64,
+  maxUriLength: 128,
+  maxParticipants: 10,
+  maxClerkLimit: 10
+}
+
+type ParticipantSet = {
+  participants: web3.PublicKey[]
+  ethParticipants: number[][]
+  deadlines: BN[]
+  weights: BN[]
+  groups: { name: string; threshold: number; members: Buffer }[]
+}
+
+/**
+ * Returns the `ParticipantSet` argument of `init_document` with the argued
+ * fields and every other field left empty.
+ */
+export const participantSet = (set: Partial<ParticipantSet>): ParticipantSet => ({
+  participants: [],
+  ethParticipants: [],
+  deadlines: [],
+  weights: [],
+  groups: [],
+  ...set
+})
+
+/**
+ * Derives the receipt mint of the participant for the document, the associated
+ * token account that holds their receipt and the token metadata of the mint.
+ */
+export async function findReceiptAccounts(
+  program: Program<Arbiter>,
+  document: web3.PublicKey,
+  participant: web3.PublicKey
+) {
+  const [mint] = await web3.PublicKey.findProgramAddress(
+    [Buffer.from('mint'), document.toBytes(), participant.toBytes()],
+    program.programId
+  )
+  const [receipt] = await web3.PublicKey.findProgramAddress(
+    [participant.toBytes(), TOKEN_2022_PROGRAM_ID.toBytes(), mint.toBytes()],
+    ASSOCIATED_TOKEN_PROGRAM_ID
+  )
+  const [metadata] = await web3.PublicKey.findProgramAddress(
+    [Buffer.from('metadata'), TOKEN_METADATA_PROGRAM_ID.toBytes(), mint.toBytes()],
+    TOKEN_METADATA_PROGRAM_ID
+  )
+  return { mint, receipt, metadata }
+}
+
+/**
+ * Derives the global config address and initializes it with the default test
+ * limits from the program upgrade authority if a previous spec has not already.
+ */
+export async function ensureConfig(program: Program<Arbiter>): Promise<web3.PublicKey> {
+  const [config] = await web3.PublicKey.findProgramAddress(
+    [Buffer.from('config')],
+    program.programId
+  )
+
+  if ((await program.account.config.fetchNullable(config)) === null) {
+    const [programData] = await web3.PublicKey.findProgramAddress(
+      [program.programId.toBytes()],
+      BPF_LOADER_UPGRADEABLE
+    )
+
+    const admin = (program.provider as Provider).wallet.publicKey
+    await program.methods
+      .initConfig(limits)
+      .accounts({ admin, payer: admin, config, program: program.programId, programData })
+      .rpc()
+  }
+
+  return config
+}
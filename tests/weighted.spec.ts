@@ -0,0 +1,130 @@
+import { BN, Program, AnchorProvider as Provider, setProvider, web3, workspace } from '@project-serum/anchor'
+import { assert, use as chaiUse } from 'chai'
+import chaiAsPromised from 'chai-as-promised'
+import { Arbiter } from '../target/types/arbiter'
+import { ensureConfig, participantSet } from './utils'
+
+chaiUse(chaiAsPromised)
+
+describe('weighted documents', async () => {
+  setProvider(Provider.env())
+
+  const program = workspace.Arbiter as Program<Arbiter>
+
+  const authority = web3.Keypair.generate()
+  const shareholders = [...Array(3)].map(() => web3.Keypair.generate())
+  const weights = [new BN(60), new BN(30), new BN(10)]
+
+  const title = 'Shareholder Consent'
+  const uri = 'https://arweave.net/consent'
+  const settings = (requiredWeight: number) => ({
+    requiresAcceptance: false,
+    participantRoot: null,
+    participantCount: 0,
+    draft: false,
+    requiredWeight: new BN(requiredWeight),
+    notBefore: null
+  })
+
+  let clerk: web3.PublicKey
+  let config: web3.PublicKey
+  let treasury: web3.PublicKey
+  let document: web3.PublicKey
+
+  const initDocument = (requiredWeight: number) =>
+    program.methods
+      .initDocument(
+        title,
+        uri,
+        participantSet({ participants: shareholders.map(s => s.publicKey), weights }),
+        [],
+        [],
+        settings(requiredWeight)
+      )
+      .accounts({
+        authority: authority.publicKey,
+        payer: authority.publicKey,
+        config,
+        treasury,
+        clerk,
+        document
+      })
+      .signers([authority])
+
+  const sign = (signer: web3.Keypair) =>
+    program.methods
+      .addSignature(0, null, null)
+      .accounts({ signer: signer.publicKey, config, participant: signer.publicKey, document })
+      .signers([signer])
+      .rpc()
+
+  const finalize = () =>
+    program.methods
+      .finalize()
+      .accounts({
+        authority: authority.publicKey,
+        payer: authority.publicKey,
+        config,
+        clerk,
+        document
+      })
+      .signers([authority])
+
+  before(async () => {
+    config = await ensureConfig(program)
+    treasury = (await program.account.config.fetch(config)).treasury
+
+    for (const k of [authority, ...shareholders]) {
+      const sig = await program.provider.connection.requestAirdrop(
+        k.publicKey,
+        web3.LAMPORTS_PER_SOL
+      )
+      await program.provider.connection.confirmTransaction(sig)
+    }
+
+    ;[clerk] = await web3.PublicKey.findProgramAddress(
+      [Buffer.from('clerk'), authority.publicKey.toBytes()],
+      program.programId
+    )
+
+    ;[document] = await web3.PublicKey.findProgramAddress(
+      [Buffer.from('document'), authority.publicKey.toBytes(), Buffer.from(title)],
+      program.programId
+    )
+
+    await program.methods
+      .initClerk(1)
+      .accounts({ authority: authority.publicKey, payer: authority.publicKey, config, clerk })
+      .signers([authority])
+      .rpc()
+  })
+
+  it('a document cannot require more weight than its participants hold', () => {
+    assert.isRejected(initDocument(101).simulate())
+  })
+
+  describe('when a document requires a weighted quorum', () => {
+    before(async () => {
+      await initDocument(70).rpc()
+      await sign(shareholders[0])
+    })
+
+    it('the participant weights are stored', async () => {
+      const doc = await program.account.document.fetch(document)
+      assert.isTrue(doc.weights.every((w: BN, i: number) => w.eq(weights[i])))
+      assert.isTrue(doc.settings.requiredWeight.eqn(70))
+    })
+
+    it('it cannot be finalized below the required weight', () => {
+      assert.isRejected(finalize().simulate())
+    })
+
+    it('it can be finalized once the signed weight meets the requirement', async () => {
+      await sign(shareholders[2])
+      await finalize().rpc()
+
+      const doc = await program.account.document.fetch(document)
+      assert.notEqual(doc.finalizationTimestamp.toNumber(), 0)
+    })
+  })
+})